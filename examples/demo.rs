@@ -12,20 +12,20 @@ fn main() -> color_eyre::Result<()> {
 
     let mut terminal = startup()?;
 
-    terminal.draw(|frame| {
-        let area = frame.size();
-        let [bottom] = Layout::vertical([Constraint::Length(1)])
-            .flex(layout::Flex::End)
-            .areas(area);
-        let status_bar = ratatui_statusbar::StatusBar::new(2)
-            .section(0, "hello")
-            .unwrap()
-            .section(1, "world")
-            .unwrap();
-        frame.render_widget(status_bar, bottom);
-    })?;
-
-    while !matches!(event::read()?, Event::Key(_)) {}
+    // build the status bar once and render it by reference every frame
+    let status_bar = ratatui_statusbar::StatusBar::new(2)
+        .section(0, "hello")?
+        .section(1, "world")?;
+
+    loop {
+        terminal.draw(|frame| {
+            let (_, bottom) = status_bar.split(frame.size());
+            frame.render_widget(&status_bar, bottom);
+        })?;
+        if matches!(event::read()?, Event::Key(_)) {
+            break;
+        }
+    }
 
     shutdown()?;
 
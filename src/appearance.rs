@@ -0,0 +1,194 @@
+//! Detection of light and dark terminal backgrounds.
+//!
+//! The background color is queried with the `OSC 11` escape sequence, followed by a device
+//! attributes request that every terminal answers, so that terminals without support for the
+//! query don't leave the detection waiting for the full timeout. The terminal is accessed
+//! through `/dev/tty`, so this module is only available on Unix.
+//!
+//! # Examples
+//! ```no_run
+//! use ratatui_statusbar::appearance::TerminalAppearance;
+//!
+//! let light = TerminalAppearance::detect()? == TerminalAppearance::Light;
+//! # Ok::<(), ratatui_statusbar::StatusBarError>(())
+//! ```
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::fd::AsRawFd;
+use std::time::{Duration, Instant};
+
+use crossterm::terminal;
+use ratatui::style::Color;
+
+use crate::{color, StatusBarError};
+
+/// How long [`TerminalAppearance::detect`] waits for the terminal to reply.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Whether the terminal has a light or a dark background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TerminalAppearance {
+    /// Dark text on a light background.
+    Light,
+    /// Light text on a dark background.
+    Dark,
+}
+
+impl TerminalAppearance {
+    /// Queries the background color of the terminal, waiting at most [`DEFAULT_TIMEOUT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Protocol`] if the terminal can't be accessed or doesn't report
+    /// its background color.
+    pub fn detect() -> Result<Self, StatusBarError> {
+        background_color(DEFAULT_TIMEOUT).map(Self::from_color)
+    }
+
+    /// Returns the appearance of a terminal with the `background` color.
+    ///
+    /// Colors without a known RGB value are assumed to be dark.
+    #[must_use]
+    pub fn from_color(background: Color) -> Self {
+        let Some((r, g, b)) = color::to_rgb(background) else {
+            return Self::Dark;
+        };
+        let luminance = 0.2126 * f32::from(r) + 0.7152 * f32::from(g) + 0.0722 * f32::from(b);
+        if luminance > 127.5 {
+            Self::Light
+        } else {
+            Self::Dark
+        }
+    }
+}
+
+/// Queries the background color of the terminal.
+///
+/// Raw mode is enabled while waiting at most `timeout` for the reply, unless it is already
+/// enabled. Nothing is left reading from the terminal afterwards, so a reply that arrives too late
+/// goes to the input of the application like any other input.
+///
+/// # Errors
+///
+/// Returns [`StatusBarError::Protocol`] if the terminal can't be accessed, doesn't reply in time
+/// or doesn't report its background color.
+pub fn background_color(timeout: Duration) -> Result<Color, StatusBarError> {
+    let raw = terminal::is_raw_mode_enabled().map_err(protocol_error)?;
+    if !raw {
+        terminal::enable_raw_mode().map_err(protocol_error)?;
+    }
+    let reply = query(timeout);
+    if !raw {
+        terminal::disable_raw_mode().map_err(protocol_error)?;
+    }
+    let reply = reply.map_err(protocol_error)?;
+    parse_reply(&reply).ok_or_else(|| {
+        StatusBarError::Protocol("the terminal did not report its background color".into())
+    })
+}
+
+/// Sends the background color and device attributes queries and returns everything up to the end
+/// of the device attributes reply.
+fn query(timeout: Duration) -> io::Result<String> {
+    let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty")?;
+    tty.write_all(b"\x1b]11;?\x1b\\\x1b[c")?;
+    tty.flush()?;
+    let deadline = Instant::now() + timeout;
+    let mut reply = Vec::new();
+    let mut byte = [0];
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match readable(&tty, remaining) {
+            Ok(true) => {}
+            Ok(false) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "the terminal did not reply",
+                ))
+            }
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+        // read a byte at a time to leave input that follows the reply to the application
+        if tty.read(&mut byte)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        reply.push(byte[0]);
+        // the device attributes reply is `ESC [ ? ... c`
+        if byte[0] == b'c' && reply.windows(3).any(|window| window == b"\x1b[?") {
+            return Ok(String::from_utf8_lossy(&reply).into_owned());
+        }
+    }
+}
+
+/// Waits at most `timeout` for `tty` to become readable and returns whether it did.
+fn readable(tty: &File, timeout: Duration) -> io::Result<bool> {
+    let mut fd = libc::pollfd {
+        fd: tty.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let timeout = libc::c_int::try_from(timeout.as_millis()).unwrap_or(libc::c_int::MAX);
+    // SAFETY: `fd` is a single valid `pollfd` that outlives the call.
+    match unsafe { libc::poll(&mut fd, 1, timeout) } {
+        -1 => Err(io::Error::last_os_error()),
+        0 => Ok(false),
+        _ => Ok(true),
+    }
+}
+
+/// Parses the color of an `OSC 11` reply such as `ESC ] 11 ; rgb:ffff/ffff/ffff ESC \`.
+fn parse_reply(reply: &str) -> Option<Color> {
+    let start = reply.find("\x1b]11;rgb:")? + "\x1b]11;rgb:".len();
+    let end = reply[start..].find(['\x1b', '\x07'])? + start;
+    let mut components = reply[start..end].split('/').map(|component| {
+        let value = u32::from_str_radix(component, 16).ok()?;
+        let max = 16u32.checked_pow(u32::try_from(component.len()).ok()?)? - 1;
+        u8::try_from(value * 255 / max).ok()
+    });
+    let (r, g, b) = (
+        components.next()??,
+        components.next()??,
+        components.next()??,
+    );
+    Some(Color::Rgb(r, g, b))
+}
+
+fn protocol_error(error: io::Error) -> StatusBarError {
+    StatusBarError::Protocol(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_replies() {
+        assert_eq!(
+            parse_reply("\x1b]11;rgb:ffff/ffff/ffff\x1b\\\x1b[?62;c"),
+            Some(Color::Rgb(255, 255, 255))
+        );
+        assert_eq!(
+            parse_reply("\x1b]11;rgb:1e/1e/2e\x07"),
+            Some(Color::Rgb(0x1e, 0x1e, 0x2e))
+        );
+        assert_eq!(parse_reply("\x1b[?62;c"), None);
+    }
+
+    #[test]
+    fn light_and_dark() {
+        assert_eq!(
+            TerminalAppearance::from_color(Color::Rgb(0xfd, 0xf6, 0xe3)),
+            TerminalAppearance::Light
+        );
+        assert_eq!(
+            TerminalAppearance::from_color(Color::Rgb(0x00, 0x2b, 0x36)),
+            TerminalAppearance::Dark
+        );
+        assert_eq!(
+            TerminalAppearance::from_color(Color::Reset),
+            TerminalAppearance::Dark
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Width-dependent adjustments of a [`StatusBar`](crate::StatusBar).
+
+/// Adjustments applied to a [`StatusBar`](crate::StatusBar) while it is rendered into an area up
+/// to a certain width, see [`StatusBar::breakpoint`](crate::StatusBar::breakpoint).
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::{Breakpoint, StatusBar, StatusBarSection};
+///
+/// let status_bar = StatusBar::new(3)
+///     .section(0, StatusBarSection::from("NORMAL").short_content("N"))?
+///     .section(1, StatusBarSection::from("main").name("git"))?
+///     .section(2, "12:00")?
+///     // Up to 100 columns use short content, up to 60 also hide the git branch.
+///     .breakpoint(100, Breakpoint::new().short(true))
+///     .breakpoint(60, Breakpoint::new().short(true).spacing(0).hide("git"));
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Breakpoint {
+    pub(crate) spacing: Option<u16>,
+    pub(crate) short: bool,
+    pub(crate) hidden: Vec<String>,
+}
+
+impl Breakpoint {
+    /// Creates a [`Breakpoint`] that doesn't change anything.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the spacing between sections.
+    #[must_use]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Switches sections to their [short content](crate::StatusBarSection::short_content).
+    #[must_use]
+    pub const fn short(mut self, short: bool) -> Self {
+        self.short = short;
+        self
+    }
+
+    /// Hides the sections [named](crate::StatusBarSection::name) `name`.
+    #[must_use]
+    pub fn hide(mut self, name: impl Into<String>) -> Self {
+        self.hidden.push(name.into());
+        self
+    }
+
+    pub(crate) fn hides(&self, name: Option<&str>) -> bool {
+        name.is_some_and(|name| self.hidden.iter().any(|hidden| hidden == name))
+    }
+}
@@ -0,0 +1,60 @@
+//! Conversion of text to pure ASCII.
+
+use ratatui::prelude::*;
+
+/// Returns an ASCII character resembling `c`, or `?` if there is none.
+pub(crate) fn to_ascii(c: char) -> char {
+    match c {
+        _ if c.is_ascii() => c,
+        '│' | '┃' | '║' | '┆' | '┊' | '╎' | '▏' | '▕' => '|',
+        '─' | '━' | '═' | '–' | '—' => '-',
+        '\u{e0b0}' | '\u{e0b1}' | '›' | '»' | '▶' | '▸' | '►' | '→' | '❯' => '>',
+        '\u{e0b2}' | '\u{e0b3}' | '‹' | '«' | '◀' | '◂' | '◄' | '←' | '❮' => '<',
+        '\u{e0b4}' | '\u{e0b5}' => ')',
+        '\u{e0b6}' | '\u{e0b7}' => '(',
+        '\u{e0b8}' | '\u{e0b9}' | '\u{e0bc}' | '\u{e0bd}' => '/',
+        '\u{e0ba}' | '\u{e0bb}' | '\u{e0be}' | '\u{e0bf}' => '\\',
+        '…' | '·' | '░' => '.',
+        '•' | '●' | '◆' => '*',
+        '█' | '▇' | '▆' | '▅' | '▓' => '#',
+        '▄' | '▃' | '▂' | '▁' | '▒' => '=',
+        '✓' | '✔' => 'v',
+        '✗' | '✘' | '✖' | '×' => 'x',
+        '\u{a0}' | '\u{2002}'..='\u{200a}' => ' ',
+        _ => '?',
+    }
+}
+
+/// Converts every character of `span` to ASCII.
+pub(crate) fn span_to_ascii<'a>(span: &Span<'a>) -> Span<'a> {
+    if span.content.is_ascii() {
+        return span.clone();
+    }
+    Span::styled(
+        span.content.chars().map(to_ascii).collect::<String>(),
+        span.style,
+    )
+}
+
+/// Converts every character of `line` to ASCII.
+pub(crate) fn line_to_ascii<'a>(line: &Line<'a>) -> Line<'a> {
+    Line {
+        spans: line.spans.iter().map(span_to_ascii).collect(),
+        ..line.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert() {
+        let line = Line::from(vec![Span::raw(" \u{e0b0} "), Span::raw("a…").red()]);
+        assert_eq!(
+            line_to_ascii(&line),
+            Line::from(vec![Span::raw(" > "), Span::raw("a.").red()])
+        );
+        assert_eq!(to_ascii('日'), '?');
+    }
+}
@@ -0,0 +1,249 @@
+//! Color conversions for the adapters and effects that need concrete RGB values.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+/// How many colors the terminal can display, see [`StatusBar::color_depth`](crate::StatusBar::color_depth).
+///
+/// Colors the terminal can't display are converted to the nearest color it can, so themes
+/// defined in RGB still look reasonable on terminals without 24-bit color.
+///
+/// # Examples
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui_statusbar::ColorDepth;
+///
+/// assert_eq!(ColorDepth::Indexed256.convert(Color::Rgb(0xff, 0x5f, 0x5f)), Color::Indexed(203));
+/// assert_eq!(ColorDepth::Ansi16.convert(Color::Rgb(0xff, 0x5f, 0x5f)), Color::LightRed);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ColorDepth {
+    /// 24-bit RGB colors are displayed as they are.
+    #[default]
+    TrueColor,
+    /// The 256 colors of the xterm palette.
+    Indexed256,
+    /// The 16 named colors.
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// Guesses the color depth of the terminal from the `COLORTERM` and `TERM` environment
+    /// variables.
+    #[must_use]
+    pub fn from_env() -> Self {
+        detect(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// Returns the color nearest to `color` that can be displayed at this depth.
+    ///
+    /// Named colors, [`Color::Reset`] and, below [`ColorDepth::TrueColor`], the first 16
+    /// indexed colors are returned unchanged, since terminals display them with their own
+    /// palette.
+    #[must_use]
+    pub fn convert(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::TrueColor, _) | (_, Color::Indexed(0..=15)) => color,
+            (Self::Indexed256, Color::Rgb(..)) => Color::Indexed(nearest(color, 16..=255)),
+            (Self::Ansi16, Color::Rgb(..) | Color::Indexed(_)) => {
+                NAMED[usize::from(nearest(color, 0..=15))]
+            }
+            _ => color,
+        }
+    }
+}
+
+/// The named colors in the order of their indexes.
+const NAMED: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+fn detect(colorterm: Option<&str>, term: Option<&str>) -> ColorDepth {
+    if matches!(colorterm, Some("truecolor" | "24bit")) {
+        ColorDepth::TrueColor
+    } else if term.is_some_and(|term| term.contains("256color")) {
+        ColorDepth::Indexed256
+    } else {
+        ColorDepth::Ansi16
+    }
+}
+
+/// Returns the index in `candidates` whose xterm color is closest to `color`.
+fn nearest(color: Color, candidates: std::ops::RangeInclusive<u8>) -> u8 {
+    let (r, g, b) = to_rgb(color).unwrap_or_default();
+    let distance = |index: u8| {
+        let (r2, g2, b2) = indexed_to_rgb(index);
+        [(r, r2), (g, g2), (b, b2)]
+            .into_iter()
+            .map(|(a, b)| u32::from(a.abs_diff(b)).pow(2))
+            .sum::<u32>()
+    };
+    candidates.min_by_key(|index| distance(*index)).unwrap_or(0)
+}
+
+/// Converts the colors of the cells in `area` to `depth`.
+pub(crate) fn convert_area(buf: &mut Buffer, area: Rect, depth: ColorDepth) {
+    if depth == ColorDepth::TrueColor {
+        return;
+    }
+    let area = area.intersection(buf.area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell = buf.get_mut(x, y);
+            cell.fg = depth.convert(cell.fg);
+            cell.bg = depth.convert(cell.bg);
+        }
+    }
+}
+
+/// The xterm default values of the 16 named colors.
+const ANSI: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00),
+    (0x80, 0x00, 0x00),
+    (0x00, 0x80, 0x00),
+    (0x80, 0x80, 0x00),
+    (0x00, 0x00, 0x80),
+    (0x80, 0x00, 0x80),
+    (0x00, 0x80, 0x80),
+    (0xc0, 0xc0, 0xc0),
+    (0x80, 0x80, 0x80),
+    (0xff, 0x00, 0x00),
+    (0x00, 0xff, 0x00),
+    (0xff, 0xff, 0x00),
+    (0x00, 0x00, 0xff),
+    (0xff, 0x00, 0xff),
+    (0x00, 0xff, 0xff),
+    (0xff, 0xff, 0xff),
+];
+
+/// Returns the RGB value of a color using the xterm palette, or `None` for [`Color::Reset`].
+pub(crate) fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        Color::Indexed(index) => index,
+    };
+    Some(indexed_to_rgb(index))
+}
+
+/// Linearly interpolates between two colors, returning `from` at `t = 0.0` and `to` at `t = 1.0`.
+///
+/// The colors are returned unchanged at the ends of the range. In between, `from` is returned if
+/// either color has no RGB value.
+pub(crate) fn blend(from: Color, to: Color, t: f32) -> Color {
+    if t <= 0.0 {
+        return from;
+    }
+    if t >= 1.0 {
+        return to;
+    }
+    let (Some(a), Some(b)) = (to_rgb(from), to_rgb(to)) else {
+        return from;
+    };
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let mix = |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8;
+    Color::Rgb(mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..=15 => ANSI[usize::from(index)],
+        16..=231 => {
+            let index = usize::from(index - 16);
+            (LEVELS[index / 36], LEVELS[index / 6 % 6], LEVELS[index % 6])
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgb() {
+        assert_eq!(to_rgb(Color::Reset), None);
+        assert_eq!(to_rgb(Color::LightRed), Some((0xff, 0, 0)));
+        assert_eq!(to_rgb(Color::Indexed(196)), Some((255, 0, 0)));
+        assert_eq!(to_rgb(Color::Indexed(244)), Some((128, 128, 128)));
+    }
+
+    #[test]
+    fn blend_colors() {
+        assert_eq!(
+            blend(Color::Black, Color::White, 0.5),
+            Color::Rgb(128, 128, 128)
+        );
+        assert_eq!(blend(Color::Red, Color::Blue, 0.0), Color::Red);
+        assert_eq!(blend(Color::Red, Color::Blue, 1.0), Color::Blue);
+        assert_eq!(blend(Color::Red, Color::Reset, 0.5), Color::Red);
+    }
+
+    #[test]
+    fn color_depth() {
+        let gray = Color::Rgb(0x26, 0x26, 0x26);
+        assert_eq!(ColorDepth::TrueColor.convert(gray), gray);
+        assert_eq!(ColorDepth::Indexed256.convert(gray), Color::Indexed(235));
+        assert_eq!(ColorDepth::Ansi16.convert(gray), Color::Black);
+        assert_eq!(
+            ColorDepth::Ansi16.convert(Color::Indexed(244)),
+            Color::DarkGray
+        );
+        assert_eq!(
+            ColorDepth::Ansi16.convert(Color::Indexed(3)),
+            Color::Indexed(3)
+        );
+        assert_eq!(ColorDepth::Indexed256.convert(Color::Reset), Color::Reset);
+
+        assert_eq!(
+            detect(Some("truecolor"), Some("xterm")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(detect(None, Some("xterm-256color")), ColorDepth::Indexed256);
+        assert_eq!(detect(None, None), ColorDepth::Ansi16);
+    }
+}
@@ -0,0 +1,671 @@
+//! Declarative status bar definitions.
+//!
+//! A [`StatusBarConfig`] describes a whole status bar: the flex layout, the spacing, a default
+//! separator, the [theme](ThemeConfig) and the sections, which are either static [`markup`](crate::markup) content or
+//! dynamic [`segments`](crate::segments) such as `"clock"` and `"git"`. It can be read from any
+//! format supported by [`serde`], with helpers for TOML (requires the `toml` feature) and YAML
+//! (requires the `yaml` feature).
+//!
+//! # Examples
+//! ```
+//! # #[cfg(feature = "toml")]
+//! # fn main() -> Result<(), ratatui_statusbar::StatusBarError> {
+//! use ratatui_statusbar::config::StatusBarConfig;
+//!
+//! let config = StatusBarConfig::from_toml(
+//!     r##"
+//!     flex = "SpaceBetween"
+//!     separator = " | "
+//!     theme = "light"
+//!     sections = [
+//!         "#[fg=black,bg=green,bold] NORMAL ",
+//!         { segment = "git", style = "fg=magenta" },
+//!         { segment = "clock", format = "%H:%M:%S" },
+//!     ]
+//!     "##,
+//! )?;
+//! // Build the status bar every frame to refresh the segments.
+//! let status_bar = config.build()?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "toml"))]
+//! # fn main() {}
+//! ```
+
+use ratatui::layout::Flex;
+use serde::{Deserialize, Serialize};
+
+use crate::segments::{SegmentOptions, SegmentRegistry};
+use crate::{
+    markup, ColorDepth, Role, SeparatorMerge, SeparatorStyle, StatusBar, StatusBarError,
+    StatusBarSection, Theme,
+};
+
+/// A declarative description of a [`StatusBar`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StatusBarConfig {
+    /// The flex layout of the sections, by name (`"Start"`, `"SpaceBetween"`, ...).
+    #[serde(default, with = "crate::serialization::flex")]
+    pub flex: Flex,
+    /// The spacing between sections.
+    #[serde(default = "default_spacing")]
+    pub spacing: u16,
+    /// Style attributes in [`markup`] syntax applied to the whole bar, e.g. `"bg=black"`,
+    /// replacing [`Theme::bar`] of the theme.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// Style attributes in [`markup`] syntax applied on top of the bar while it is inactive,
+    /// replacing [`Theme::inactive`] of the theme or the default of
+    /// [`StatusBar::DEFAULT_INACTIVE_STYLE`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inactive_style: Option<String>,
+    /// The post-separator of every section but the last that doesn't declare its own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub separator: Option<String>,
+    /// Where separators take their style from, e.g. `"preceding"`.
+    #[serde(default)]
+    pub separator_style: SeparatorStyle,
+    /// Which separator is kept where two separators meet, e.g. `"preceding"`.
+    #[serde(default)]
+    pub separator_merge: SeparatorMerge,
+    /// Whether the sections are laid out from right to left.
+    #[serde(default)]
+    pub right_to_left: bool,
+    /// Whether spacing, separators and padding are dropped before content is truncated.
+    #[serde(default)]
+    pub compact: bool,
+    /// Whether the sections on the left are clipped first when the sections don't fit.
+    #[serde(default)]
+    pub fill_from_end: bool,
+    /// Whether the bar is rendered in pure ASCII.
+    #[serde(default)]
+    pub ascii: bool,
+    /// How many colors the terminal can display, e.g. `"indexed256"`.
+    #[serde(default)]
+    pub color_depth: ColorDepth,
+    /// Whether the bar scrolls horizontally instead of truncating sections that don't fit.
+    #[serde(default)]
+    pub scrollable: bool,
+    /// Whether the edges of a scrolled bar that hide content are marked.
+    #[serde(default)]
+    pub scroll_indicators: bool,
+    /// Whether the number of sections left out for lack of space is shown.
+    #[serde(default)]
+    pub overflow_indicator: bool,
+    /// The theme the bar and the [roles](crate::Role) of its sections are styled with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemeConfig>,
+    /// The sections of the status bar.
+    #[serde(default)]
+    pub sections: Vec<SectionConfig>,
+}
+
+impl Default for StatusBarConfig {
+    fn default() -> Self {
+        Self {
+            flex: Flex::default(),
+            spacing: default_spacing(),
+            style: None,
+            inactive_style: None,
+            separator: None,
+            separator_style: SeparatorStyle::default(),
+            separator_merge: SeparatorMerge::default(),
+            right_to_left: false,
+            compact: false,
+            fill_from_end: false,
+            ascii: false,
+            color_depth: ColorDepth::TrueColor,
+            scrollable: false,
+            scroll_indicators: false,
+            overflow_indicator: false,
+            theme: None,
+            sections: Vec::new(),
+        }
+    }
+}
+
+/// The [`Theme`] of a [`StatusBarConfig`], either a preset by name or explicit styles.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "toml")]
+/// # fn main() -> Result<(), ratatui_statusbar::StatusBarError> {
+/// use ratatui_statusbar::config::StatusBarConfig;
+///
+/// let config = StatusBarConfig::from_toml(
+///     r##"
+///     theme = { preset = "light", accent = "fg=black,bg=green,bold" }
+///     sections = ["NORMAL"]
+///     "##,
+/// )?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "toml"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeConfig {
+    /// A preset, e.g. `"light"`.
+    Preset(ThemePreset),
+    /// Styles in [`markup`] syntax replacing those of a preset.
+    Styles(ThemeStyles),
+}
+
+/// A [`Theme`] preset of a [`ThemeConfig`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", from = "PresetName")]
+pub enum ThemePreset {
+    /// [`Theme::dark`].
+    #[default]
+    Dark,
+    /// [`Theme::light`].
+    Light,
+    /// The preset matching the background of the terminal (requires the `appearance` feature),
+    /// or [`Theme::dark`] without it.
+    ///
+    /// The terminal is queried once per process, when the first configuration using the preset
+    /// is deserialized (or on the first call to [`ThemePreset::theme`] for presets built in
+    /// code), so that building the status bar every frame doesn't stall on the terminal.
+    Auto,
+}
+
+/// The name of a [`ThemePreset`] in a configuration.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum PresetName {
+    Dark,
+    Light,
+    Auto,
+}
+
+impl From<PresetName> for ThemePreset {
+    fn from(name: PresetName) -> Self {
+        match name {
+            PresetName::Dark => Self::Dark,
+            PresetName::Light => Self::Light,
+            PresetName::Auto => {
+                // query the terminal while the configuration is loaded rather than in `build`
+                let _ = Self::Auto.theme();
+                Self::Auto
+            }
+        }
+    }
+}
+
+/// Explicit styles of a [`ThemeConfig`], in [`markup`] syntax, e.g. `"fg=red,bold"`.
+///
+/// Styles that aren't given are taken from the preset.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeStyles {
+    /// The preset the styles replace those of.
+    #[serde(default)]
+    pub preset: ThemePreset,
+    /// See [`Theme::bar`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar: Option<String>,
+    /// See [`Theme::accent`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+    /// See [`Theme::muted`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<String>,
+    /// See [`Theme::info`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+    /// See [`Theme::warning`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    /// See [`Theme::error`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// See [`Theme::inactive`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inactive: Option<String>,
+}
+
+impl ThemePreset {
+    /// Returns the [`Theme`] of the preset. The [`Auto`](Self::Auto) preset is detected once and
+    /// then cached.
+    #[must_use]
+    pub fn theme(self) -> Theme {
+        match self {
+            Self::Dark => Theme::dark(),
+            Self::Light => Theme::light(),
+            #[cfg(all(feature = "appearance", unix))]
+            Self::Auto => {
+                static AUTO: std::sync::OnceLock<Theme> = std::sync::OnceLock::new();
+                *AUTO.get_or_init(Theme::auto)
+            }
+            #[cfg(not(all(feature = "appearance", unix)))]
+            Self::Auto => Theme::dark(),
+        }
+    }
+}
+
+impl ThemeConfig {
+    /// Returns the described [`Theme`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if a style has unknown attributes or invalid colors.
+    pub fn theme(&self) -> Result<Theme, StatusBarError> {
+        match self {
+            Self::Preset(preset) => Ok(preset.theme()),
+            Self::Styles(styles) => {
+                let mut theme = styles.preset.theme();
+                for (name, style, markup) in [
+                    ("bar", &mut theme.bar, &styles.bar),
+                    ("accent", &mut theme.accent, &styles.accent),
+                    ("muted", &mut theme.muted, &styles.muted),
+                    ("info", &mut theme.info, &styles.info),
+                    ("warning", &mut theme.warning, &styles.warning),
+                    ("error", &mut theme.error, &styles.error),
+                    ("inactive", &mut theme.inactive, &styles.inactive),
+                ] {
+                    if let Some(markup) = markup {
+                        *style = parse(markup::parse_style_strict, name, markup)?;
+                    }
+                }
+                Ok(theme)
+            }
+        }
+    }
+}
+
+/// Parses the markup of the configuration option `name` with `parse`, reporting malformed markup
+/// as a [`StatusBarError::Config`] naming the option.
+fn parse<T>(
+    parse: fn(&str) -> Result<T, StatusBarError>,
+    name: &str,
+    markup: &str,
+) -> Result<T, StatusBarError> {
+    parse(markup).map_err(|err| StatusBarError::Config(format!("`{name}`: {err}")))
+}
+
+/// The description of a single section in a [`StatusBarConfig`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum SectionConfig {
+    /// A section whose content is produced by a [`Segment`](crate::segments::Segment).
+    Segment(SegmentConfig),
+    /// A section with static content.
+    Static(StatusBarSection<'static>),
+}
+
+/// A section produced by a [`Segment`](crate::segments::Segment).
+///
+/// The segment is looked up by name in a [`SegmentRegistry`], which also documents the built-in
+/// segments and their options.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentConfig {
+    /// The name of the segment.
+    pub segment: String,
+    /// The name of the section, see [`StatusBarSection::name`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Style attributes in [`markup`] syntax applied to the section, e.g. `"fg=red,bold"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// The [`Role`] the section takes its style from in the theme of the bar, e.g. `"accent"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<Role>,
+    /// The pre-separator of the section, in [`markup`] syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_separator: Option<String>,
+    /// The post-separator of the section, in [`markup`] syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_separator: Option<String>,
+    /// Segment specific options.
+    #[serde(flatten)]
+    pub options: SegmentOptions,
+}
+
+impl StatusBarConfig {
+    /// Parses a configuration from TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if the configuration is invalid.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(config: &str) -> Result<Self, StatusBarError> {
+        toml::from_str(config).map_err(|err| StatusBarError::Config(err.to_string()))
+    }
+
+    /// Parses a configuration from YAML.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if the configuration is invalid.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(config: &str) -> Result<Self, StatusBarError> {
+        serde_yaml::from_str(config).map_err(|err| StatusBarError::Config(err.to_string()))
+    }
+
+    /// Builds the [`StatusBar`], evaluating all segments with the built-in segments of
+    /// [`SegmentRegistry::with_builtins`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if a section refers to an unknown segment or has invalid
+    /// options.
+    pub fn build(&self) -> Result<StatusBar<'static>, StatusBarError> {
+        self.build_with(&SegmentRegistry::with_builtins())
+    }
+
+    /// Builds the [`StatusBar`], resolving segments through `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if a section refers to a segment that is not registered
+    /// or has invalid options, or any other error returned by a segment factory.
+    pub fn build_with(
+        &self,
+        registry: &SegmentRegistry,
+    ) -> Result<StatusBar<'static>, StatusBarError> {
+        let mut sections = self
+            .sections
+            .iter()
+            .map(|section| section.build(registry))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(separator) = &self.separator {
+            let count = sections.len();
+            for section in sections.iter_mut().take(count.saturating_sub(1)) {
+                if section.post_separator.is_none() {
+                    section.post_separator =
+                        Some(parse(markup::parse_span_strict, "separator", separator)?);
+                }
+            }
+        }
+        // like `StatusBar::theme`, the theme styles the bar unless the config overrides them
+        let theme = self.theme.as_ref().map(ThemeConfig::theme).transpose()?;
+        Ok(StatusBar {
+            sections,
+            flex: self.flex,
+            spacing: self.spacing,
+            style: self
+                .style
+                .as_deref()
+                .map(|style| parse(markup::parse_style_strict, "style", style))
+                .transpose()?
+                .or(theme.map(|theme| theme.bar))
+                .unwrap_or_default(),
+            separator_style: self.separator_style,
+            separator_merge: self.separator_merge,
+            theme,
+            inactive: false,
+            inactive_style: self
+                .inactive_style
+                .as_deref()
+                .map(|style| parse(markup::parse_style_strict, "inactive_style", style))
+                .transpose()?
+                .or(theme.map(|theme| theme.inactive)),
+            breakpoints: Vec::new(),
+            right_to_left: self.right_to_left,
+            compact: self.compact,
+            fill_from_end: self.fill_from_end,
+            ascii: self.ascii,
+            color_depth: self.color_depth,
+            scrollable: self.scrollable,
+            scroll_indicators: self.scroll_indicators,
+            overflow_indicator: self.overflow_indicator,
+            on_render: None,
+        })
+    }
+}
+
+impl SectionConfig {
+    fn build(
+        &self,
+        registry: &SegmentRegistry,
+    ) -> Result<StatusBarSection<'static>, StatusBarError> {
+        match self {
+            Self::Static(section) => Ok(section.clone()),
+            Self::Segment(config) => config.build(registry),
+        }
+    }
+}
+
+impl SegmentConfig {
+    fn build(
+        &self,
+        registry: &SegmentRegistry,
+    ) -> Result<StatusBarSection<'static>, StatusBarError> {
+        Ok(StatusBarSection {
+            name: self.name.clone(),
+            pre_separator: self
+                .pre_separator
+                .as_deref()
+                .map(|separator| parse(markup::parse_span_strict, "pre_separator", separator))
+                .transpose()?,
+            content: registry.create(&self.segment, &self.options)?.content(),
+            post_separator: self
+                .post_separator
+                .as_deref()
+                .map(|separator| parse(markup::parse_span_strict, "post_separator", separator))
+                .transpose()?,
+            style: self
+                .style
+                .as_deref()
+                .map(|style| parse(markup::parse_style_strict, "style", style))
+                .transpose()?
+                .unwrap_or_default(),
+            role: self.role,
+            ..Default::default()
+        })
+    }
+}
+
+impl StatusBar<'static> {
+    /// Builds a [`StatusBar`] from a TOML [`StatusBarConfig`].
+    ///
+    /// Segments are evaluated once; keep the [`StatusBarConfig`] around and call
+    /// [`StatusBarConfig::build`] to refresh them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if the configuration is invalid.
+    #[cfg(feature = "toml")]
+    pub fn from_config(config: &str) -> Result<Self, StatusBarError> {
+        StatusBarConfig::from_toml(config)?.build()
+    }
+}
+
+const fn default_spacing() -> u16 {
+    1
+}
+
+// the tests use the built-in clock and git segments
+#[cfg(all(test, feature = "segments-clock", feature = "segments-git"))]
+mod tests {
+    #[cfg(any(feature = "toml", feature = "yaml"))]
+    use indoc::indoc;
+    use ratatui::prelude::*;
+
+    use super::*;
+    use crate::segments::Segment;
+
+    struct Weather;
+
+    impl Segment for Weather {
+        fn content(&mut self) -> Line<'static> {
+            Line::from("sunny")
+        }
+    }
+
+    #[test]
+    fn build() -> Result<(), StatusBarError> {
+        let config: StatusBarConfig = serde_json::from_str(
+            r#"{
+                "spacing": 0,
+                "separator": "|",
+                "separator_style": "following",
+                "sections": [
+                    "a",
+                    { "segment": "clock", "format": "clock", "style": "bold" },
+                    { "content": "c", "post_separator": "!" }
+                ]
+            }"#,
+        )
+        .map_err(|err| StatusBarError::Config(err.to_string()))?;
+        let status_bar = config.build()?;
+        let sections = &status_bar.sections;
+        assert_eq!(status_bar.spacing, 0);
+        assert_eq!(status_bar.separator_style, SeparatorStyle::Following);
+        assert_eq!(sections[0].post_separator, Some(Span::raw("|")));
+        assert_eq!(sections[1].content, Line::from("clock"));
+        assert_eq!(sections[1].style, Style::new().bold());
+        assert_eq!(sections[2].post_separator, Some(Span::raw("!")));
+        Ok(())
+    }
+
+    #[test]
+    fn custom_segment() -> Result<(), StatusBarError> {
+        let config = StatusBarConfig {
+            sections: vec![SectionConfig::Segment(SegmentConfig {
+                segment: "weather".to_string(),
+                name: None,
+                style: None,
+                role: None,
+                pre_separator: None,
+                post_separator: None,
+                options: SegmentOptions::new(),
+            })],
+            ..StatusBarConfig::default()
+        };
+        assert!(matches!(config.build(), Err(StatusBarError::Config(_))));
+
+        let mut registry = SegmentRegistry::new();
+        registry.register("weather", |_| Ok(Box::new(Weather)));
+        assert_eq!(
+            config.build_with(&registry)?.sections[0].content,
+            Line::from("sunny")
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_config() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::from_config(indoc! {r##"
+            flex = "Center"
+            theme = "light"
+            sections = ["#[fg=red]left", { content = "right", pre_separator = " " }]
+        "##})?;
+        assert_eq!(status_bar.flex, Flex::Center);
+        assert_eq!(status_bar.theme, Some(Theme::light()));
+        assert_eq!(status_bar.spacing, 1);
+        assert_eq!(status_bar.sections.len(), 2);
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn auto_theme() -> Result<(), StatusBarError> {
+        let config: StatusBarConfig = serde_json::from_str(r#"{ "theme": "auto" }"#)
+            .map_err(|err| StatusBarError::Config(err.to_string()))?;
+        assert!(matches!(
+            config.theme,
+            Some(ThemeConfig::Preset(ThemePreset::Auto))
+        ));
+        assert_eq!(
+            serde_json::to_string(&config.theme)
+                .map_err(|err| StatusBarError::Config(err.to_string()))?,
+            r#""auto""#
+        );
+        assert_eq!(config.build()?.theme, config.build()?.theme);
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn theme_styles_bar_and_sections() -> Result<(), StatusBarError> {
+        let theme = Theme::light();
+        let status_bar = StatusBar::from_config(indoc! {r#"
+            theme = "light"
+            sections = ["a", { segment = "clock", format = "b", role = "accent" }]
+        "#})?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Widget::render(&status_bar, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["a b "]);
+        expected.set_style(expected.area, theme.bar);
+        expected.set_style(Rect::new(2, 0, 1, 1), theme.accent);
+        assert_eq!(buf, expected);
+        assert_eq!(
+            status_bar,
+            StatusBar::new(2)
+                .theme(theme)
+                .section(0, "a")?
+                .section(1, StatusBarSection::from("b").role(Role::Accent))?
+        );
+
+        let status_bar = StatusBar::from_config(indoc! {r#"
+            theme = "light"
+            style = "bg=black"
+            inactive_style = "italic"
+        "#})?;
+        assert_eq!(status_bar.style, Style::new().on_black());
+        assert_eq!(status_bar.inactive_style, Some(Style::new().italic()));
+        Ok(())
+    }
+
+    #[cfg(feature = "scripting")]
+    #[test]
+    fn script_segment() -> Result<(), StatusBarError> {
+        let config: StatusBarConfig =
+            serde_json::from_str(r#"{"sections":[{"segment":"script","source":"40 + 2"}]}"#)
+                .map_err(|err| StatusBarError::Config(err.to_string()))?;
+        assert_eq!(config.build()?.sections[0].content, Line::from("42"));
+        Ok(())
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml() -> Result<(), StatusBarError> {
+        let config = StatusBarConfig::from_yaml(indoc! {"
+            spacing: 2
+            theme:
+              preset: light
+              accent: fg=black,bg=green
+            sections:
+              - left
+              - segment: git
+                path: .
+        "})?;
+        assert_eq!(config.spacing, 2);
+        assert_eq!(config.sections.len(), 2);
+        let theme = config.build()?.theme.unwrap();
+        assert_eq!(theme.accent, Style::new().black().on_green());
+        assert_eq!(theme.error, Theme::light().error);
+        assert!(StatusBarConfig::from_yaml("theme: sepia").is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn rejects_malformed_markup() -> Result<(), StatusBarError> {
+        assert!(matches!(
+            StatusBarConfig::from_toml(r##"sections = ["#[fg=red,blod]main"]"##),
+            Err(StatusBarError::Config(_))
+        ));
+        let error = StatusBarConfig::from_toml(indoc! {r#"
+            style = "fg=red,blod"
+            sections = ["main"]
+        "#})?
+        .build()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid configuration: `style`: Invalid template at byte 7: unknown attribute `blod`"
+        );
+        let config = StatusBarConfig::from_toml(indoc! {r##"
+            theme = { accent = "fg=grene" }
+            sections = [{ segment = "clock", post_separator = "#[bold" }]
+        "##})?;
+        assert!(matches!(config.build(), Err(StatusBarError::Config(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+//! Debug events describing the layout decisions of a status bar.
+
+use ratatui::prelude::*;
+use tracing::Level;
+
+use crate::{SectionFit, StatusBar};
+
+impl StatusBar<'_> {
+    /// Emits debug events describing how the sections are laid out in `area` and why sections
+    /// are truncated or left out.
+    pub(crate) fn trace_layout(&self, area: Rect) {
+        if !tracing::enabled!(Level::DEBUG) {
+            return;
+        }
+        let report = self.fits_in(area.width);
+        let breakpoint = self.active_breakpoint(area.width);
+        tracing::debug!(
+            width = area.width,
+            required = report.required(),
+            breakpoint = breakpoint.is_some(),
+            compact = self.compact && report.required() > usize::from(area.width),
+            "status bar layout"
+        );
+        let resolved = self.resolved(area);
+        let layout = resolved.as_ref().unwrap_or(self).layout(area);
+        for (index, (section, fit)) in self.sections.iter().zip(report.sections()).enumerate() {
+            let name = section.name.as_deref().unwrap_or_default();
+            let reason = match fit {
+                SectionFit::Fits | SectionFit::Truncated(_) => {
+                    let truncated = match fit {
+                        SectionFit::Truncated(columns) => *columns,
+                        _ => 0,
+                    };
+                    if let Some((_, rect)) = layout.iter().find(|(shown, _)| *shown == index) {
+                        tracing::debug!(
+                            index,
+                            name,
+                            x = rect.x,
+                            width = rect.width,
+                            truncated,
+                            "section placed"
+                        );
+                    }
+                    continue;
+                }
+                SectionFit::Hidden
+                    if breakpoint.is_some_and(|breakpoint| breakpoint.hides(Some(name))) =>
+                {
+                    "hidden by a breakpoint"
+                }
+                SectionFit::Hidden => "no room left next to pinned sections",
+                SectionFit::NotShown if section.hidden => "hidden",
+                SectionFit::NotShown if section.optional => "optional section not shown",
+                SectionFit::NotShown => "visibility predicate is false",
+            };
+            tracing::debug!(index, name, reason, "section left out");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use super::*;
+    use crate::{Breakpoint, StatusBarError, StatusBarSection};
+
+    /// Collects the fields of all events as `name=value` strings.
+    #[derive(Default)]
+    struct Events(Arc<Mutex<Vec<String>>>);
+
+    struct Fields(Vec<String>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.push(format!("{}={value:?}", field.name()));
+        }
+    }
+
+    impl Subscriber for Events {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields(Vec::new());
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.0.join(" "));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn explains_layout() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(0, "mode")?
+            .section(1, StatusBarSection::from("main").name("git"))?
+            .section(2, "file.rs")?
+            .breakpoint(10, Breakpoint::new().hide("git"));
+        let events = Events::default();
+        let log = events.0.clone();
+        tracing::subscriber::with_default(events, || {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            Widget::render(&status_bar, buf.area, &mut buf);
+        });
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "message=status bar layout width=10 required=12 breakpoint=true compact=false",
+                "message=section placed index=0 name=\"\" x=0 width=4 truncated=0",
+                "message=section left out index=1 name=\"git\" reason=\"hidden by a breakpoint\"",
+                "message=section placed index=2 name=\"\" x=5 width=5 truncated=2",
+            ]
+        );
+        Ok(())
+    }
+}
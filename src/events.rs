@@ -0,0 +1,540 @@
+//! Backend-agnostic mouse handling for status bars.
+//!
+//! [`StatusBar::handle_mouse`] turns a [`MouseEvent`] into hovering, clicking, dragging and
+//! scrolling of sections. The event is a small type of this crate, so the helpers work with any
+//! terminal backend: events of crossterm, termion and termwiz convert with [`From`] (requires the
+//! `crossterm`, `termion` or `termwiz` feature), others are constructed from the backend's events
+//! with [`MouseEvent::new`].
+//!
+//! The conversions make the 1-based coordinates of termion and termwiz 0-based. termwiz reports
+//! the buttons held rather than presses and releases, so use a [`TermwizMouse`] to tell them
+//! apart.
+//!
+//! # Examples
+//! ```
+//! use ratatui::prelude::*;
+//! use ratatui_statusbar::events::{BarEvent, MouseButton, MouseEvent, MouseEventKind};
+//! use ratatui_statusbar::{StatusBar, StatusBarState};
+//!
+//! let mut status_bar = StatusBar::new(2).section(0, "NORMAL")?.section(1, "main")?;
+//! let mut state = StatusBarState::new();
+//! let area = Rect::new(0, 9, 20, 1);
+//!
+//! let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 8, 9);
+//! assert_eq!(
+//!     status_bar.handle_mouse(area, click, &mut state),
+//!     Some(BarEvent::Click { index: 1, button: MouseButton::Left })
+//! );
+//! # Ok::<(), ratatui_statusbar::StatusBarError>(())
+//! ```
+//!
+//! Sections can have an [`Action`] that runs when they are clicked, or activated from the
+//! keyboard, with [`StatusBar::activate`]:
+//! ```
+//! use ratatui_statusbar::events::Action;
+//! use ratatui_statusbar::{StatusBar, StatusBarSection};
+//!
+//! let status_bar = StatusBar::new(1).section(
+//!     0,
+//!     StatusBarSection::from(" main").action(Action::Copy("main".into())),
+//! )?;
+//! let mut terminal = Vec::new();
+//! assert!(status_bar.activate(0, &mut terminal)?);
+//! assert_eq!(terminal, b"\x1b]52;c;bWFpbg==\x1b\\");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::io::{self, Write};
+
+use ratatui::layout::{Position, Rect};
+
+use crate::{osc, StatusBar, StatusBarState};
+
+/// A mouse event from any terminal backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MouseEvent {
+    /// What the mouse did.
+    pub kind: MouseEventKind,
+    /// The column of the pointer.
+    pub column: u16,
+    /// The row of the pointer.
+    pub row: u16,
+}
+
+/// What the mouse did in a [`MouseEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseEventKind {
+    /// A button was pressed.
+    Down(MouseButton),
+    /// A button was released.
+    Up(MouseButton),
+    /// The pointer moved while a button was held.
+    Drag(MouseButton),
+    /// The pointer moved without a button held.
+    Moved,
+    /// The wheel was scrolled up.
+    ScrollUp,
+    /// The wheel was scrolled down.
+    ScrollDown,
+    /// The wheel was scrolled left.
+    ScrollLeft,
+    /// The wheel was scrolled right.
+    ScrollRight,
+}
+
+/// A mouse button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    /// The left button.
+    Left,
+    /// The right button.
+    Right,
+    /// The middle button or wheel.
+    Middle,
+}
+
+/// What happened to the status bar in response to a [`MouseEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BarEvent {
+    /// A section was clicked.
+    Click {
+        /// The index of the section.
+        index: usize,
+        /// The button that was pressed.
+        button: MouseButton,
+    },
+    /// The pointer moved onto a section, or off all sections with `None`. Its
+    /// [tooltip](StatusBar::tooltip) is shown.
+    Hover(Option<usize>),
+    /// A dragged section was dropped at a new position.
+    Moved {
+        /// The index the section had.
+        from: usize,
+        /// The index the section has now.
+        to: usize,
+    },
+    /// A [scrollable](StatusBar::scrollable) status bar was scrolled.
+    Scrolled,
+    /// The [overflow indicator](StatusBar::overflow_indicator) was clicked, e.g. to show the
+    /// [list of left out sections](StatusBar::overflow_tooltip).
+    Overflow(MouseButton),
+}
+
+/// What a section does when it is clicked or activated from the keyboard, see
+/// [`StatusBarSection::action`](crate::StatusBarSection::action).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Action {
+    /// Copies the text to the system clipboard with [OSC 52](osc::copy_to_clipboard), e.g. the
+    /// branch name of a branch section or the full path of a shortened path.
+    Copy(String),
+}
+
+impl Action {
+    /// Runs the action by writing to the terminal.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the underlying writer.
+    pub fn run<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Self::Copy(text) => writer.write_all(osc::copy_to_clipboard(text).as_bytes())?,
+        }
+        writer.flush()
+    }
+}
+
+impl MouseEvent {
+    /// Creates a mouse event at `column` and `row`.
+    #[must_use]
+    pub const fn new(kind: MouseEventKind, column: u16, row: u16) -> Self {
+        Self { kind, column, row }
+    }
+
+    /// Returns the position of the pointer.
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        Position {
+            x: self.column,
+            y: self.row,
+        }
+    }
+}
+
+impl StatusBar<'_> {
+    /// Handles a mouse event for the [`StatusBar`] rendered into `area` and returns what
+    /// happened, if anything. See the [`events`](crate::events) module for an example.
+    ///
+    /// - Moving the pointer shows the [tooltip](Self::tooltip) of the section under it.
+    /// - Pressing a button on a section clicks it, and the left button starts
+    ///   [dragging](Self::end_drag) it. Releasing the left button drops it.
+    /// - Pressing a button on the [overflow indicator](Self::overflow_indicator) reports it.
+    /// - The wheel scrolls a [scrollable](Self::scrollable) status bar.
+    pub fn handle_mouse(
+        &mut self,
+        area: Rect,
+        event: impl Into<MouseEvent>,
+        state: &mut StatusBarState,
+    ) -> Option<BarEvent> {
+        let event = event.into();
+        let hit = self.hit_test_with(area, state, event.position());
+        match event.kind {
+            MouseEventKind::Moved => {
+                if state.tooltip() == hit {
+                    return None;
+                }
+                state.show_tooltip(hit);
+                Some(BarEvent::Hover(hit))
+            }
+            MouseEventKind::Down(button) => {
+                if hit.is_none() && self.overflow_hit(area, event.position()) {
+                    return Some(BarEvent::Overflow(button));
+                }
+                let index = hit?;
+                if button == MouseButton::Left {
+                    state.start_drag(index, event.column);
+                }
+                Some(BarEvent::Click { index, button })
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                state.drag_to(event.column);
+                None
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                state.drag_to(event.column);
+                let moved = self.end_drag(area, state);
+                state.end_drag();
+                moved.map(|(from, to)| BarEvent::Moved { from, to })
+            }
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollLeft
+                if self.scrollable && area.contains(event.position()) =>
+            {
+                state.scroll_left(1);
+                Some(BarEvent::Scrolled)
+            }
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollRight
+                if self.scrollable && area.contains(event.position()) =>
+            {
+                state.scroll_right(1);
+                Some(BarEvent::Scrolled)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the [action](crate::StatusBarSection::action) of the section at `index`.
+    #[must_use]
+    pub fn action(&self, index: usize) -> Option<&Action> {
+        self.sections.get(index)?.action.as_ref()
+    }
+
+    /// Runs the [action](crate::StatusBarSection::action) of the section at `index` and returns
+    /// whether it has one.
+    ///
+    /// Call it for the index of a [`BarEvent::Click`], or when the section is activated from the
+    /// keyboard, e.g. with Enter while the application has it focused.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the underlying writer.
+    pub fn activate<W: Write>(&self, index: usize, writer: &mut W) -> io::Result<bool> {
+        let Some(action) = self.action(index) else {
+            return Ok(false);
+        };
+        action.run(writer)?;
+        Ok(true)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl From<crossterm::event::MouseEvent> for MouseEvent {
+    fn from(event: crossterm::event::MouseEvent) -> Self {
+        use crossterm::event::MouseEventKind as Kind;
+        let kind = match event.kind {
+            Kind::Down(button) => MouseEventKind::Down(button.into()),
+            Kind::Up(button) => MouseEventKind::Up(button.into()),
+            Kind::Drag(button) => MouseEventKind::Drag(button.into()),
+            Kind::Moved => MouseEventKind::Moved,
+            Kind::ScrollUp => MouseEventKind::ScrollUp,
+            Kind::ScrollDown => MouseEventKind::ScrollDown,
+            Kind::ScrollLeft => MouseEventKind::ScrollLeft,
+            Kind::ScrollRight => MouseEventKind::ScrollRight,
+        };
+        Self::new(kind, event.column, event.row)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl From<crossterm::event::MouseButton> for MouseButton {
+    fn from(button: crossterm::event::MouseButton) -> Self {
+        match button {
+            crossterm::event::MouseButton::Left => Self::Left,
+            crossterm::event::MouseButton::Right => Self::Right,
+            crossterm::event::MouseButton::Middle => Self::Middle,
+        }
+    }
+}
+
+#[cfg(all(feature = "termion", unix))]
+impl From<termion::event::MouseEvent> for MouseEvent {
+    /// Converts a termion mouse event and makes its 1-based coordinates 0-based. termion doesn't
+    /// report which button is released or held, so releases and drags are reported for the left
+    /// button.
+    fn from(event: termion::event::MouseEvent) -> Self {
+        use termion::event::{MouseButton as Button, MouseEvent as Event};
+        let (kind, column, row) = match event {
+            Event::Press(button, column, row) => {
+                let kind = match button {
+                    Button::Left => MouseEventKind::Down(MouseButton::Left),
+                    Button::Right => MouseEventKind::Down(MouseButton::Right),
+                    Button::Middle => MouseEventKind::Down(MouseButton::Middle),
+                    Button::WheelUp => MouseEventKind::ScrollUp,
+                    Button::WheelDown => MouseEventKind::ScrollDown,
+                    Button::WheelLeft => MouseEventKind::ScrollLeft,
+                    Button::WheelRight => MouseEventKind::ScrollRight,
+                };
+                (kind, column, row)
+            }
+            Event::Release(column, row) => (MouseEventKind::Up(MouseButton::Left), column, row),
+            Event::Hold(column, row) => (MouseEventKind::Drag(MouseButton::Left), column, row),
+        };
+        Self::new(kind, column.saturating_sub(1), row.saturating_sub(1))
+    }
+}
+
+/// Converts termwiz mouse events, which report the buttons held, into presses, drags and
+/// releases by remembering the button held during the previous event.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::events::{MouseButton, MouseEventKind, TermwizMouse};
+/// use termwiz::input::{Modifiers, MouseButtons, MouseEvent};
+///
+/// let mut mouse = TermwizMouse::new();
+/// let event = |mouse_buttons| MouseEvent {
+///     x: 3,
+///     y: 1,
+///     mouse_buttons,
+///     modifiers: Modifiers::NONE,
+/// };
+/// let press = mouse.convert(event(MouseButtons::LEFT));
+/// assert_eq!(press.kind, MouseEventKind::Down(MouseButton::Left));
+/// let drag = mouse.convert(event(MouseButtons::LEFT));
+/// assert_eq!(drag.kind, MouseEventKind::Drag(MouseButton::Left));
+/// let release = mouse.convert(event(MouseButtons::NONE));
+/// assert_eq!(release.kind, MouseEventKind::Up(MouseButton::Left));
+/// ```
+#[cfg(feature = "termwiz")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TermwizMouse {
+    held: Option<MouseButton>,
+}
+
+#[cfg(feature = "termwiz")]
+impl TermwizMouse {
+    /// Creates a converter with no button held.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { held: None }
+    }
+
+    /// Converts `event`, a press if a button is held now but wasn't before, a drag if it still
+    /// is, and a release if it no longer is.
+    ///
+    /// The 1-based coordinates termwiz reports for terminals are made 0-based. On Windows,
+    /// termwiz reads the console, which reports 0-based coordinates.
+    pub fn convert(&mut self, event: termwiz::input::MouseEvent) -> MouseEvent {
+        use termwiz::input::MouseButtons as Buttons;
+        let buttons = event.mouse_buttons;
+        let positive = buttons.contains(Buttons::WHEEL_POSITIVE);
+        let kind = if buttons.contains(Buttons::VERT_WHEEL) {
+            if positive {
+                MouseEventKind::ScrollUp
+            } else {
+                MouseEventKind::ScrollDown
+            }
+        } else if buttons.contains(Buttons::HORZ_WHEEL) {
+            if positive {
+                MouseEventKind::ScrollLeft
+            } else {
+                MouseEventKind::ScrollRight
+            }
+        } else {
+            let button = [
+                (Buttons::LEFT, MouseButton::Left),
+                (Buttons::RIGHT, MouseButton::Right),
+                (Buttons::MIDDLE, MouseButton::Middle),
+            ]
+            .into_iter()
+            .find(|(flag, _)| buttons.contains(flag.clone()))
+            .map(|(_, button)| button);
+            let kind = match (self.held, button) {
+                (None, Some(button)) => MouseEventKind::Down(button),
+                (Some(held), Some(_)) => MouseEventKind::Drag(held),
+                (Some(held), None) => MouseEventKind::Up(held),
+                (None, None) => MouseEventKind::Moved,
+            };
+            self.held = match kind {
+                MouseEventKind::Down(button) | MouseEventKind::Drag(button) => Some(button),
+                _ => None,
+            };
+            kind
+        };
+        let (column, row) = if cfg!(windows) {
+            (event.x, event.y)
+        } else {
+            (event.x.saturating_sub(1), event.y.saturating_sub(1))
+        };
+        MouseEvent::new(kind, column, row)
+    }
+}
+
+#[cfg(feature = "termwiz")]
+impl From<termwiz::input::MouseEvent> for MouseEvent {
+    /// Converts a termwiz mouse event without knowing the buttons held before, so an event with
+    /// a button held is a press and one without is a move. See [`TermwizMouse`] to convert drags
+    /// and releases.
+    fn from(event: termwiz::input::MouseEvent) -> Self {
+        TermwizMouse::new().convert(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StatusBarError;
+
+    #[test]
+    fn mouse() -> Result<(), StatusBarError> {
+        let mut status_bar = StatusBar::new(3)
+            .section(0, "a")?
+            .section(1, "b")?
+            .section(2, "c")?;
+        let area = Rect::new(0, 0, 10, 1);
+        let mut state = StatusBarState::new();
+        let mut handle = |kind, column| {
+            status_bar.handle_mouse(area, MouseEvent::new(kind, column, 0), &mut state)
+        };
+        assert_eq!(
+            handle(MouseEventKind::Moved, 0),
+            Some(BarEvent::Hover(Some(0)))
+        );
+        assert_eq!(handle(MouseEventKind::Moved, 0), None);
+        assert_eq!(
+            handle(MouseEventKind::Moved, 1),
+            Some(BarEvent::Hover(None))
+        );
+        assert_eq!(
+            handle(MouseEventKind::Down(MouseButton::Left), 0),
+            Some(BarEvent::Click {
+                index: 0,
+                button: MouseButton::Left
+            })
+        );
+        assert_eq!(handle(MouseEventKind::Drag(MouseButton::Left), 3), None);
+        assert_eq!(
+            handle(MouseEventKind::Up(MouseButton::Left), 5),
+            Some(BarEvent::Moved { from: 0, to: 2 })
+        );
+        assert_eq!(handle(MouseEventKind::Down(MouseButton::Left), 1), None);
+        assert_eq!(handle(MouseEventKind::ScrollDown, 0), None);
+        assert_eq!(state.drag(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn actions() -> color_eyre::Result<()> {
+        let status_bar = StatusBar::new(2).section(0, "plain")?.section(
+            1,
+            crate::StatusBarSection::from("…/src/lib.rs")
+                .action(Action::Copy("/home/user/crate/src/lib.rs".into())),
+        )?;
+        let mut output = Vec::new();
+        assert!(!status_bar.activate(0, &mut output)?);
+        assert!(!status_bar.activate(2, &mut output)?);
+        assert!(output.is_empty());
+        assert!(status_bar.activate(1, &mut output)?);
+        assert_eq!(
+            String::from_utf8(output)?,
+            osc::copy_to_clipboard("/home/user/crate/src/lib.rs")
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn crossterm_events() {
+        let event = crossterm::event::MouseEvent {
+            kind: crossterm::event::MouseEventKind::Drag(crossterm::event::MouseButton::Right),
+            column: 3,
+            row: 4,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        assert_eq!(
+            MouseEvent::from(event),
+            MouseEvent::new(MouseEventKind::Drag(MouseButton::Right), 3, 4)
+        );
+    }
+
+    #[cfg(all(feature = "termion", unix))]
+    #[test]
+    fn termion_events() {
+        use termion::event::{MouseButton as Button, MouseEvent as Event};
+        assert_eq!(
+            MouseEvent::from(Event::Press(Button::Right, 1, 10)),
+            MouseEvent::new(MouseEventKind::Down(MouseButton::Right), 0, 9)
+        );
+        assert_eq!(
+            MouseEvent::from(Event::Press(Button::WheelLeft, 4, 1)),
+            MouseEvent::new(MouseEventKind::ScrollLeft, 3, 0)
+        );
+        assert_eq!(
+            MouseEvent::from(Event::Release(2, 2)),
+            MouseEvent::new(MouseEventKind::Up(MouseButton::Left), 1, 1)
+        );
+    }
+
+    #[cfg(all(feature = "termwiz", not(windows)))]
+    #[test]
+    fn termwiz_events() {
+        use termwiz::input::{Modifiers, MouseButtons};
+        let event = |mouse_buttons, x| termwiz::input::MouseEvent {
+            x,
+            y: 1,
+            mouse_buttons,
+            modifiers: Modifiers::NONE,
+        };
+        let mut mouse = TermwizMouse::new();
+        let kinds = [
+            (MouseButtons::NONE, MouseEventKind::Moved),
+            (
+                MouseButtons::RIGHT,
+                MouseEventKind::Down(MouseButton::Right),
+            ),
+            (
+                MouseButtons::RIGHT | MouseButtons::LEFT,
+                MouseEventKind::Drag(MouseButton::Right),
+            ),
+            (MouseButtons::NONE, MouseEventKind::Up(MouseButton::Right)),
+            (
+                MouseButtons::HORZ_WHEEL | MouseButtons::WHEEL_POSITIVE,
+                MouseEventKind::ScrollLeft,
+            ),
+        ];
+        for (buttons, kind) in kinds {
+            assert_eq!(
+                mouse.convert(event(buttons, 5)),
+                MouseEvent::new(kind, 4, 0)
+            );
+        }
+        assert_eq!(
+            MouseEvent::from(event(MouseButtons::VERT_WHEEL, 1)),
+            MouseEvent::new(MouseEventKind::ScrollDown, 0, 0)
+        );
+    }
+}
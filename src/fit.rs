@@ -0,0 +1,194 @@
+//! Checking whether a status bar fits a width without rendering it.
+
+use ratatui::prelude::*;
+
+use crate::{RenderContext, StatusBar, StatusBarError};
+
+/// How a [`StatusBar`] fits into a width, returned by [`StatusBar::fits_in`].
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::{SectionFit, StatusBar, StatusBarSection, Truncation};
+///
+/// let status_bar = StatusBar::new(2)
+///     .section(0, "NORMAL")?
+///     .section(1, StatusBarSection::from("src/lib.rs").truncation(Truncation::Start))?;
+/// for width in [80, 120, 200] {
+///     assert!(status_bar.fits_in(width).fits());
+/// }
+///
+/// let report = status_bar.fits_in(12);
+/// assert_eq!(report.required(), 17);
+/// assert_eq!(report.sections(), [SectionFit::Fits, SectionFit::Truncated(4)]);
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FitReport {
+    required: usize,
+    available: u16,
+    sections: Vec<SectionFit>,
+}
+
+/// How a single section fits, see [`FitReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SectionFit {
+    /// The section is shown in full.
+    Fits,
+    /// The section is shown, but this many columns of it are cut off.
+    Truncated(usize),
+    /// The section is left out for lack of space or by a [`Breakpoint`](crate::Breakpoint).
+    Hidden,
+    /// The section is [hidden](crate::StatusBarSection::hidden) or its
+    /// [visibility predicate](crate::StatusBarSection::visible_when) is false.
+    NotShown,
+}
+
+impl FitReport {
+    /// Returns whether all shown sections fit in full.
+    #[must_use]
+    pub fn fits(&self) -> bool {
+        self.sections
+            .iter()
+            .all(|fit| matches!(fit, SectionFit::Fits | SectionFit::NotShown))
+    }
+
+    /// Returns the number of columns the sections and gaps shown at the width need.
+    #[must_use]
+    pub const fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the number of columns available.
+    #[must_use]
+    pub const fn available(&self) -> u16 {
+        self.available
+    }
+
+    /// Returns how each section fits, by index.
+    #[must_use]
+    pub fn sections(&self) -> &[SectionFit] {
+        &self.sections
+    }
+
+    /// Returns the indices of the sections that are truncated and by how many columns.
+    pub fn truncated(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.sections
+            .iter()
+            .enumerate()
+            .filter_map(|(index, fit)| match fit {
+                SectionFit::Truncated(columns) => Some((index, *columns)),
+                _ => None,
+            })
+    }
+
+    /// Returns the indices of the sections that are left out.
+    pub fn hidden(&self) -> impl Iterator<Item = usize> + '_ {
+        self.sections
+            .iter()
+            .enumerate()
+            .filter_map(|(index, fit)| (*fit == SectionFit::Hidden).then_some(index))
+    }
+
+    /// Converts the report into an error if the status bar doesn't fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::WidthOverflow`] if any section is truncated or hidden.
+    pub fn into_result(self) -> Result<(), StatusBarError> {
+        if self.fits() {
+            Ok(())
+        } else {
+            Err(StatusBarError::WidthOverflow {
+                required: self.required,
+                available: self.available,
+            })
+        }
+    }
+}
+
+impl StatusBar<'_> {
+    /// Reports whether the [`StatusBar`] fits into `width` columns, and which sections would be
+    /// truncated or hidden, without rendering it. See [`FitReport`] for an example.
+    ///
+    /// Sections are compared with the content they show at `width`, i.e. with their short content
+    /// if a [`Breakpoint`](crate::Breakpoint) selects it.
+    #[must_use]
+    pub fn fits_in(&self, width: u16) -> FitReport {
+        let area = Rect::new(0, 0, width, self.height());
+        let resolved = self.resolved(area);
+        let bar = resolved.as_ref().unwrap_or(self);
+        let short = bar
+            .active_breakpoint(width)
+            .is_some_and(|breakpoint| breakpoint.short);
+        let context = RenderContext {
+            area,
+            inactive: self.inactive,
+        };
+        let layout = bar.layout(area);
+        let sections = bar
+            .sections
+            .iter()
+            .enumerate()
+            .map(|(index, section)| {
+                if !section.is_visible(&context) {
+                    return SectionFit::NotShown;
+                }
+                let Some((_, rect)) = layout.iter().find(|(shown, _)| *shown == index) else {
+                    return SectionFit::Hidden;
+                };
+                match section
+                    .width_for(short)
+                    .saturating_sub(usize::from(rect.width))
+                {
+                    0 => SectionFit::Fits,
+                    columns => SectionFit::Truncated(columns),
+                }
+            })
+            .collect();
+        FitReport {
+            required: bar.required_width(area),
+            available: width,
+            sections,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Breakpoint, StatusBarSection};
+
+    #[test]
+    fn report() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(4)
+            .section(0, "mode")?
+            .section(1, StatusBarSection::from("git").name("git"))?
+            .section(2, StatusBarSection::from("x").hidden(true))?
+            .section(3, "file.rs")?
+            .breakpoint(12, Breakpoint::new().hide("git"));
+        let report = status_bar.fits_in(20);
+        assert!(report.fits());
+        assert_eq!(report.required(), 16);
+
+        let report = status_bar.fits_in(10);
+        assert_eq!(
+            report.sections(),
+            [
+                SectionFit::Fits,
+                SectionFit::Hidden,
+                SectionFit::NotShown,
+                SectionFit::Truncated(2)
+            ]
+        );
+        assert_eq!(report.truncated().collect::<Vec<_>>(), [(3, 2)]);
+        assert_eq!(report.hidden().collect::<Vec<_>>(), [1]);
+        assert!(matches!(
+            report.into_result(),
+            Err(StatusBarError::WidthOverflow {
+                required: 12,
+                available: 10
+            })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,296 @@
+//! Human-readable formatting of sizes, rates, durations and counts for segment content.
+//!
+//! The functions format with the default options of [`Humanize`], which can be changed for e.g.
+//! more precision or decimal units. All unit suffixes are [labels](crate::labels), so they can
+//! be translated.
+//!
+//! # Examples
+//! ```
+//! use std::time::Duration;
+//!
+//! use ratatui_statusbar::format::{self, Humanize, UnitStyle};
+//!
+//! assert_eq!(format::bytes(1_572_864), "1.5 MiB");
+//! assert_eq!(format::rate(2048.0), "2.0 KiB/s");
+//! assert_eq!(format::duration(Duration::from_secs(192)), "3m 12s");
+//! assert_eq!(format::count(12_400), "12.4k");
+//! assert_eq!(format::percent(42.0), "42.0%");
+//!
+//! let humanize = Humanize::new().precision(2).units(UnitStyle::Decimal).spaced(false);
+//! assert_eq!(humanize.bytes(1_572_864), "1.57MB");
+//! ```
+
+use std::time::Duration;
+
+use crate::labels;
+
+/// The labels of sizes in powers of 1024.
+const BINARY: [&str; 7] = [
+    labels::BYTES,
+    labels::KIBIBYTES,
+    labels::MEBIBYTES,
+    labels::GIBIBYTES,
+    labels::TEBIBYTES,
+    labels::PEBIBYTES,
+    labels::EXBIBYTES,
+];
+
+/// The labels of sizes in powers of 1000.
+const DECIMAL: [&str; 7] = [
+    labels::BYTES,
+    labels::KILOBYTES,
+    labels::MEGABYTES,
+    labels::GIGABYTES,
+    labels::TERABYTES,
+    labels::PETABYTES,
+    labels::EXABYTES,
+];
+
+/// The labels of counts in powers of 1000, starting with counts under 1000, which have none.
+const COUNT: [&str; 7] = [
+    "",
+    labels::THOUSANDS,
+    labels::MILLIONS,
+    labels::BILLIONS,
+    labels::TRILLIONS,
+    labels::QUADRILLIONS,
+    labels::QUINTILLIONS,
+];
+
+/// Whether sizes are written in powers of 1024 or of 1000.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum UnitStyle {
+    /// Powers of 1024 with IEC units: `KiB`, `MiB`, `GiB`.
+    #[default]
+    Binary,
+    /// Powers of 1000 with SI units: `kB`, `MB`, `GB`.
+    Decimal,
+}
+
+/// Options for human-readable formatting, see the [module](self) for an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Humanize {
+    precision: usize,
+    units: UnitStyle,
+    spaced: bool,
+    duration_parts: usize,
+}
+
+impl Default for Humanize {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Humanize {
+    /// Creates the default options: one decimal, binary units, a space before size units and
+    /// durations in up to two units.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            precision: 1,
+            units: UnitStyle::Binary,
+            spaced: true,
+            duration_parts: 2,
+        }
+    }
+
+    /// Sets the number of decimals of scaled sizes, rates, counts and percentages.
+    #[must_use]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets whether sizes and rates use binary or decimal units.
+    #[must_use]
+    pub const fn units(mut self, units: UnitStyle) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Sets whether a space separates sizes and rates from their unit: `1.5 MiB` or `1.5MiB`.
+    #[must_use]
+    pub const fn spaced(mut self, spaced: bool) -> Self {
+        self.spaced = spaced;
+        self
+    }
+
+    /// Sets how many units durations are written in, from the largest: `1h 3m` or `1h 3m 12s`.
+    #[must_use]
+    pub const fn duration_parts(mut self, parts: usize) -> Self {
+        self.duration_parts = parts;
+        self
+    }
+
+    /// Formats a size in bytes: `1.5 MiB`.
+    #[must_use]
+    pub fn bytes(&self, bytes: u64) -> String {
+        self.scaled_bytes(bytes as f64)
+    }
+
+    /// Formats a transfer rate in bytes per second: `1.5 MiB/s`.
+    #[must_use]
+    pub fn rate(&self, bytes_per_second: f64) -> String {
+        format!(
+            "{}{}",
+            self.scaled_bytes(bytes_per_second),
+            labels::label(labels::PER_SECOND)
+        )
+    }
+
+    /// Formats a duration in its largest units, leaving out units that are zero: `3m 12s`,
+    /// `1h`. Durations under a second are written in milliseconds.
+    #[must_use]
+    pub fn duration(&self, duration: Duration) -> String {
+        let secs = duration.as_secs();
+        if secs == 0 {
+            return format!(
+                "{}{}",
+                duration.as_millis(),
+                labels::label(labels::MILLISECONDS)
+            );
+        }
+        let parts = [
+            (secs / 86_400, labels::DAYS),
+            (secs / 3600 % 24, labels::HOURS),
+            (secs / 60 % 60, labels::MINUTES),
+            (secs % 60, labels::SECONDS),
+        ];
+        let first = parts.iter().position(|(value, _)| *value > 0).unwrap_or(3);
+        parts[first..]
+            .iter()
+            .take(self.duration_parts.max(1))
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| format!("{value}{}", labels::label(unit)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Formats a count with an SI suffix: `12.4k`, `3.0M`. Counts under 1000 are written as they
+    /// are.
+    #[must_use]
+    pub fn count(&self, count: u64) -> String {
+        let (value, unit) = self.scale(count as f64, 1000.0, &COUNT);
+        if unit.is_empty() {
+            count.to_string()
+        } else {
+            format!(
+                "{value:.precision$}{}",
+                labels::label(unit),
+                precision = self.precision
+            )
+        }
+    }
+
+    /// Formats a percentage with the [`labels::PERCENT`] label: `42.0%`.
+    #[must_use]
+    pub fn percent(&self, percent: f64) -> String {
+        labels::format(
+            labels::PERCENT,
+            format!("{percent:.precision$}", precision = self.precision),
+        )
+    }
+
+    fn scaled_bytes(&self, bytes: f64) -> String {
+        let (base, units) = match self.units {
+            UnitStyle::Binary => (1024.0, &BINARY),
+            UnitStyle::Decimal => (1000.0, &DECIMAL),
+        };
+        let (value, unit) = self.scale(bytes, base, units);
+        let space = if self.spaced { " " } else { "" };
+        let precision = if unit == labels::BYTES {
+            0
+        } else {
+            self.precision
+        };
+        format!("{value:.precision$}{space}{}", labels::label(unit))
+    }
+
+    /// Divides `value` by `base` until it is written with less than `base` before the decimal
+    /// point at the precision, and returns it with the matching unit.
+    fn scale<'u>(&self, mut value: f64, base: f64, units: &[&'u str]) -> (f64, &'u str) {
+        let mut unit = 0;
+        let precision = i32::try_from(self.precision).unwrap_or(i32::MAX);
+        let rounded = |value: f64, precision: i32| {
+            let factor = 10f64.powi(precision);
+            (value * factor).round() / factor
+        };
+        while unit + 1 < units.len()
+            && rounded(value, if unit == 0 { 0 } else { precision }) >= base
+        {
+            value /= base;
+            unit += 1;
+        }
+        (value, units[unit])
+    }
+}
+
+/// Formats a size in bytes with the default [`Humanize`] options: `1.5 MiB`.
+#[must_use]
+pub fn bytes(bytes: u64) -> String {
+    Humanize::new().bytes(bytes)
+}
+
+/// Formats a transfer rate in bytes per second with the default [`Humanize`] options:
+/// `1.5 MiB/s`.
+#[must_use]
+pub fn rate(bytes_per_second: f64) -> String {
+    Humanize::new().rate(bytes_per_second)
+}
+
+/// Formats a duration with the default [`Humanize`] options: `3m 12s`.
+#[must_use]
+pub fn duration(duration: Duration) -> String {
+    Humanize::new().duration(duration)
+}
+
+/// Formats a count with the default [`Humanize`] options: `12.4k`.
+#[must_use]
+pub fn count(count: u64) -> String {
+    Humanize::new().count(count)
+}
+
+/// Formats a percentage with the default [`Humanize`] options: `42.0%`.
+#[must_use]
+pub fn percent(percent: f64) -> String {
+    Humanize::new().percent(percent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humanize() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(1024), "1.0 KiB");
+        assert_eq!(bytes(1024 * 1024 - 1), "1.0 MiB");
+        let decimal = Humanize::new().units(UnitStyle::Decimal).precision(0);
+        assert_eq!(decimal.bytes(1_500_000), "2 MB");
+        assert_eq!(decimal.rate(999.0), "999 B/s");
+
+        assert_eq!(duration(Duration::from_millis(250)), "250ms");
+        assert_eq!(duration(Duration::from_secs(3600)), "1h");
+        assert_eq!(duration(Duration::from_secs(3 * 86_400 + 5)), "3d");
+        assert_eq!(
+            Humanize::new()
+                .duration_parts(4)
+                .duration(Duration::from_secs(3 * 86_400 + 5)),
+            "3d 5s"
+        );
+
+        assert_eq!(count(999), "999");
+        assert_eq!(count(999_960), "1.0M");
+        assert_eq!(Humanize::new().precision(0).count(1500), "2k");
+
+        assert_eq!(percent(99.95), "100.0%");
+        assert_eq!(Humanize::new().precision(0).percent(42.0), "42%");
+    }
+}
@@ -0,0 +1,124 @@
+//! Status bars laid out once and rendered by copying.
+
+use ratatui::layout::Position;
+use ratatui::prelude::*;
+use ratatui::widgets::WidgetRef;
+
+use crate::StatusBar;
+
+/// A [`StatusBar`] rendered ahead of time at a fixed width, created with [`StatusBar::freeze`].
+///
+/// Layout, truncation and separators are resolved once, so rendering a frozen bar is a plain copy
+/// of its cells. This pays off for bars with static content that are rendered every frame. Freeze
+/// the bar again when its content or the width of the terminal changes.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::StatusBar;
+///
+/// let frozen = StatusBar::new(2)
+///     .section(0, "NORMAL")?
+///     .section(1, "main")?
+///     .freeze(12);
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+/// frozen.render(buf.area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(vec!["NORMAL main "]));
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrozenStatusBar {
+    buffer: Buffer,
+    areas: Vec<Rect>,
+}
+
+impl FrozenStatusBar {
+    /// Returns the width the bar was frozen at.
+    #[must_use]
+    pub const fn width(&self) -> u16 {
+        self.buffer.area.width
+    }
+
+    /// Returns the number of rows of the bar.
+    #[must_use]
+    pub const fn height(&self) -> u16 {
+        self.buffer.area.height
+    }
+
+    /// Returns the index of the section at `position` when the bar is rendered into `area`,
+    /// like [`StatusBar::hit_test`].
+    #[must_use]
+    pub fn hit_test(&self, area: Rect, position: impl Into<Position>) -> Option<usize> {
+        let position = position.into();
+        if !area.contains(position) {
+            return None;
+        }
+        let relative = Position {
+            x: position.x - area.x,
+            y: position.y - area.y,
+        };
+        self.areas.iter().position(|rect| rect.contains(relative))
+    }
+}
+
+impl StatusBar<'_> {
+    /// Lays out and renders the [`StatusBar`] once at `width` columns, see [`FrozenStatusBar`].
+    #[must_use]
+    pub fn freeze(&self, width: u16) -> FrozenStatusBar {
+        let area = Rect::new(0, 0, width, self.height());
+        let mut buffer = Buffer::empty(area);
+        WidgetRef::render_ref(self, area, &mut buffer);
+        FrozenStatusBar {
+            buffer,
+            areas: self.areas(area),
+        }
+    }
+}
+
+impl Widget for FrozenStatusBar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for FrozenStatusBar {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let width = area.width.min(self.width());
+        let height = area.height.min(self.height());
+        for y in 0..height {
+            for x in 0..width {
+                *buf.get_mut(area.x + x, area.y + y) = self.buffer.get(x, y).clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{StatusBarError, StatusBarSection};
+
+    #[test]
+    fn render_and_hit_test() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(2)
+            .section(0, ("a", Style::new().red()))?
+            .section(1, StatusBarSection::from("bcd").pre_separator("|"))?;
+        let frozen = status_bar.freeze(5);
+        assert_eq!(frozen.width(), 5);
+
+        let area = Rect::new(1, 1, 4, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        frozen.render_ref(area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["      ", " a |b "]);
+        expected.set_style(Rect::new(1, 1, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+
+        assert_eq!(frozen.hit_test(area, (1, 1)), Some(0));
+        assert_eq!(frozen.hit_test(area, (3, 1)), Some(1));
+        assert_eq!(frozen.hit_test(area, (2, 1)), None);
+        assert_eq!(frozen.hit_test(area, (0, 1)), None);
+        Ok(())
+    }
+}
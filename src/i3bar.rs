@@ -0,0 +1,308 @@
+//! An adapter for the [i3bar protocol](https://i3wm.org/docs/i3bar-protocol.html), which is also
+//! spoken by swaybar.
+//!
+//! Blocks produced by status line generators such as i3status or i3blocks can be turned into
+//! sections, and the sections of a [`StatusBar`] can be written as blocks, so the same content can
+//! drive both a terminal status bar and a desktop bar.
+//!
+//! # Examples
+//! ```
+//! use ratatui_statusbar::i3bar;
+//!
+//! // i3status writes a header, the start of an endless array, and then one line per update.
+//! let mut blocks = Vec::new();
+//! for line in [
+//!     r#"{"version":1}"#,
+//!     "[",
+//!     r##"[{"full_text":"W: up","color":"#00FF00"},{"full_text":"12:00"}]"##,
+//!     r#",[{"full_text":"W: down","urgent":true},{"full_text":"12:01"}]"#,
+//! ] {
+//!     if let Some(update) = i3bar::parse_line(line).unwrap() {
+//!         blocks = update;
+//!     }
+//! }
+//! let status_bar = i3bar::status_bar(&blocks);
+//!
+//! // Going the other way, print the header once and then a line per update.
+//! println!("{}", i3bar::Header::default().to_json().unwrap());
+//! println!("[");
+//! println!("{},", i3bar::to_json(&i3bar::blocks(&status_bar)).unwrap());
+//! ```
+
+use ratatui::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::color::to_rgb;
+use crate::{StatusBar, StatusBarError, StatusBarSection};
+
+/// The header that starts an i3bar protocol stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    /// The protocol version, always 1.
+    pub version: u32,
+    /// Whether the bar should send click events on standard input.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub click_events: bool,
+}
+
+impl Default for Header {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            click_events: false,
+        }
+    }
+}
+
+impl Header {
+    /// Serializes the header as a single line of JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Protocol`] if serialization fails.
+    pub fn to_json(&self) -> Result<String, StatusBarError> {
+        serde_json::to_string(self).map_err(|err| StatusBarError::Protocol(err.to_string()))
+    }
+}
+
+/// A single block of an i3bar status line.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Block {
+    /// The text of the block.
+    pub full_text: String,
+    /// A shorter text used when the bar runs out of space.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_text: Option<String>,
+    /// The text color as `#RRGGBB` or `#RRGGBBAA`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The background color as `#RRGGBB` or `#RRGGBBAA`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    /// Identifies the kind of block, e.g. `"wireless"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Identifies the instance of the block, e.g. `"wlan0"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Whether the block needs attention.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub urgent: bool,
+    /// Whether a separator is drawn after the block, `true` if not given. A block without a
+    /// separator runs into the next one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub separator: Option<bool>,
+}
+
+/// Parses one line of an i3bar protocol stream.
+///
+/// Returns `Ok(None)` for the header, the opening `[` of the endless array and blank lines, and
+/// the blocks of the status line otherwise. The comma that precedes every status line but the
+/// first is accepted.
+///
+/// # Errors
+///
+/// Returns [`StatusBarError::Protocol`] if the line is not valid i3bar JSON.
+pub fn parse_line(line: &str) -> Result<Option<Vec<Block>>, StatusBarError> {
+    let line = line.trim();
+    let line = line.strip_prefix(',').unwrap_or(line).trim_start();
+    if line.is_empty() || line == "[" || line.starts_with('{') {
+        return Ok(None);
+    }
+    serde_json::from_str(line)
+        .map(Some)
+        .map_err(|err| StatusBarError::Protocol(err.to_string()))
+}
+
+/// Serializes blocks as a single line of JSON, without the separating comma.
+///
+/// # Errors
+///
+/// Returns [`StatusBarError::Protocol`] if serialization fails.
+pub fn to_json(blocks: &[Block]) -> Result<String, StatusBarError> {
+    serde_json::to_string(blocks).map_err(|err| StatusBarError::Protocol(err.to_string()))
+}
+
+/// Creates a [`StatusBar`] with one section per block.
+#[must_use]
+pub fn status_bar(blocks: &[Block]) -> StatusBar<'static> {
+    StatusBar {
+        sections: blocks.iter().map(StatusBarSection::from).collect(),
+        ..StatusBar::new(0)
+    }
+}
+
+/// Converts the sections of a [`StatusBar`] to blocks.
+#[must_use]
+pub fn blocks(status_bar: &StatusBar) -> Vec<Block> {
+    status_bar.sections.iter().map(Block::from).collect()
+}
+
+/// Joins the `name` and `instance` of a block into the name of its section.
+fn section_name(name: Option<&str>, instance: Option<&str>) -> Option<String> {
+    match (name, instance) {
+        (Some(name), Some(instance)) => Some(format!("{name}:{instance}")),
+        (Some(name), None) => Some(name.to_string()),
+        (None, Some(instance)) => Some(format!(":{instance}")),
+        (None, None) => None,
+    }
+}
+
+/// Splits the name of a section into the `name` and `instance` of its block.
+fn block_name(section_name: Option<&str>) -> (Option<String>, Option<String>) {
+    let Some(section_name) = section_name else {
+        return (None, None);
+    };
+    match section_name.split_once(':') {
+        Some((name, instance)) => (
+            Some(name)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string),
+            Some(instance.to_string()),
+        ),
+        None => (Some(section_name.to_string()), None),
+    }
+}
+
+/// The section is [named](StatusBarSection::name) after the `name` of the block, followed by a
+/// colon and the `instance` if there is one, e.g. `"wireless:wlan0"`, so that click events can be
+/// routed back to it. The `short_text` becomes the
+/// [short content](StatusBarSection::short_content). A block with `separator` set to `false` has
+/// no [gap](StatusBarSection::gap_after) after its section.
+impl From<&Block> for StatusBarSection<'static> {
+    fn from(block: &Block) -> Self {
+        let mut style = Style::new();
+        if let Some(color) = block.color.as_deref().and_then(parse_color) {
+            style = style.fg(color);
+        }
+        if let Some(color) = block.background.as_deref().and_then(parse_color) {
+            style = style.bg(color);
+        }
+        if block.urgent {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        let mut section = StatusBarSection::from(Line::from(block.full_text.clone())).style(style);
+        section.name = section_name(block.name.as_deref(), block.instance.as_deref());
+        if let Some(short_text) = &block.short_text {
+            section = section.short_content(short_text.clone());
+        }
+        if block.separator == Some(false) {
+            section = section.gap_after(0);
+        }
+        section
+    }
+}
+
+/// The `name` and `instance` of the block are taken from the name of the section, see the
+/// conversion from a [`Block`].
+impl From<&StatusBarSection<'_>> for Block {
+    fn from(section: &StatusBarSection<'_>) -> Self {
+        let spans = section
+            .pre_separator
+            .iter()
+            .chain(&section.content.spans)
+            .chain(&section.post_separator);
+        let full_text = spans.clone().map(|span| span.content.as_ref()).collect();
+        let short_text = section.short_content.as_ref().map(|short| {
+            section
+                .pre_separator
+                .iter()
+                .chain(&short.spans)
+                .chain(&section.post_separator)
+                .map(|span| span.content.as_ref())
+                .collect()
+        });
+        let (name, instance) = block_name(section.name.as_deref());
+        let base = section.style.patch(section.content.style);
+        let style = spans
+            .map(|span| base.patch(span.style))
+            .find(|style| *style != Style::default())
+            .unwrap_or(base);
+        Self {
+            full_text,
+            short_text,
+            name,
+            instance,
+            color: style.fg.and_then(format_color),
+            background: style.bg.and_then(format_color),
+            urgent: style.add_modifier.contains(Modifier::REVERSED),
+            separator: (section.gap_after == Some(0)).then_some(false),
+        }
+    }
+}
+
+fn parse_color(color: &str) -> Option<Color> {
+    color.get(..7)?.parse().ok()
+}
+
+fn format_color(color: Color) -> Option<String> {
+    to_rgb(color).map(|(r, g, b)| format!("#{r:02X}{g:02X}{b:02X}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_stream() -> Result<(), StatusBarError> {
+        assert_eq!(parse_line(r#"{"version":1,"click_events":true}"#)?, None);
+        assert_eq!(parse_line("[")?, None);
+        let blocks = parse_line(r##",[{"full_text":"a","color":"#FF000080","urgent":true}]"##)?;
+        let blocks = blocks.unwrap_or_default();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(
+            StatusBarSection::from(&blocks[0]).style,
+            Style::new().fg(Color::Rgb(255, 0, 0)).reversed()
+        );
+        assert!(parse_line("[{").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn emit_blocks() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(2)
+            .section(0, Span::styled("main", Style::new().green().on_black()))?
+            .section(
+                1,
+                StatusBarSection::default()
+                    .pre_separator("| ")
+                    .content("12:00"),
+            )?;
+        assert_eq!(
+            to_json(&blocks(&status_bar))?,
+            r##"[{"full_text":"main","color":"#008000","background":"#000000"},{"full_text":"| 12:00"}]"##
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), StatusBarError> {
+        let line = r#"[{"full_text":"W: up","short_text":"W","name":"wireless","instance":"wlan0"},{"full_text":"12:00","name":"time"},{"full_text":"x"}]"#;
+        let blocks = parse_line(line)?.unwrap_or_default();
+        let status_bar = status_bar(&blocks);
+        assert_eq!(
+            status_bar.sections[0].name.as_deref(),
+            Some("wireless:wlan0")
+        );
+        assert_eq!(status_bar.sections[0].short_content, Some(Line::from("W")));
+        assert_eq!(status_bar.sections[1].name.as_deref(), Some("time"));
+        assert_eq!(super::blocks(&status_bar), blocks);
+        assert_eq!(to_json(&super::blocks(&status_bar))?, line);
+        Ok(())
+    }
+
+    #[test]
+    fn separators() -> Result<(), StatusBarError> {
+        let line = r#"[{"full_text":"a","separator":false},{"full_text":"b","separator":true},{"full_text":"c"}]"#;
+        let blocks = parse_line(line)?.unwrap_or_default();
+        let status_bar = status_bar(&blocks);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Widget::render(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["ab c "]));
+        assert_eq!(
+            to_json(&super::blocks(&status_bar))?,
+            r#"[{"full_text":"a","separator":false},{"full_text":"b"},{"full_text":"c"}]"#
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+//! Semantic icons with fallbacks for fonts without glyph patches.
+//!
+//! Every [`Icon`] has a [Nerd Font](https://www.nerdfonts.com) glyph, a plain Unicode symbol that
+//! common fonts can display, and an ASCII variant for limited terminals. Which one
+//! [`Icon::glyph`] returns is controlled by a process-wide [`IconSet`], so built-in segments and
+//! application code pick up the same choice.
+//!
+//! # Examples
+//! ```
+//! use ratatui_statusbar::icons::{self, Icon, IconSet};
+//!
+//! icons::set_icon_set(IconSet::Ascii);
+//! assert_eq!(Icon::Warning.glyph(), "!");
+//! assert_eq!(Icon::Warning.glyph_in(IconSet::NerdFont), "\u{f071}");
+//! ```
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The kind of glyphs [`Icon`]s are rendered with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum IconSet {
+    /// Glyphs from the private use area of fonts patched by Nerd Fonts.
+    NerdFont,
+    /// Symbols from standard Unicode blocks.
+    #[default]
+    Unicode,
+    /// Plain ASCII text.
+    Ascii,
+}
+
+/// A semantic icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Icon {
+    /// A version control branch.
+    Branch,
+    /// An error.
+    Error,
+    /// A warning.
+    Warning,
+    /// An informational message.
+    Info,
+    /// A successful result.
+    Check,
+    /// A directory.
+    Folder,
+    /// A file.
+    File,
+    /// A read-only or locked resource.
+    Lock,
+    /// The time.
+    Clock,
+    /// An empty battery.
+    BatteryEmpty,
+    /// A battery charged to about a quarter.
+    BatteryQuarter,
+    /// A battery charged to about half.
+    BatteryHalf,
+    /// A battery charged to about three quarters.
+    BatteryThreeQuarters,
+    /// A full battery.
+    BatteryFull,
+    /// A charging battery.
+    BatteryCharging,
+}
+
+static ICON_SET: AtomicU8 = AtomicU8::new(IconSet::Unicode as u8);
+
+/// Sets the [`IconSet`] used by [`Icon::glyph`] for the whole process.
+pub fn set_icon_set(icon_set: IconSet) {
+    ICON_SET.store(icon_set as u8, Ordering::Relaxed);
+}
+
+/// Returns the [`IconSet`] used by [`Icon::glyph`], [`IconSet::Unicode`] unless changed with
+/// [`set_icon_set`].
+#[must_use]
+pub fn icon_set() -> IconSet {
+    match ICON_SET.load(Ordering::Relaxed) {
+        x if x == IconSet::NerdFont as u8 => IconSet::NerdFont,
+        x if x == IconSet::Ascii as u8 => IconSet::Ascii,
+        _ => IconSet::Unicode,
+    }
+}
+
+impl Icon {
+    /// Returns the battery icon for a charge of `percent`.
+    #[must_use]
+    pub const fn battery(percent: u8) -> Self {
+        match percent {
+            0..=12 => Self::BatteryEmpty,
+            13..=37 => Self::BatteryQuarter,
+            38..=62 => Self::BatteryHalf,
+            63..=87 => Self::BatteryThreeQuarters,
+            _ => Self::BatteryFull,
+        }
+    }
+
+    /// Returns the glyph of the icon in the global [`IconSet`].
+    #[must_use]
+    pub fn glyph(self) -> &'static str {
+        self.glyph_in(icon_set())
+    }
+
+    /// Returns the glyph of the icon in `icon_set`.
+    #[must_use]
+    pub const fn glyph_in(self, icon_set: IconSet) -> &'static str {
+        let (nerd_font, unicode, ascii) = match self {
+            Self::Branch => ("\u{e0a0}", "⎇", "br"),
+            Self::Error => ("\u{f057}", "✖", "x"),
+            Self::Warning => ("\u{f071}", "⚠", "!"),
+            Self::Info => ("\u{f05a}", "ℹ", "i"),
+            Self::Check => ("\u{f00c}", "✔", "ok"),
+            Self::Folder => ("\u{f07b}", "▸", "/"),
+            Self::File => ("\u{f15b}", "≡", "-"),
+            Self::Lock => ("\u{f023}", "⚿", "RO"),
+            Self::Clock => ("\u{f017}", "◷", "@"),
+            Self::BatteryEmpty => ("\u{f244}", "▁", "[    ]"),
+            Self::BatteryQuarter => ("\u{f243}", "▃", "[=   ]"),
+            Self::BatteryHalf => ("\u{f242}", "▅", "[==  ]"),
+            Self::BatteryThreeQuarters => ("\u{f241}", "▇", "[=== ]"),
+            Self::BatteryFull => ("\u{f240}", "█", "[====]"),
+            Self::BatteryCharging => ("\u{f0e7}", "↯", "~"),
+        };
+        match icon_set {
+            IconSet::NerdFont => nerd_font,
+            IconSet::Unicode => unicode,
+            IconSet::Ascii => ascii,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallbacks() {
+        assert_eq!(Icon::Branch.glyph_in(IconSet::NerdFont), "\u{e0a0}");
+        assert_eq!(Icon::Branch.glyph_in(IconSet::Unicode), "⎇");
+        assert_eq!(Icon::Branch.glyph_in(IconSet::Ascii), "br");
+        assert_eq!(Icon::battery(0), Icon::BatteryEmpty);
+        assert_eq!(Icon::battery(50), Icon::BatteryHalf);
+        assert_eq!(Icon::battery(100), Icon::BatteryFull);
+        assert!(Icon::battery(90).glyph_in(IconSet::Ascii).is_ascii());
+    }
+}
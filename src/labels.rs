@@ -0,0 +1,391 @@
+//! Translatable strings of the built-in segments.
+//!
+//! Built-in segments and indicators never show hard-coded English text. They look their words
+//! and unit suffixes up by key in a process-wide [`Labels`] table, which falls back to English for keys
+//! without a translation. Install a table with translations using [`set_labels`].
+//!
+//! # Examples
+//! ```
+//! use ratatui_statusbar::labels::{self, Labels};
+//!
+//! labels::set_labels(
+//!     Labels::new()
+//!         .with(labels::MONDAY, "lundi")
+//!         .with(labels::MONDAY_SHORT, "lun."),
+//! );
+//! assert_eq!(labels::label(labels::MONDAY), "lundi");
+//! assert_eq!(labels::label(labels::TUESDAY), "Tuesday");
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::RwLock;
+
+/// The English labels, by key.
+const ENGLISH: [(&str, &str); 67] = [
+    (MONDAY, "Monday"),
+    (TUESDAY, "Tuesday"),
+    (WEDNESDAY, "Wednesday"),
+    (THURSDAY, "Thursday"),
+    (FRIDAY, "Friday"),
+    (SATURDAY, "Saturday"),
+    (SUNDAY, "Sunday"),
+    (MONDAY_SHORT, "Mon"),
+    (TUESDAY_SHORT, "Tue"),
+    (WEDNESDAY_SHORT, "Wed"),
+    (THURSDAY_SHORT, "Thu"),
+    (FRIDAY_SHORT, "Fri"),
+    (SATURDAY_SHORT, "Sat"),
+    (SUNDAY_SHORT, "Sun"),
+    (JANUARY, "January"),
+    (FEBRUARY, "February"),
+    (MARCH, "March"),
+    (APRIL, "April"),
+    (MAY, "May"),
+    (JUNE, "June"),
+    (JULY, "July"),
+    (AUGUST, "August"),
+    (SEPTEMBER, "September"),
+    (OCTOBER, "October"),
+    (NOVEMBER, "November"),
+    (DECEMBER, "December"),
+    (JANUARY_SHORT, "Jan"),
+    (FEBRUARY_SHORT, "Feb"),
+    (MARCH_SHORT, "Mar"),
+    (APRIL_SHORT, "Apr"),
+    (MAY_SHORT, "May"),
+    (JUNE_SHORT, "Jun"),
+    (JULY_SHORT, "Jul"),
+    (AUGUST_SHORT, "Aug"),
+    (SEPTEMBER_SHORT, "Sep"),
+    (OCTOBER_SHORT, "Oct"),
+    (NOVEMBER_SHORT, "Nov"),
+    (DECEMBER_SHORT, "Dec"),
+    (AM, "AM"),
+    (PM, "PM"),
+    (DAYS, "d"),
+    (HOURS, "h"),
+    (MINUTES, "m"),
+    (SECONDS, "s"),
+    (MILLISECONDS, "ms"),
+    (PER_SECOND, "/s"),
+    (BYTES, "B"),
+    (KIBIBYTES, "KiB"),
+    (MEBIBYTES, "MiB"),
+    (GIBIBYTES, "GiB"),
+    (TEBIBYTES, "TiB"),
+    (PEBIBYTES, "PiB"),
+    (EXBIBYTES, "EiB"),
+    (KILOBYTES, "kB"),
+    (MEGABYTES, "MB"),
+    (GIGABYTES, "GB"),
+    (TERABYTES, "TB"),
+    (PETABYTES, "PB"),
+    (EXABYTES, "EB"),
+    (THOUSANDS, "k"),
+    (MILLIONS, "M"),
+    (BILLIONS, "G"),
+    (TRILLIONS, "T"),
+    (QUADRILLIONS, "P"),
+    (QUINTILLIONS, "E"),
+    (PERCENT, "{}%"),
+    (OVERFLOW, "+{}"),
+];
+
+/// The full name of Monday.
+pub const MONDAY: &str = "weekday.monday";
+/// The full name of Tuesday.
+pub const TUESDAY: &str = "weekday.tuesday";
+/// The full name of Wednesday.
+pub const WEDNESDAY: &str = "weekday.wednesday";
+/// The full name of Thursday.
+pub const THURSDAY: &str = "weekday.thursday";
+/// The full name of Friday.
+pub const FRIDAY: &str = "weekday.friday";
+/// The full name of Saturday.
+pub const SATURDAY: &str = "weekday.saturday";
+/// The full name of Sunday.
+pub const SUNDAY: &str = "weekday.sunday";
+/// The abbreviated name of Monday.
+pub const MONDAY_SHORT: &str = "weekday.monday.short";
+/// The abbreviated name of Tuesday.
+pub const TUESDAY_SHORT: &str = "weekday.tuesday.short";
+/// The abbreviated name of Wednesday.
+pub const WEDNESDAY_SHORT: &str = "weekday.wednesday.short";
+/// The abbreviated name of Thursday.
+pub const THURSDAY_SHORT: &str = "weekday.thursday.short";
+/// The abbreviated name of Friday.
+pub const FRIDAY_SHORT: &str = "weekday.friday.short";
+/// The abbreviated name of Saturday.
+pub const SATURDAY_SHORT: &str = "weekday.saturday.short";
+/// The abbreviated name of Sunday.
+pub const SUNDAY_SHORT: &str = "weekday.sunday.short";
+/// The full name of January.
+pub const JANUARY: &str = "month.january";
+/// The full name of February.
+pub const FEBRUARY: &str = "month.february";
+/// The full name of March.
+pub const MARCH: &str = "month.march";
+/// The full name of April.
+pub const APRIL: &str = "month.april";
+/// The full name of May.
+pub const MAY: &str = "month.may";
+/// The full name of June.
+pub const JUNE: &str = "month.june";
+/// The full name of July.
+pub const JULY: &str = "month.july";
+/// The full name of August.
+pub const AUGUST: &str = "month.august";
+/// The full name of September.
+pub const SEPTEMBER: &str = "month.september";
+/// The full name of October.
+pub const OCTOBER: &str = "month.october";
+/// The full name of November.
+pub const NOVEMBER: &str = "month.november";
+/// The full name of December.
+pub const DECEMBER: &str = "month.december";
+/// The abbreviated name of January.
+pub const JANUARY_SHORT: &str = "month.january.short";
+/// The abbreviated name of February.
+pub const FEBRUARY_SHORT: &str = "month.february.short";
+/// The abbreviated name of March.
+pub const MARCH_SHORT: &str = "month.march.short";
+/// The abbreviated name of April.
+pub const APRIL_SHORT: &str = "month.april.short";
+/// The abbreviated name of May.
+pub const MAY_SHORT: &str = "month.may.short";
+/// The abbreviated name of June.
+pub const JUNE_SHORT: &str = "month.june.short";
+/// The abbreviated name of July.
+pub const JULY_SHORT: &str = "month.july.short";
+/// The abbreviated name of August.
+pub const AUGUST_SHORT: &str = "month.august.short";
+/// The abbreviated name of September.
+pub const SEPTEMBER_SHORT: &str = "month.september.short";
+/// The abbreviated name of October.
+pub const OCTOBER_SHORT: &str = "month.october.short";
+/// The abbreviated name of November.
+pub const NOVEMBER_SHORT: &str = "month.november.short";
+/// The abbreviated name of December.
+pub const DECEMBER_SHORT: &str = "month.december.short";
+/// The marker of times before noon.
+pub const AM: &str = "time.am";
+/// The marker of times after noon.
+pub const PM: &str = "time.pm";
+/// The unit suffix of days in [formatted](crate::format) durations.
+pub const DAYS: &str = "unit.days";
+/// The unit suffix of hours in [formatted](crate::format) durations.
+pub const HOURS: &str = "unit.hours";
+/// The unit suffix of minutes in [formatted](crate::format) durations.
+pub const MINUTES: &str = "unit.minutes";
+/// The unit suffix of seconds in [formatted](crate::format) durations.
+pub const SECONDS: &str = "unit.seconds";
+/// The unit suffix of milliseconds in [formatted](crate::format) durations.
+pub const MILLISECONDS: &str = "unit.milliseconds";
+/// The suffix of [formatted](crate::format) transfer rates.
+pub const PER_SECOND: &str = "unit.per_second";
+/// The unit of [formatted](crate::format) sizes under a kibibyte or kilobyte.
+pub const BYTES: &str = "unit.bytes";
+/// The unit of [formatted](crate::format) sizes in kibibytes (1024 bytes).
+pub const KIBIBYTES: &str = "unit.kibibytes";
+/// The unit of [formatted](crate::format) sizes in mebibytes.
+pub const MEBIBYTES: &str = "unit.mebibytes";
+/// The unit of [formatted](crate::format) sizes in gibibytes.
+pub const GIBIBYTES: &str = "unit.gibibytes";
+/// The unit of [formatted](crate::format) sizes in tebibytes.
+pub const TEBIBYTES: &str = "unit.tebibytes";
+/// The unit of [formatted](crate::format) sizes in pebibytes.
+pub const PEBIBYTES: &str = "unit.pebibytes";
+/// The unit of [formatted](crate::format) sizes in exbibytes.
+pub const EXBIBYTES: &str = "unit.exbibytes";
+/// The unit of [formatted](crate::format) sizes in kilobytes (1000 bytes).
+pub const KILOBYTES: &str = "unit.kilobytes";
+/// The unit of [formatted](crate::format) sizes in megabytes.
+pub const MEGABYTES: &str = "unit.megabytes";
+/// The unit of [formatted](crate::format) sizes in gigabytes.
+pub const GIGABYTES: &str = "unit.gigabytes";
+/// The unit of [formatted](crate::format) sizes in terabytes.
+pub const TERABYTES: &str = "unit.terabytes";
+/// The unit of [formatted](crate::format) sizes in petabytes.
+pub const PETABYTES: &str = "unit.petabytes";
+/// The unit of [formatted](crate::format) sizes in exabytes.
+pub const EXABYTES: &str = "unit.exabytes";
+/// The suffix of [formatted](crate::format) counts in thousands.
+pub const THOUSANDS: &str = "unit.thousands";
+/// The suffix of [formatted](crate::format) counts in millions.
+pub const MILLIONS: &str = "unit.millions";
+/// The suffix of [formatted](crate::format) counts in billions.
+pub const BILLIONS: &str = "unit.billions";
+/// The suffix of [formatted](crate::format) counts in trillions.
+pub const TRILLIONS: &str = "unit.trillions";
+/// The suffix of [formatted](crate::format) counts in quadrillions.
+pub const QUADRILLIONS: &str = "unit.quadrillions";
+/// The suffix of [formatted](crate::format) counts in quintillions.
+pub const QUINTILLIONS: &str = "unit.quintillions";
+/// A percentage, with `{}` standing for the number, e.g. in a
+/// [progress](crate::segments::ProgressSegment) segment.
+pub const PERCENT: &str = "unit.percent";
+/// The [overflow indicator](crate::StatusBar::overflow_indicator), with `{}` standing for the
+/// number of sections left out.
+pub const OVERFLOW: &str = "indicator.overflow";
+
+/// The full names of the weekdays, starting with Monday.
+#[cfg(feature = "segments-clock")]
+pub(crate) const WEEKDAYS: [&str; 7] = [
+    MONDAY, TUESDAY, WEDNESDAY, THURSDAY, FRIDAY, SATURDAY, SUNDAY,
+];
+
+/// The abbreviated names of the weekdays, starting with Monday.
+#[cfg(feature = "segments-clock")]
+pub(crate) const WEEKDAYS_SHORT: [&str; 7] = [
+    MONDAY_SHORT,
+    TUESDAY_SHORT,
+    WEDNESDAY_SHORT,
+    THURSDAY_SHORT,
+    FRIDAY_SHORT,
+    SATURDAY_SHORT,
+    SUNDAY_SHORT,
+];
+
+/// The full names of the months, starting with January.
+#[cfg(feature = "segments-clock")]
+pub(crate) const MONTHS: [&str; 12] = [
+    JANUARY, FEBRUARY, MARCH, APRIL, MAY, JUNE, JULY, AUGUST, SEPTEMBER, OCTOBER, NOVEMBER,
+    DECEMBER,
+];
+
+/// The abbreviated names of the months, starting with January.
+#[cfg(feature = "segments-clock")]
+pub(crate) const MONTHS_SHORT: [&str; 12] = [
+    JANUARY_SHORT,
+    FEBRUARY_SHORT,
+    MARCH_SHORT,
+    APRIL_SHORT,
+    MAY_SHORT,
+    JUNE_SHORT,
+    JULY_SHORT,
+    AUGUST_SHORT,
+    SEPTEMBER_SHORT,
+    OCTOBER_SHORT,
+    NOVEMBER_SHORT,
+    DECEMBER_SHORT,
+];
+
+static LABELS: RwLock<Option<Labels>> = RwLock::new(None);
+
+/// A table of translated labels, by key.
+///
+/// Keys are the constants of the [`labels`](self) module. With the `serde` feature, a table can
+/// be loaded from a map of keys to translations, e.g. a section of a configuration file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Labels {
+    translations: HashMap<String, String>,
+}
+
+impl Labels {
+    /// Creates a table without translations, i.e. with the English labels.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the translation of the label with `key`.
+    #[must_use]
+    pub fn with(mut self, key: impl Into<String>, translation: impl Into<String>) -> Self {
+        self.translations.insert(key.into(), translation.into());
+        self
+    }
+
+    /// Returns the label with `key`: its translation, the English label, or the key itself for
+    /// unknown keys.
+    #[must_use]
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        self.translations.get(key).map_or_else(
+            || {
+                ENGLISH
+                    .iter()
+                    .find(|(english, _)| *english == key)
+                    .map_or(key, |(_, label)| label)
+            },
+            String::as_str,
+        )
+    }
+
+    /// Returns the label with `key` with its `{}` placeholder replaced by `value`.
+    #[must_use]
+    pub fn format(&self, key: &str, value: impl Display) -> String {
+        self.get(key).replacen("{}", &value.to_string(), 1)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Labels {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            translations: iter
+                .into_iter()
+                .map(|(key, translation)| (key.into(), translation.into()))
+                .collect(),
+        }
+    }
+}
+
+/// Sets the [`Labels`] the built-in segments use for the whole process.
+pub fn set_labels(labels: Labels) {
+    *LABELS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(labels);
+}
+
+/// Returns the label with `key` from the [`Labels`] set with [`set_labels`].
+#[must_use]
+pub fn label(key: &str) -> String {
+    let labels = LABELS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match labels.as_ref() {
+        Some(labels) => labels.get(key).to_string(),
+        None => Labels::default().get(key).to_string(),
+    }
+}
+
+/// Returns the label with `key` from the [`Labels`] set with [`set_labels`], with its `{}`
+/// placeholder replaced by `value`.
+#[must_use]
+pub fn format(key: &str, value: impl Display) -> String {
+    let labels = LABELS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match labels.as_ref() {
+        Some(labels) => labels.format(key, value),
+        None => Labels::default().format(key, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallbacks() {
+        let labels: Labels = [(MONDAY, "Montag")].into_iter().collect();
+        assert_eq!(labels.get(MONDAY), "Montag");
+        assert_eq!(labels.get(MONDAY_SHORT), "Mon");
+        assert_eq!(labels.get("unknown"), "unknown");
+        assert!(ENGLISH.iter().all(|(key, _)| key.contains('.')));
+    }
+
+    #[test]
+    fn placeholders() {
+        let labels = Labels::new().with(OVERFLOW, "{} de plus");
+        assert_eq!(labels.format(OVERFLOW, 3), "3 de plus");
+        assert_eq!(labels.format(PERCENT, 50), "50%");
+        assert_eq!(Labels::new().format(OVERFLOW, 12), "+12");
+        assert_eq!(Labels::new().format(PER_SECOND, 1), "/s");
+        assert_eq!(Labels::new().get(MEBIBYTES), "MiB");
+    }
+}
@@ -5,19 +5,186 @@
 //! ## Features
 //! - Define status bar layouts with any number of sections
 //! - Customizable flex layout and spacing between sections
+//! - Flashing sections and animated segments driven by a single [`StatusBarState`] tick
+//! - A matching [`TabLine`] with close buttons, scrolling and hit-testing
+//! - Per-pane [`WinBar`] headers styled by focus
+//! - [`Tooltip`]s anchored to sections
+//! - Horizontal [scrolling](StatusBar::scrollable) of bars that don't fit
+//! - A clickable [indicator](StatusBar::overflow_indicator) counting sections left out for lack
+//!   of space
+//! - Animated [transitions](StatusBarSection::transition) when the content of a section changes
+//! - Sections [paging](StatusBarSection::pages) through alternative contents
+//! - [Optional](StatusBarSection::optional) sections toggled or cycled through with a key
+//! - Backend-agnostic mouse [`events`] for hovering, clicking and dragging sections
+//! - Reordering sections by [dragging](StatusBar::end_drag) them with the mouse
+//! - [Merging](SeparatorMerge) the separators where two sections meet
+//! - [Frozen](StatusBar::freeze) bars rendered by copying pre-rendered cells
+//! - Checking whether a bar [fits](StatusBar::fits_in) a width without rendering it
+//! - Stacks of status bars that can be shown and hidden with a [`MultiBar`]
+//! - Nested groups of sections with their own layout
+//! - Existing widgets such as a sparkline embedded as sections
+//! - Command and search [`Prompt`]s edited with `tui-textarea` (requires the `tui-textarea`
+//!   feature)
+//! - Truncation of long content at the start, middle or end, or with custom [`Truncator`]s
+//! - A compact mode that drops spacing, separators and padding before truncating
+//! - Multi-line sections wrapped within taller status bars
+//! - Responsive [`Breakpoint`]s that adapt the status bar to narrow terminals
+//! - Dimmed rendering of inactive status bars for unfocused windows
+//! - Right-to-left layout for Arabic and Hebrew interfaces
+//! - Rendering and segments that work on the web, with injectable clocks and no required threads
+//! - An ASCII-only mode for terminals without reliable Unicode support
+//! - A pluggable [`WidthPolicy`](width::WidthPolicy) for terminals that disagree on character
+//!   and [emoji](width::EmojiWidth) widths
+//! - Separators that inherit the style of neighbouring sections
+//! - Smooth [color transitions](ColorTransition) between styles and themes
+//! - Conversion of RGB colors for terminals with a lower [`ColorDepth`]
+//! - Ready-made [`Preset`] layouts in the style of powerline, lualine and Helix
+//! - Light and dark [`Theme`]s, chosen to match the terminal background (requires the
+//!   `appearance` feature, on Unix)
+//! - Styled content written in a tmux-style [`markup`]
+//! - Serialization of status bars with [`serde`](https://serde.rs) (requires the `serde` feature)
+//! - Hyperlinked sections using OSC 8 escape sequences
+//! - Section [actions](events::Action) copying text to the clipboard with OSC 52 when clicked
+//! - A section [mirrored](osc::TitleMirror) to the terminal title
+//! - Bell and desktop [notifications](osc::Notification) for severe log messages
+//! - A [progress](segments::ProgressSegment) segment mirrored to the terminal tab or taskbar
+//! - Dynamic section content from [`segments`] such as a clock or the current git branch
+//!   (requires the default `segments` feature, and `segments-clock` or `segments-git` for those)
+//! - Human-readable sizes, rates, durations and counts for segment content in [`format`]
+//! - [`Thresholds`] styling measurements by range without flickering at the bounds
+//! - Semantic [`icons`] with Nerd Font, Unicode and ASCII variants
+//! - Translatable [`labels`] for the text of built-in segments
+//! - Segments that fall back to other content on errors, refresh on a background thread or
+//!   mark readouts that are no longer fresh
+//! - [Render](StatusBar::on_render) and segment refresh timings to find slow segments
+//! - User-defined segments written in [Rhai](https://rhai.rs) (requires the `scripting` feature)
+//! - Declarative status bars loaded from TOML or YAML [`config`] files
+//! - An adapter for the i3bar/swaybar JSON protocol (requires the `i3bar` feature)
+//! - A `tracing` layer showing the latest warning or error in the status bar (requires the
+//!   `tracing` feature)
+//! - A frame [`Recorder`](testing::Recorder) for snapshot tests of animated status bars (requires
+//!   the `testing` feature)
+//! - `Arbitrary` status bars for property tests checking their
+//!   [invariants](testing::check_invariants) (requires the `proptest` feature)
+//! - Debug events explaining why sections were truncated or left out (requires the `debug-trace`
+//!   feature)
+//! - A `log` logger capturing recent records for the status bar (requires the `log` feature)
 
-use itertools::Itertools;
-use ratatui::layout::Flex;
+#[cfg(all(feature = "appearance", unix))]
+pub mod appearance;
+mod breakpoint;
+mod charset;
+mod color;
+#[cfg(all(feature = "serde", feature = "segments"))]
+pub mod config;
+#[cfg(feature = "debug-trace")]
+mod debug_trace;
+pub mod events;
+mod fit;
+pub mod format;
+mod frozen;
+#[cfg(feature = "i3bar")]
+pub mod i3bar;
+pub mod icons;
+pub mod labels;
+#[cfg(feature = "log")]
+pub mod logger;
+pub mod markup;
+mod multibar;
+pub mod osc;
+mod preset;
+#[cfg(feature = "tui-textarea")]
+mod prompt;
+#[cfg(feature = "segments")]
+pub mod segments;
+#[cfg(feature = "serde")]
+mod serialization;
+mod state;
+mod tabline;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+mod theme;
+mod thresholds;
+mod tooltip;
+#[cfg(feature = "tracing")]
+pub mod tracing_layer;
+mod transition;
+mod truncation;
+mod tween;
+pub mod width;
+mod winbar;
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ratatui::layout::{Flex, Position};
 use ratatui::prelude::*;
-use ratatui::widgets::WidgetRef;
+use ratatui::style::Styled;
+use ratatui::widgets::{Paragraph, StatefulWidgetRef, WidgetRef, Wrap};
 use thiserror::Error;
 
+pub use crate::breakpoint::Breakpoint;
+pub use crate::color::ColorDepth;
+use crate::events::Action;
+pub use crate::fit::{FitReport, SectionFit};
+pub use crate::frozen::FrozenStatusBar;
+pub use crate::multibar::MultiBar;
+pub use crate::preset::Preset;
+#[cfg(feature = "tui-textarea")]
+pub use crate::prompt::Prompt;
+pub use crate::state::StatusBarState;
+pub use crate::tabline::{TabHit, TabLine, TabLineState};
+pub use crate::theme::{Role, Theme};
+pub use crate::thresholds::Thresholds;
+pub use crate::tooltip::Tooltip;
+pub use crate::transition::Transition;
+pub use crate::truncation::{PathTruncator, Truncation, Truncator, WordTruncator};
+pub use crate::tween::{Blend, ColorTransition};
+pub use crate::winbar::WinBar;
+
 /// An enumeration of potential errors that can impact the [`StatusBar`] operations.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum StatusBarError {
     /// The requested index does not exist.
     #[error("Index out of bounds: {0}")]
     IndexOutOfBounds(usize),
+    /// A status bar configuration could not be loaded.
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+    /// Input or output of an external status bar protocol is invalid.
+    #[error("Invalid protocol message: {0}")]
+    Protocol(String),
+    /// A segment script failed to compile or run.
+    #[error("Script error: {0}")]
+    Script(String),
+    /// A segment failed to produce its content.
+    #[error("Segment error: {0}")]
+    Segment(String),
+    /// No section has the requested name.
+    #[error("Unknown section: {0}")]
+    UnknownSection(String),
+    /// The content needs more columns than are available.
+    #[error("Content needs {required} columns but only {available} are available")]
+    WidthOverflow {
+        /// The number of columns the content needs.
+        required: usize,
+        /// The number of columns available.
+        available: u16,
+    },
+    /// A template or markup string is malformed.
+    #[error("Invalid template at byte {position}: {message}")]
+    TemplateParse {
+        /// The byte offset of the error in the template.
+        position: usize,
+        /// The length in bytes of the malformed part of the template.
+        length: usize,
+        /// A description of the error.
+        message: String,
+    },
 }
 
 /// A representation of a single section in a [`StatusBar`]
@@ -25,19 +192,333 @@ pub enum StatusBarError {
 ///
 /// # Examples
 /// ```
+/// # use ratatui_statusbar::StatusBarSection;
 /// let section = StatusBarSection::default()
 ///     .pre_separator(" | ")
 ///     .content("Section Content")
 ///     .post_separator(" | ");
 /// ```
-#[derive(Debug, Default, Clone)]
+///
+/// Sections implement [`Styled`], so the [`Stylize`] shorthands can be used to style them:
+/// ```
+/// # use ratatui::prelude::*;
+/// # use ratatui_statusbar::StatusBarSection;
+/// let section = StatusBarSection::from(" NORMAL ").black().on_green().bold();
+/// ```
+///
+/// Sections are equal if they render the same. [Visibility predicates](Self::visible_when) and
+/// [data](Self::data) can't be compared, so they are only equal to their clones.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct StatusBarSection<'a> {
     pre_separator: Option<Span<'a>>,
     content: Line<'a>,
+    continuation: Vec<Line<'a>>,
     post_separator: Option<Span<'a>>,
+    hyperlink: Option<String>,
+    action: Option<Action>,
+    style: Style,
+    name: Option<String>,
+    hidden: bool,
+    visible_when: Option<VisibilityPredicate>,
+    short_content: Option<Line<'a>>,
+    truncation: Truncation,
+    truncator: Option<SectionTruncator>,
+    min_width: Option<u16>,
+    max_width: Option<u16>,
+    gap_after: Option<u16>,
+    group: Option<Box<StatusBar<'a>>>,
+    widget: Option<SectionWidget<'a>>,
+    data: Option<SectionData>,
+    tooltip: Option<Text<'a>>,
+    pages: Vec<Line<'a>>,
+    pinned: bool,
+    optional: bool,
+    role: Option<Role>,
+    gap_weight: u16,
+    transition: Option<(Transition, Duration)>,
+}
+
+/// The context a [`StatusBar`] is rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct RenderContext {
+    /// The area the [`StatusBar`] is rendered into.
+    pub area: Rect,
+    /// Whether the [`StatusBar`] is [inactive](StatusBar::inactive).
+    pub inactive: bool,
+}
+
+#[derive(Clone)]
+struct VisibilityPredicate(Arc<dyn Fn(&RenderContext) -> bool + Send + Sync>);
+
+impl fmt::Debug for VisibilityPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("VisibilityPredicate")
+    }
+}
+
+/// Closures can't be compared, so predicates are equal only if they are the same closure, i.e.
+/// the section was cloned.
+impl PartialEq for VisibilityPredicate {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Clone)]
+struct SectionTruncator(Arc<dyn Truncator>);
+
+impl fmt::Debug for SectionTruncator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SectionTruncator")
+    }
+}
+
+/// Truncators are equal only if they are the same instance, i.e. the section was cloned.
+impl PartialEq for SectionTruncator {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Clone)]
+struct RenderHook(Arc<dyn Fn(Duration) + Send + Sync>);
+
+impl fmt::Debug for RenderHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RenderHook")
+    }
+}
+
+/// Like predicates, hooks are equal only if they are the same closure.
+impl PartialEq for RenderHook {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Clone)]
+struct SectionWidget<'a> {
+    widget: Arc<dyn WidgetRef + Send + Sync + 'a>,
+    width: u16,
+}
+
+impl fmt::Debug for SectionWidget<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SectionWidget")
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Widgets can't be compared, so widgets are equal only if they are the same widget, i.e. the
+/// section was cloned.
+impl PartialEq for SectionWidget<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && Arc::ptr_eq(&self.widget, &other.widget)
+    }
+}
+
+#[derive(Clone)]
+struct SectionData(Arc<dyn Any + Send + Sync>);
+
+impl fmt::Debug for SectionData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SectionData")
+    }
+}
+
+/// Data of any type can't be compared, so data is equal only if it is the same value, i.e. the
+/// section was cloned.
+impl PartialEq for SectionData {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl<'a> StatusBarSection<'a> {
+    /// Attaches application-defined data to the section, e.g. the message to send when it is
+    /// clicked.
+    ///
+    /// The data is retrieved with [`StatusBarSection::data_ref`] or, for the section at a mouse
+    /// position, with [`StatusBar::data_at`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// #[derive(Debug, PartialEq)]
+    /// enum Action {
+    ///     ShowDiagnostics,
+    /// }
+    ///
+    /// let status_bar = StatusBar::new(1)
+    ///     .section(0, StatusBarSection::from("2 errors").data(Action::ShowDiagnostics))?;
+    /// let area = Rect::new(0, 0, 20, 1);
+    /// assert_eq!(status_bar.data_at::<Action>(area, (3, 0)), Some(&Action::ShowDiagnostics));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn data<T: Any + Send + Sync>(mut self, data: T) -> Self {
+        self.data = Some(SectionData(Arc::new(data)));
+        self
+    }
+
+    /// Sets the text of the [`Tooltip`] of the section, see [`StatusBar::tooltip`].
+    #[must_use]
+    pub fn tooltip(mut self, tooltip: impl Into<Text<'a>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Sets alternative contents that take turns in the section, e.g. CPU, memory and network
+    /// readouts sharing one slot.
+    ///
+    /// The first page becomes the content of the section. The page that is shown is selected
+    /// with [`StatusBarState::next_page`] or rotated on a timer with
+    /// [`StatusBarState::rotate_pages`]. The section is as wide as its widest page, so the other
+    /// sections stay in place while the pages change.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::time::Duration;
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection, StatusBarState};
+    /// let status_bar = StatusBar::new(1)
+    ///     .section(0, StatusBarSection::default().pages(["CPU 12%", "MEM 3.1G"]))?;
+    /// let mut state = StatusBarState::default();
+    /// state.rotate_pages(0, Duration::from_secs(5));
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+    /// state.advance(Duration::from_secs(5));
+    /// StatefulWidget::render(status_bar, buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["MEM 3.1G"]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn pages<I>(mut self, pages: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        self.pages = pages.into_iter().map(Into::into).collect();
+        if let Some(first) = self.pages.first() {
+            self.content = first.clone();
+            self.continuation.clear();
+        }
+        self
+    }
+
+    /// Returns the data attached with [`StatusBarSection::data`] if it is a `T`.
+    #[must_use]
+    pub fn data_ref<T: Any>(&self) -> Option<&T> {
+        self.data.as_ref()?.0.downcast_ref()
+    }
+
+    /// Hides or shows the section. Hidden sections take no space.
+    #[must_use]
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Styles the section with the style of `role` in the [`Theme`] of the bar.
+    ///
+    /// The style of the section itself is applied on top, see [`StatusBar`] for how styles
+    /// cascade.
+    #[must_use]
+    pub fn role(mut self, role: Role) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Pins the section, e.g. a mode indicator or an error count, so that it is never hidden by a
+    /// [`Breakpoint`] and keeps its full width when the sections don't fit.
+    ///
+    /// The other sections are shrunk instead, starting with the last one, or the first one if
+    /// the bar [fills from the end](StatusBar::fill_from_end), and left out once they have no
+    /// width left. Pinned sections are only truncated if they don't fit on their own.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// let status_bar = StatusBar::new(3)
+    ///     .section(0, "main.rs")?
+    ///     .section(1, "utf-8")?
+    ///     .section(2, StatusBarSection::from("E:2").pinned(true))?;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+    /// Widget::render(status_bar, buf.area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["main.rs E:2 "]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Shows the section only while `predicate` returns `true`.
+    ///
+    /// The predicate is evaluated whenever the [`StatusBar`] is laid out, so sections can appear
+    /// and disappear depending on the [`RenderContext`] or application state without rebuilding
+    /// the status bar.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use std::sync::Arc;
+    /// # use ratatui_statusbar::StatusBarSection;
+    /// let recording = Arc::new(AtomicBool::new(false));
+    /// let flag = Arc::clone(&recording);
+    /// let section = StatusBarSection::from("REC").visible_when(move |_| flag.load(Ordering::Relaxed));
+    ///
+    /// // Only show the clock when the status bar is wide enough.
+    /// let clock = StatusBarSection::from("12:00").visible_when(|context| context.area.width >= 80);
+    /// ```
+    #[must_use]
+    pub fn visible_when<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&RenderContext) -> bool + Send + Sync + 'static,
+    {
+        self.visible_when = Some(VisibilityPredicate(Arc::new(predicate)));
+        self
+    }
+
+    /// Marks the section as optional, e.g. a clock or a battery level that is only worth its
+    /// space on demand.
+    ///
+    /// Optional sections are hidden until they are shown with [`StatusBar::toggle_section`] or
+    /// [`StatusBar::cycle_optional`], which record the shown sections in a [`StatusBarState`], so
+    /// the bar has to be rendered as a [`StatefulWidget`] to show them.
+    #[must_use]
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Returns whether the section is [hidden](Self::hidden) or [optional](Self::optional) and
+    /// not shown.
+    const fn is_hidden(&self) -> bool {
+        self.hidden || self.optional
+    }
+
+    fn is_visible(&self, context: &RenderContext) -> bool {
+        !self.is_hidden()
+            && self
+                .visible_when
+                .as_ref()
+                .is_none_or(|predicate| (predicate.0)(context))
+    }
+
+    /// Names the section, so it can be replaced with [`StatusBar::named_section`].
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     /// Associates a pre-separator with the section.
     #[must_use]
     pub fn pre_separator(mut self, separator: impl Into<Span<'a>>) -> Self {
@@ -46,26 +527,407 @@ impl<'a> StatusBarSection<'a> {
     }
 
     /// Sets the main content of the section.
+    ///
+    /// Accepts anything that converts into a [`Line`], e.g. `&str`, `String`, `Cow<str>`, a
+    /// [`Span`] or a `Vec<Span>`. Use [`Self::text`] for [`Text`] with several lines.
     #[must_use]
     pub fn content(mut self, content: impl Into<Line<'a>>) -> Self {
         self.content = content.into();
+        self.continuation.clear();
+        self
+    }
+
+    /// Sets multi-line content of the section.
+    ///
+    /// A [`StatusBar`] rendered into a single row shows the first line only. When it is given
+    /// more rows, e.g. because a section has several lines and the area follows
+    /// [`StatusBar::height`], the lines are wrapped to the width of the section instead of being
+    /// [truncated](Self::truncation).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// let status_bar = StatusBar::new(1)
+    ///     .section(0, StatusBarSection::default().text("error:\nfile not found"))?;
+    /// assert_eq!(status_bar.height(), 2);
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn text(mut self, text: impl Into<Text<'a>>) -> Self {
+        let text = text.into();
+        let mut lines = text.lines.into_iter().map(|mut line| {
+            line.style = text.style.patch(line.style);
+            line.alignment = line.alignment.or(text.alignment);
+            line
+        });
+        self.content = lines.next().unwrap_or_default();
+        self.continuation = lines.collect();
+        self
+    }
+
+    /// Sets a shorter alternative to the content, used while a [`Breakpoint`] asks for
+    /// [short](Breakpoint::short) content.
+    #[must_use]
+    pub fn short_content(mut self, content: impl Into<Line<'a>>) -> Self {
+        self.short_content = Some(content.into());
+        self
+    }
+
+    /// Sets where the content is cut when the section is too narrow for it.
+    ///
+    /// Separators are never truncated.
+    #[must_use]
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+        self.truncator = None;
+        self
+    }
+
+    /// Sets a custom strategy for shortening the content when the section is too narrow for it,
+    /// such as a [`PathTruncator`] that drops leading path components. It replaces the
+    /// [truncation](Self::truncation).
+    ///
+    /// # Examples
+    /// ```
+    /// use ratatui_statusbar::{PathTruncator, StatusBarSection};
+    ///
+    /// let section = StatusBarSection::from("~/src/ratatui-statusbar/src/lib.rs").truncator(PathTruncator);
+    /// ```
+    #[must_use]
+    pub fn truncator(mut self, truncator: impl Truncator + 'static) -> Self {
+        self.truncator = Some(SectionTruncator(Arc::new(truncator)));
         self
     }
 
+    /// Shortens `line` to `width` columns with the truncator or truncation of the section.
+    fn truncate(&self, line: &Line<'a>, width: usize) -> Line<'a> {
+        match &self.truncator {
+            Some(SectionTruncator(truncator)) => truncator.truncate(line, width),
+            None => truncation::truncate(line, width, self.truncation),
+        }
+    }
+
     /// Associates a post-separator with the section.
     #[must_use]
     pub fn post_separator(mut self, separator: impl Into<Span<'a>>) -> Self {
         self.post_separator = Some(separator.into());
         self
     }
+
+    /// Returns the width of the section, including its separators.
+    fn width(&self) -> usize {
+        self.width_for(false)
+    }
+
+    /// Reserves at least `width` columns for the section, including its separators.
+    ///
+    /// This keeps the layout from jumping when the content changes width, e.g. a counter gaining
+    /// a digit. Narrower content is positioned according to its [`Line::alignment`].
+    #[must_use]
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    /// Limits the section to at most `width` columns, including its separators.
+    ///
+    /// Wider content is [truncated](Self::truncation), so unbounded content can't crowd out the
+    /// other sections. The maximum takes precedence over the minimum.
+    #[must_use]
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets the space between this section and the next visible one, overriding the spacing of
+    /// the [`StatusBar`].
+    #[must_use]
+    pub fn gap_after(mut self, gap: u16) -> Self {
+        self.gap_after = Some(gap);
+        self
+    }
+
+    /// Sets the share of the free space of the [`StatusBar`] that is added to the gap after this
+    /// section, see [`StatusBar::gap_weights`].
+    #[must_use]
+    pub fn gap_weight(mut self, weight: u16) -> Self {
+        self.gap_weight = weight;
+        self
+    }
+
+    /// Animates changes of the content over `duration` with `transition`, e.g. to draw attention
+    /// to a changed mode or branch.
+    ///
+    /// The status bar compares the content with the content it showed before whenever it is
+    /// rendered with a [`StatusBarState`], and time passes with [`StatusBarState::advance`].
+    /// The transition plays within the width of the new content.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::prelude::*;
+    /// use ratatui_statusbar::{StatusBar, StatusBarSection, StatusBarState, Transition};
+    ///
+    /// let mode = |mode| {
+    ///     StatusBar::new(1).section(
+    ///         0,
+    ///         StatusBarSection::from(mode).transition(Transition::Slide, Duration::from_millis(200)),
+    ///     )
+    /// };
+    /// let mut state = StatusBarState::new();
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// StatefulWidget::render(&mode("NORMAL")?, buf.area, &mut buf, &mut state);
+    /// StatefulWidget::render(&mode("INSERT")?, buf.area, &mut buf, &mut state);
+    /// assert!(state.is_transitioning(0));
+    ///
+    /// state.advance(Duration::from_millis(100));
+    /// StatefulWidget::render(&mode("INSERT")?, buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["AL INS    "]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn transition(mut self, transition: Transition, duration: Duration) -> Self {
+        self.transition = Some((transition, duration));
+        self
+    }
+
+    /// Replaces the content of the section with a group of sub-sections.
+    ///
+    /// The group is a [`StatusBar`] of its own, with its own flex layout, spacing and separators,
+    /// that is rendered into the area left for the content of the section. The section is as wide
+    /// as the [desired width](StatusBar::desired_width) of the group.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// // Branch and diff counts stay together, while the sections of the bar are spread out.
+    /// let git = StatusBar::new(3)
+    ///     .spacing(0u16)
+    ///     .section(0, " main")?
+    ///     .section(1, " +1".green())?
+    ///     .section(2, " -2".red())?;
+    /// let status_bar = StatusBar::new(2)
+    ///     .flex(ratatui::layout::Flex::SpaceBetween)
+    ///     .section(0, StatusBarSection::default().group(git).pre_separator("["))?
+    ///     .section(1, "utf-8")?;
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn group(mut self, group: StatusBar<'a>) -> Self {
+        self.group = Some(Box::new(group));
+        self
+    }
+
+    /// Replaces the content of the section with a widget, e.g. a small [`Sparkline`] or
+    /// [`LineGauge`], that is rendered into `width` columns between the separators.
+    ///
+    /// [`Sparkline`]: ratatui::widgets::Sparkline
+    /// [`LineGauge`]: ratatui::widgets::LineGauge
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui::widgets::Sparkline;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// let load = [1, 4, 8];
+    /// let status_bar = StatusBar::new(2)
+    ///     .section(0, "cpu")?
+    ///     .section(1, StatusBarSection::default().widget(Sparkline::default().data(&load), 3))?;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+    /// Widget::render(&status_bar, buf.area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["cpu ▁▄█"]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn widget(mut self, widget: impl WidgetRef + Send + Sync + 'a, width: u16) -> Self {
+        self.widget = Some(SectionWidget {
+            widget: Arc::new(widget),
+            width,
+        });
+        self
+    }
+
+    /// Returns the width of the section with its short or full content.
+    fn width_for(&self, short: bool) -> usize {
+        if let Some(group) = &self.group {
+            return self.clamp(self.separators_width() + group.desired_width());
+        }
+        if let Some(widget) = &self.widget {
+            return self.clamp(self.separators_width() + usize::from(widget.width));
+        }
+        let width = self
+            .displayed_lines(short)
+            .chain(self.pages.iter().filter(|_| !self.has_short(short)))
+            .map(width::line_width)
+            .max()
+            .unwrap_or_default();
+        self.clamp(self.separators_width() + width)
+    }
+
+    fn clamp(&self, width: usize) -> usize {
+        let width = self
+            .min_width
+            .map_or(width, |min_width| width.max(usize::from(min_width)));
+        self.max_width
+            .map_or(width, |max_width| width.min(usize::from(max_width)))
+    }
+
+    /// Returns the short content if `short` is set and the section has one, the content otherwise.
+    fn displayed_content(&self, short: bool) -> &Line<'a> {
+        self.short_content
+            .as_ref()
+            .filter(|_| short)
+            .unwrap_or(&self.content)
+    }
+
+    /// Returns the page `page` wraps around to, or the displayed content if `short` is set and
+    /// the section has short content or the section has no pages.
+    fn displayed_page(&self, short: bool, page: usize) -> &Line<'a> {
+        if self.pages.is_empty() || self.has_short(short) {
+            return self.displayed_content(short);
+        }
+        &self.pages[page % self.pages.len()]
+    }
+
+    fn has_short(&self, short: bool) -> bool {
+        short && self.short_content.is_some()
+    }
+
+    /// Returns the short content if `short` is set and the section has one, all lines of the
+    /// content otherwise.
+    fn displayed_lines(&self, short: bool) -> impl Iterator<Item = &Line<'a>> {
+        let short = self.short_content.as_ref().filter(|_| short);
+        let continuation = if short.is_some() {
+            &[][..]
+        } else {
+            &self.continuation[..]
+        };
+        std::iter::once(self.displayed_content(short.is_some())).chain(continuation)
+    }
+
+    /// Returns the text of the content without styles, see [`StatusBar::describe`].
+    fn plain_text(&self) -> String {
+        let texts: Vec<String> = match &self.group {
+            Some(group) => group.describe().into_iter().map(|(_, text)| text).collect(),
+            None => std::iter::once(&self.content)
+                .chain(&self.continuation)
+                .map(|line| line.to_string().trim().to_string())
+                .collect(),
+        };
+        texts
+            .into_iter()
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns the number of lines of the content.
+    fn line_count(&self) -> usize {
+        self.group
+            .as_ref()
+            .map_or(1 + self.continuation.len(), |group| {
+                usize::from(group.height())
+            })
+    }
+
+    /// Returns the narrowest width the section can be rendered in without disappearing: its
+    /// separators and one column of content, or its minimum width if that is wider.
+    fn narrowest_width(&self) -> usize {
+        let width = match (&self.group, &self.widget) {
+            (Some(group), _) => group.min_width(),
+            (None, Some(widget)) => usize::from(widget.width.min(1)),
+            (None, None) => width::line_width(&self.content).min(1),
+        };
+        self.clamp(self.separators_width() + width)
+    }
+
+    fn separators_width(&self) -> usize {
+        self.pre_separator.as_ref().map_or(0, width::span_width)
+            + self.post_separator.as_ref().map_or(0, width::span_width)
+    }
+
+    /// Links the section to a URL.
+    ///
+    /// Terminals can't be told about links through the rendered buffer, so links are written
+    /// separately with [`osc::write_hyperlinks`] after the frame has been drawn.
+    #[must_use]
+    pub fn hyperlink(mut self, url: impl Into<String>) -> Self {
+        self.hyperlink = Some(url.into());
+        self
+    }
+
+    /// Sets the [`Action`] run when the section is clicked or activated from the keyboard, see
+    /// [`StatusBar::activate`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::events::Action;
+    /// # use ratatui_statusbar::StatusBarSection;
+    /// let branch = "feature/clipboard";
+    /// let section = StatusBarSection::from(format!(" {branch}")).action(Action::Copy(branch.into()));
+    /// ```
+    #[must_use]
+    pub fn action(mut self, action: Action) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Sets the style of the section.
+    ///
+    /// The style is applied to the whole area of the section before the content is rendered:
+    /// the content, the padding added by [`min_width`](Self::min_width) and, with the default
+    /// [`SeparatorStyle::Section`], the separators. Styles of the content and of the separator
+    /// spans are patched on top, so they take precedence.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::StatusBarSection;
+    /// // a green block with a bold separator, no need to style the separator span on its own
+    /// let section = StatusBarSection::from("NORMAL")
+    ///     .pre_separator(" ")
+    ///     .post_separator(Span::raw(" \u{e0b0}").bold())
+    ///     .style(Style::new().black().on_green());
+    /// ```
+    #[must_use]
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a> Styled for StatusBarSection<'a> {
+    type Item = StatusBarSection<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<'a> From<StatusBar<'a>> for StatusBarSection<'a> {
+    fn from(group: StatusBar<'a>) -> Self {
+        StatusBarSection::default().group(group)
+    }
+}
+
+impl<'a> From<Text<'a>> for StatusBarSection<'a> {
+    fn from(text: Text<'a>) -> Self {
+        StatusBarSection::default().text(text)
+    }
 }
 
 impl<'a> From<Line<'a>> for StatusBarSection<'a> {
     fn from(line: Line<'a>) -> Self {
         StatusBarSection {
-            pre_separator: None,
             content: line,
-            post_separator: None,
+            ..Default::default()
         }
     }
 }
@@ -73,9 +935,8 @@ impl<'a> From<Line<'a>> for StatusBarSection<'a> {
 impl<'a> From<Span<'a>> for StatusBarSection<'a> {
     fn from(span: Span<'a>) -> Self {
         StatusBarSection {
-            pre_separator: None,
             content: span.into(),
-            post_separator: None,
+            ..Default::default()
         }
     }
 }
@@ -83,32 +944,178 @@ impl<'a> From<Span<'a>> for StatusBarSection<'a> {
 impl<'a> From<&'a str> for StatusBarSection<'a> {
     fn from(s: &'a str) -> Self {
         StatusBarSection {
-            pre_separator: None,
             content: s.into(),
-            post_separator: None,
+            ..Default::default()
         }
     }
 }
 
+impl<'a> From<String> for StatusBarSection<'a> {
+    fn from(s: String) -> Self {
+        StatusBarSection::default().text(s)
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for StatusBarSection<'a> {
+    fn from(s: Cow<'a, str>) -> Self {
+        StatusBarSection::default().text(s)
+    }
+}
+
+/// Creates a section from its content and style.
+///
+/// # Examples
+/// ```
+/// # use ratatui::prelude::*;
+/// # use ratatui_statusbar::StatusBar;
+/// let status_bar = StatusBar::new(1).section(0, ("NORMAL", Style::new().on_green()))?;
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+impl<'a, T, S> From<(T, S)> for StatusBarSection<'a>
+where
+    T: Into<Text<'a>>,
+    S: Into<Style>,
+{
+    fn from((content, style): (T, S)) -> Self {
+        StatusBarSection::default().text(content).style(style)
+    }
+}
+
+/// Where the separators of a [`StatusBar`] take their style from.
+///
+/// The style of the separator span is always applied on top of the inherited style, so a
+/// separator can e.g. inherit the background of a section and set its own foreground.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SeparatorStyle {
+    /// Separators inherit the style of the section they belong to.
+    #[default]
+    Section,
+    /// Separators inherit the style of the section before them, i.e. post-separators use the
+    /// style of their own section and pre-separators the style of the previous section.
+    Preceding,
+    /// Separators inherit the style of the section after them, i.e. pre-separators use the style
+    /// of their own section and post-separators the style of the next section.
+    Following,
+    /// Separators inherit only the style of the [`StatusBar`].
+    Bar,
+    /// Separators only use the style of their own span.
+    Explicit,
+}
+
+/// Which separator of a [`StatusBar`] is kept when the post-separator of a section meets the
+/// pre-separator of the next visible section.
+///
+/// # Examples
+/// ```
+/// # use ratatui::prelude::*;
+/// # use ratatui_statusbar::{SeparatorMerge, StatusBar, StatusBarSection};
+/// let status_bar = StatusBar::new(2)
+///     .spacing(0u16)
+///     .separator_merge(SeparatorMerge::Preceding)
+///     .section(0, StatusBarSection::from("a").post_separator(" | "))?
+///     .section(1, StatusBarSection::from("b").pre_separator(" | "))?;
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+/// Widget::render(status_bar, buf.area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(vec!["a | b"]));
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SeparatorMerge {
+    /// Both separators are rendered.
+    #[default]
+    Keep,
+    /// The post-separator of the section before wins.
+    Preceding,
+    /// The pre-separator of the section after wins.
+    Following,
+}
+
 /// A customizable [`StatusBar`] that can contain multiple sections.
 ///
 /// # Examples
 /// ```
+/// # use ratatui::layout::Flex;
+/// # use ratatui_statusbar::{StatusBar, StatusBarError};
+/// # fn main() -> Result<(), StatusBarError> {
 /// let status_bar = StatusBar::new(3)
 ///     .flex(Flex::Center)
-///     .spacing(2)
+///     .spacing(2u16)
 ///     .section(0, "Left Section")?
 ///     .section(1, "Center Section")?
 ///     .section(2, "Right Section")?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The status bar implements [`Styled`], so the [`Stylize`] shorthands can be used to set the
+/// style of the whole bar, which sections and content are rendered on top of:
+/// ```
+/// # use ratatui::prelude::*;
+/// # use ratatui_statusbar::StatusBar;
+/// let status_bar = StatusBar::new(2).white().on_dark_gray();
 /// ```
-#[derive(Debug, Default)]
+///
+/// Status bars can be compared, e.g. to skip redrawing a bar that didn't change:
+/// ```
+/// # use ratatui_statusbar::StatusBar;
+/// let previous = StatusBar::new(1).section(0, "12:00")?;
+/// let current = StatusBar::new(1).section(0, "12:01")?;
+/// assert_ne!(previous, current);
+/// assert_eq!(current.clone(), current);
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+///
+/// Styles cascade from the bar down to the content, each level [patched](Style::patch) on top of
+/// the one before, so content that e.g. only sets a foreground color keeps the background of its
+/// section:
+///
+/// 1. the style of the bar, e.g. [`Theme::bar`]
+/// 2. the style of the [`Role`] of the section in the [`Theme`] of the bar
+/// 3. the style of the section
+/// 4. the styles of the content lines and spans
+///
+/// [Grouped](StatusBarSection::group) bars are rendered on top of the style of their section and
+/// use the theme of the enclosing bar unless they have their own. [Flashes](StatusBarState::flash)
+/// are applied on top of the section style and the [inactive style](StatusBar::inactive_style) on
+/// top of everything.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct StatusBar<'a> {
     sections: Vec<StatusBarSection<'a>>,
     flex: Flex,
     spacing: u16,
+    style: Style,
+    separator_style: SeparatorStyle,
+    separator_merge: SeparatorMerge,
+    theme: Option<Theme>,
+    inactive: bool,
+    inactive_style: Option<Style>,
+    breakpoints: Vec<(u16, Breakpoint)>,
+    right_to_left: bool,
+    compact: bool,
+    fill_from_end: bool,
+    ascii: bool,
+    color_depth: ColorDepth,
+    scrollable: bool,
+    scroll_indicators: bool,
+    overflow_indicator: bool,
+    on_render: Option<RenderHook>,
 }
 
 impl<'a> StatusBar<'a> {
+    /// The default style applied on top of an inactive [`StatusBar`].
+    pub const DEFAULT_INACTIVE_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
     /// Initializes a new [`StatusBar`] with a specified number of sections, all set to default.
     #[must_use]
     pub fn new(nsections: usize) -> Self {
@@ -116,10 +1123,64 @@ impl<'a> StatusBar<'a> {
             sections: vec![StatusBarSection::default(); nsections],
             flex: Flex::default(),
             spacing: 1,
+            style: Style::default(),
+            separator_style: SeparatorStyle::default(),
+            separator_merge: SeparatorMerge::default(),
+            theme: None,
+            inactive: false,
+            inactive_style: None,
+            breakpoints: Vec::new(),
+            right_to_left: false,
+            compact: false,
+            fill_from_end: false,
+            ascii: false,
+            color_depth: ColorDepth::TrueColor,
+            scrollable: false,
+            scroll_indicators: false,
+            overflow_indicator: false,
+            on_render: None,
         }
     }
 
-    /// Configures the flex layout mode of the sections in the [`StatusBar`].
+    /// Calls `hook` with the time each render of the [`StatusBar`] took, e.g. to find out whether
+    /// the status bar slows down a TUI.
+    ///
+    /// Renders are timed with [`Instant`], which isn't available on `wasm32-unknown-unknown`.
+    ///
+    /// With the `tracing` feature, every render is also recorded in a `statusbar_render` span.
+    /// Refresh durations of segments are reported by [`Timed`](segments::Timed).
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::prelude::*;
+    /// use ratatui_statusbar::StatusBar;
+    ///
+    /// let slowest = Arc::new(Mutex::new(Duration::ZERO));
+    /// let status_bar = StatusBar::new(1).section(0, "NORMAL")?.on_render({
+    ///     let slowest = slowest.clone();
+    ///     move |elapsed| {
+    ///         let mut slowest = slowest.lock().unwrap();
+    ///         *slowest = elapsed.max(*slowest);
+    ///     }
+    /// });
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// Widget::render(&status_bar, buf.area, &mut buf);
+    /// assert!(*slowest.lock().unwrap() > Duration::ZERO);
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn on_render<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.on_render = Some(RenderHook(Arc::new(hook)));
+        self
+    }
+
+    /// Configures the flex layout mode of the sections in the [`StatusBar`].
     #[must_use]
     pub fn flex(mut self, flex: Flex) -> Self {
         self.flex = flex;
@@ -127,12 +1188,372 @@ impl<'a> StatusBar<'a> {
     }
 
     /// Sets the spacing between [`StatusBar`] sections.
+    ///
+    /// Individual gaps can be changed with [`StatusBarSection::gap_after`] or
+    /// [`StatusBar::gaps`].
     #[must_use]
     pub fn spacing(mut self, spacing: impl Into<u16>) -> Self {
         self.spacing = spacing.into();
         self
     }
 
+    /// Sets the gap after each section in order, see [`StatusBarSection::gap_after`].
+    ///
+    /// Sections without a corresponding gap keep using the spacing of the [`StatusBar`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::StatusBar;
+    /// // Group the first two sections tightly and leave a wide gap before the third.
+    /// let status_bar = StatusBar::new(3)
+    ///     .section(0, "NORMAL")?
+    ///     .section(1, "main")?
+    ///     .section(2, "12:00")?
+    ///     .gaps([0, 4]);
+    /// assert_eq!(status_bar.desired_width(), 19);
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn gaps(mut self, gaps: impl IntoIterator<Item = u16>) -> Self {
+        for (section, gap) in self.sections.iter_mut().zip(gaps) {
+            section.gap_after = Some(gap);
+        }
+        self
+    }
+
+    /// Sets the weights of the gaps after the sections, in order.
+    ///
+    /// When any gap between visible sections has a weight, the columns left over after the
+    /// sections and gaps are distributed among the gaps in proportion to their weights instead of
+    /// according to the [flex](Self::flex) mode. This allows layouts such as a tight cluster on
+    /// the left and all remaining space before a cluster on the right.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::StatusBar;
+    /// let status_bar = StatusBar::new(4)
+    ///     .section(0, "a")?
+    ///     .section(1, "b")?
+    ///     .section(2, "c")?
+    ///     .section(3, "d")?
+    ///     .gap_weights([0, 2, 1]);
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 13, 1));
+    /// Widget::render(status_bar, buf.area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["a b     c   d"]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn gap_weights(mut self, weights: impl IntoIterator<Item = u16>) -> Self {
+        for (section, weight) in self.sections.iter_mut().zip(weights) {
+            section.gap_weight = weight;
+        }
+        self
+    }
+
+    /// Sets the style of the whole [`StatusBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Applies the bar and inactive styles of `theme`.
+    ///
+    /// Sections are styled with the theme through their [role](StatusBarSection::role), or
+    /// individually, e.g. with [`Theme::accent`].
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self.style = theme.bar;
+        self.inactive_style = Some(theme.inactive);
+        self
+    }
+
+    /// Sets where separators take their style from.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{SeparatorStyle, StatusBar, StatusBarSection};
+    /// // Separators take the colors of the section to their left.
+    /// let status_bar = StatusBar::new(2)
+    ///     .separator_style(SeparatorStyle::Preceding)
+    ///     .section(0, StatusBarSection::from("NORMAL").post_separator(" ").on_green())
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn separator_style(mut self, separator_style: SeparatorStyle) -> Self {
+        self.separator_style = separator_style;
+        self
+    }
+
+    /// Sets which separator is kept when two visible sections next to each other both have one
+    /// on the side they meet, see [`SeparatorMerge`].
+    #[must_use]
+    pub fn separator_merge(mut self, separator_merge: SeparatorMerge) -> Self {
+        self.separator_merge = separator_merge;
+        self
+    }
+
+    /// Marks the [`StatusBar`] as belonging to an unfocused window.
+    ///
+    /// An inactive status bar is rendered as usual and then covered with the inactive style, which
+    /// dims it by default. This mirrors the active and inactive statuslines editors show for split
+    /// windows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::StatusBar;
+    /// let focused = false;
+    /// let status_bar = StatusBar::new(2).inactive(!focused);
+    /// ```
+    #[must_use]
+    pub fn inactive(mut self, inactive: bool) -> Self {
+        self.inactive = inactive;
+        self
+    }
+
+    /// Sets the style applied on top of the [`StatusBar`] while it is [inactive](Self::inactive).
+    ///
+    /// Defaults to [`StatusBar::DEFAULT_INACTIVE_STYLE`]. Setting colors here replaces the colors
+    /// of all sections, e.g. `Style::new().dark_gray().on_black()`.
+    #[must_use]
+    pub fn inactive_style(mut self, style: impl Into<Style>) -> Self {
+        self.inactive_style = Some(style.into());
+        self
+    }
+
+    /// Lays the [`StatusBar`] out from right to left, for right-to-left languages such as Arabic
+    /// and Hebrew.
+    ///
+    /// The first section is placed at the right edge, the flex layout and the alignment of the
+    /// content are mirrored, and the pre-separator of each section is drawn on its right side.
+    /// Content is written in logical order, leaving the display of right-to-left text to the
+    /// bidirectional support of the terminal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::StatusBar;
+    /// let status_bar = StatusBar::new(2)
+    ///     .section(0, "עברית")?
+    ///     .section(1, "12:00")?
+    ///     .right_to_left(true);
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn right_to_left(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+
+    /// Removes chrome before content is truncated when the [`StatusBar`] doesn't fit.
+    ///
+    /// A compact status bar that is too wide for its area first drops the spacing between
+    /// sections, then the separators and finally the whitespace padding the content of each
+    /// section, stopping as soon as it fits. Only if it still doesn't fit is content truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// let status_bar = StatusBar::new(2)
+    ///     .section(0, StatusBarSection::from(" NORMAL ").post_separator(" |"))?
+    ///     .section(1, " main ")?
+    ///     .compact(true);
+    ///
+    /// // Without the spacing and the separator the sections fit in full.
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 14, 1));
+    /// Widget::render(status_bar, buf.area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(vec![" NORMAL  main "]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Fills the [`StatusBar`] from the right edge when the sections don't fit, so that the
+    /// sections on the left are clipped or left out instead of the ones on the right.
+    ///
+    /// This keeps critical indicators on the right visible in narrow terminals. The flex layout
+    /// only applies while all sections fit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::StatusBar;
+    /// let status_bar = StatusBar::new(2)
+    ///     .section(0, "~/src/ratatui-statusbar")?
+    ///     .section(1, "ERROR")?
+    ///     .fill_from_end(true);
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// Widget::render(status_bar, buf.area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["~/sr ERROR"]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn fill_from_end(mut self, fill_from_end: bool) -> Self {
+        self.fill_from_end = fill_from_end;
+        self
+    }
+
+    /// Renders the [`StatusBar`] in pure ASCII, for terminals and locales where Unicode isn't
+    /// displayed reliably.
+    ///
+    /// Separators and content are converted character by character: box drawing characters,
+    /// arrows and powerline glyphs become similar ASCII characters, the ellipsis of truncated
+    /// content becomes `.`, and anything else that isn't ASCII becomes `?`. Built-in
+    /// [`segments`] follow the global [`IconSet`](icons::IconSet) instead, so set it to
+    /// [`IconSet::Ascii`](icons::IconSet::Ascii) as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// let status_bar = StatusBar::new(2)
+    ///     .section(0, StatusBarSection::from("NORMAL").post_separator(" \u{e0b0}"))?
+    ///     .section(1, "main │ utf-8")?
+    ///     .ascii(true);
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 21, 1));
+    /// Widget::render(status_bar, buf.area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["NORMAL > main | utf-8"]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Sets how many colors the terminal can display. Colors it can't display are converted to
+    /// the nearest color it can when the [`StatusBar`] is rendered, see [`ColorDepth`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{ColorDepth, StatusBar, Theme};
+    /// let status_bar = StatusBar::new(1)
+    ///     .section(0, "NORMAL")?
+    ///     .theme(Theme::dark())
+    ///     .color_depth(ColorDepth::Indexed256);
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+    /// Widget::render(status_bar, buf.area, &mut buf);
+    /// assert_eq!(buf.get(0, 0).bg, Color::Indexed(235));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Lets the [`StatusBar`] scroll horizontally when its sections don't fit, instead of
+    /// truncating them.
+    ///
+    /// The sections are laid out at their full width and the visible window is panned with
+    /// [`StatusBarState::scroll_left`] and [`StatusBarState::scroll_right`], so the bar has to be
+    /// rendered as a [`StatefulWidget`]. Content is still truncated when the bar fits, e.g. to
+    /// [`max_width`](StatusBarSection::max_width), and [compaction](Self::compact) is applied
+    /// before scrolling. [`StatusBar::areas`] and [`StatusBar::hit_test`] don't take the scroll
+    /// offset into account.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarState};
+    /// let status_bar = StatusBar::new(3)
+    ///     .section(0, "one")?
+    ///     .section(1, "two")?
+    ///     .section(2, "three")?
+    ///     .scrollable(true)
+    ///     .scroll_indicators(true);
+    /// let mut state = StatusBarState::default();
+    /// state.scroll_right(4);
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+    /// StatefulWidget::render(status_bar, buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["‹wo thr›"]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn scrollable(mut self, scrollable: bool) -> Self {
+        self.scrollable = scrollable;
+        self
+    }
+
+    /// Marks the edges of a [scrolled](Self::scrollable) [`StatusBar`] that hide content with
+    /// `‹` and `›`, or `<` and `>` in [ASCII](Self::ascii) mode.
+    #[must_use]
+    pub fn scroll_indicators(mut self, scroll_indicators: bool) -> Self {
+        self.scroll_indicators = scroll_indicators;
+        self
+    }
+
+    /// Shows how many sections are left out for lack of space, e.g. `+3`, at the edge of the
+    /// [`StatusBar`] where sections are dropped, so that elided information isn't mistaken for
+    /// absent information.
+    ///
+    /// The indicator is placed on the right, or on the left when the bar
+    /// [fills from the end](Self::fill_from_end), mirrored for [right-to-left](Self::right_to_left)
+    /// bars. Sections hidden by a [`Breakpoint`] or a [visibility](StatusBarSection::hidden)
+    /// setting aren't counted. Clicking the indicator is reported as [`BarEvent::Overflow`] and
+    /// [`StatusBar::overflow_tooltip`] lists the left out sections. The text of the indicator is
+    /// the [`labels::OVERFLOW`] label.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::StatusBar;
+    /// let status_bar = StatusBar::new(3)
+    ///     .section(0, "~/src/ratatui-statusbar")?
+    ///     .section(1, "main")?
+    ///     .section(2, "ERROR")?
+    ///     .fill_from_end(true)
+    ///     .overflow_indicator(true);
+    ///
+    /// let area = Rect::new(0, 0, 13, 1);
+    /// assert_eq!(status_bar.overflowed(area), [0]);
+    /// let mut buf = Buffer::empty(area);
+    /// Widget::render(status_bar, area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["+1 main ERROR"]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    ///
+    /// [`BarEvent::Overflow`]: crate::events::BarEvent::Overflow
+    #[must_use]
+    pub fn overflow_indicator(mut self, overflow_indicator: bool) -> Self {
+        self.overflow_indicator = overflow_indicator;
+        self
+    }
+
+    /// Applies `breakpoint` while the [`StatusBar`] is rendered into an area at most `max_width`
+    /// columns wide.
+    ///
+    /// When several breakpoints match, the one with the smallest `max_width` applies. See
+    /// [`Breakpoint`] for an example.
+    #[must_use]
+    pub fn breakpoint(mut self, max_width: u16, breakpoint: Breakpoint) -> Self {
+        let index = self
+            .breakpoints
+            .partition_point(|(width, _)| *width <= max_width);
+        self.breakpoints.insert(index, (max_width, breakpoint));
+        self
+    }
+
+    /// Returns the breakpoint that applies to an area `width` columns wide.
+    fn active_breakpoint(&self, width: u16) -> Option<&Breakpoint> {
+        self.breakpoints
+            .iter()
+            .find(|(max_width, _)| width <= *max_width)
+            .map(|(_, breakpoint)| breakpoint)
+    }
+
     /// Modifies a specific section within the [`StatusBar`] based on its index.
     ///
     /// # Errors
@@ -150,44 +1571,1285 @@ impl<'a> StatusBar<'a> {
             Err(StatusBarError::IndexOutOfBounds(index))
         }
     }
+
+    /// Replaces the section named `name`.
+    ///
+    /// The new section keeps the name, unless it is named differently itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::UnknownSection`] if no section is named `name`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// let status_bar = StatusBar::new(2)
+    ///     .section(0, StatusBarSection::from("NORMAL").name("mode"))?
+    ///     .named_section("mode", "INSERT")?;
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    pub fn named_section(
+        mut self,
+        name: &str,
+        section: impl Into<StatusBarSection<'a>>,
+    ) -> Result<Self, StatusBarError> {
+        let slot = self
+            .sections
+            .iter_mut()
+            .find(|s| s.name.as_deref() == Some(name))
+            .ok_or_else(|| StatusBarError::UnknownSection(name.to_string()))?;
+        let mut section = section.into();
+        if section.name.is_none() {
+            section.name = slot.name.take();
+        }
+        *slot = section;
+        Ok(self)
+    }
+
+    /// Replaces only the content of the section named `name`, keeping its separators, style and
+    /// other settings, e.g. to fill in the sections of a [`Preset`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::UnknownSection`] if no section is named `name`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// let status_bar = StatusBar::new(1)
+    ///     .section(0, StatusBarSection::default().name("mode").pre_separator("["))?
+    ///     .named_content("mode", "INSERT")?;
+    /// assert_eq!(status_bar.desired_width(), 7);
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    pub fn named_content(
+        mut self,
+        name: &str,
+        content: impl Into<Text<'a>>,
+    ) -> Result<Self, StatusBarError> {
+        let slot = self
+            .sections
+            .iter_mut()
+            .find(|s| s.name.as_deref() == Some(name))
+            .ok_or_else(|| StatusBarError::UnknownSection(name.to_string()))?;
+        *slot = std::mem::take(slot).text(content);
+        Ok(self)
+    }
+
+    /// Shows the section named `name` if it is hidden, or hides it if it is shown, and returns
+    /// whether it is shown now.
+    ///
+    /// [Optional](StatusBarSection::optional) sections start out hidden and the others shown. The
+    /// change is recorded in `state`, so it survives rebuilding the [`StatusBar`] every frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::UnknownSection`] if no section is named `name`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection, StatusBarState};
+    /// let status_bar = StatusBar::new(2)
+    ///     .section(0, "NORMAL")?
+    ///     .section(1, StatusBarSection::from("12:00").name("clock").optional(true))?;
+    /// let mut state = StatusBarState::default();
+    /// assert!(status_bar.toggle_section("clock", &mut state)?);
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+    /// StatefulWidget::render(status_bar, buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["NORMAL 12:00"]));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    pub fn toggle_section(
+        &self,
+        name: &str,
+        state: &mut StatusBarState,
+    ) -> Result<bool, StatusBarError> {
+        let index = self
+            .sections
+            .iter()
+            .position(|s| s.name.as_deref() == Some(name))
+            .ok_or_else(|| StatusBarError::UnknownSection(name.to_string()))?;
+        state.toggle_section(index);
+        Ok(self.sections[index].optional == state.is_toggled(index))
+    }
+
+    /// Shows the next [optional](StatusBarSection::optional) section and hides the others, or
+    /// hides all of them after the last one, and returns the index of the shown section.
+    ///
+    /// Binding this to a key rotates through extra sections in limited space, e.g. from the clock
+    /// to the battery level to nothing. The shown section is recorded in `state`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection, StatusBarState};
+    /// let status_bar = StatusBar::new(3)
+    ///     .section(0, "NORMAL")?
+    ///     .section(1, StatusBarSection::from("12:00").optional(true))?
+    ///     .section(2, StatusBarSection::from("80%").optional(true))?;
+    /// let mut state = StatusBarState::default();
+    /// assert_eq!(status_bar.cycle_optional(&mut state), Some(1));
+    /// assert_eq!(status_bar.cycle_optional(&mut state), Some(2));
+    /// assert_eq!(status_bar.cycle_optional(&mut state), None);
+    /// assert_eq!(status_bar.cycle_optional(&mut state), Some(1));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    pub fn cycle_optional(&self, state: &mut StatusBarState) -> Option<usize> {
+        let optional = (0..self.sections.len())
+            .filter(|index| self.sections[*index].optional)
+            .collect::<Vec<_>>();
+        let next = optional
+            .iter()
+            .rposition(|index| state.is_toggled(*index))
+            .map_or(0, |position| position + 1);
+        for &index in &optional {
+            if state.is_toggled(index) {
+                state.toggle_section(index);
+            }
+        }
+        let shown = optional.get(next).copied();
+        if let Some(index) = shown {
+            state.toggle_section(index);
+        }
+        shown
+    }
+
+    /// Returns a copy of the [`StatusBar`] with the sections toggled in `state` shown or hidden,
+    /// or `None` if no section is toggled.
+    fn toggled(&self, state: &StatusBarState) -> Option<StatusBar<'a>> {
+        if !(0..self.sections.len()).any(|index| state.is_toggled(index)) {
+            return None;
+        }
+        let mut bar = self.clone();
+        for (index, section) in bar.sections.iter_mut().enumerate() {
+            if state.is_toggled(index) {
+                if section.optional {
+                    section.optional = false;
+                } else {
+                    section.hidden = true;
+                }
+            }
+        }
+        Some(bar)
+    }
+
+    /// Moves the section at `from` to `to`, shifting the sections in between.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::IndexOutOfBounds`] if either index does not exist.
+    pub fn move_section(mut self, from: usize, to: usize) -> Result<Self, StatusBarError> {
+        let len = self.sections.len();
+        if let Some(index) = [from, to].into_iter().find(|index| *index >= len) {
+            return Err(StatusBarError::IndexOutOfBounds(index));
+        }
+        let section = self.sections.remove(from);
+        self.sections.insert(to, section);
+        Ok(self)
+    }
+
+    /// Returns the number of rows the [`StatusBar`] needs: one, or the number of lines of the
+    /// tallest section with [multi-line](StatusBarSection::text) content.
+    #[must_use]
+    pub fn height(&self) -> u16 {
+        let lines = self
+            .shown()
+            .map(StatusBarSection::line_count)
+            .max()
+            .unwrap_or(1);
+        u16::try_from(lines).unwrap_or(u16::MAX)
+    }
+
+    /// Returns a label and the text of each section that isn't hidden and has content, e.g. for
+    /// screen readers or debugging overlays.
+    ///
+    /// The label is the [name](StatusBarSection::name) of the section, or its index for unnamed
+    /// sections. The text is the content without styles, separators and surrounding whitespace,
+    /// with the lines of multi-line content and the sections of [groups](StatusBarSection::group)
+    /// joined by spaces.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::{StatusBar, StatusBarSection};
+    /// let status_bar = StatusBar::new(3)
+    ///     .section(0, StatusBarSection::from(" main ").name("git"))?
+    ///     .section(1, StatusBarSection::from("2").name("errors").pre_separator("E:"))?
+    ///     .section(2, "utf-8")?;
+    /// let description = status_bar
+    ///     .describe()
+    ///     .into_iter()
+    ///     .map(|(label, text)| format!("{label}: {text}"))
+    ///     .collect::<Vec<_>>()
+    ///     .join(", ");
+    /// assert_eq!(description, "git: main, errors: 2, 2: utf-8");
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn describe(&self) -> Vec<(String, String)> {
+        self.sections
+            .iter()
+            .enumerate()
+            .filter(|(_, section)| !section.is_hidden())
+            .filter_map(|(index, section)| {
+                let text = section.plain_text();
+                let label = section.name.clone().unwrap_or_else(|| index.to_string());
+                (!text.is_empty()).then_some((label, text))
+            })
+            .collect()
+    }
+
+    /// Returns the number of columns the [`StatusBar`] needs to show all sections in full,
+    /// including separators and spacing.
+    ///
+    /// [Hidden](StatusBarSection::hidden) sections are not counted, but sections with a
+    /// [visibility predicate](StatusBarSection::visible_when) are, since the predicate depends
+    /// on the area.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui_statusbar::StatusBar;
+    /// let status_bar = StatusBar::new(2).section(0, "hello")?.section(1, "world")?;
+    /// assert_eq!(status_bar.desired_width(), 11);
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn desired_width(&self) -> usize {
+        if self.ascii {
+            return self.to_ascii().desired_width();
+        }
+        self.shown().map(StatusBarSection::width).sum::<usize>() + self.spacing_width()
+    }
+
+    /// Returns the narrowest width the [`StatusBar`] can be rendered in while every section is
+    /// still visible, i.e. shows its separators and at least one column of its content.
+    ///
+    /// Below [`StatusBar::desired_width`] content is clipped, which is a good point to switch to a
+    /// more compact status bar.
+    #[must_use]
+    pub fn min_width(&self) -> usize {
+        if self.ascii {
+            return self.to_ascii().min_width();
+        }
+        self.shown()
+            .map(StatusBarSection::narrowest_width)
+            .sum::<usize>()
+            + self.spacing_width()
+    }
+
+    /// Returns the sections that are not hidden.
+    fn shown(&self) -> impl Iterator<Item = &StatusBarSection<'a>> {
+        self.sections.iter().filter(|section| !section.is_hidden())
+    }
+
+    fn spacing_width(&self) -> usize {
+        let count = self.shown().count();
+        self.shown()
+            .take(count.saturating_sub(1))
+            .map(|section| usize::from(section.gap_after.unwrap_or(self.spacing)))
+            .sum()
+    }
+
+    /// Splits `area`, typically the whole frame, into the content area above the [`StatusBar`] and
+    /// the area of the [`StatusBar`] at the bottom.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::StatusBar;
+    /// let status_bar = StatusBar::new(2);
+    /// let (content, bar) = status_bar.split(Rect::new(0, 0, 80, 24));
+    /// assert_eq!(content, Rect::new(0, 0, 80, 23));
+    /// assert_eq!(bar, Rect::new(0, 23, 80, 1));
+    /// ```
+    #[must_use]
+    pub fn split(&self, area: Rect) -> (Rect, Rect) {
+        split_bottom(area, self.height())
+    }
+
+    /// Returns the area each section, including its separators, occupies when the [`StatusBar`]
+    /// is rendered into `area`.
+    ///
+    /// Sections that are not visible get an empty area. Use [`StatusBar::areas_with`] for a bar
+    /// rendered with a [`StatusBarState`].
+    #[must_use]
+    pub fn areas(&self, area: Rect) -> Vec<Rect> {
+        self.areas_with(area, &StatusBarState::default())
+    }
+
+    /// Returns the area each section, including its separators, occupies when the [`StatusBar`]
+    /// is rendered into `area` with `state`, i.e. with the sections toggled in `state` and
+    /// [scrolled](Self::scrollable) to its scroll offset.
+    ///
+    /// Sections that are not visible or scrolled out of view get an empty area, and sections
+    /// partially scrolled out of view get their visible part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarState};
+    /// let status_bar = StatusBar::new(2).section(0, "one")?.section(1, "two")?.scrollable(true);
+    /// let area = Rect::new(0, 0, 5, 1);
+    /// let mut state = StatusBarState::default();
+    /// state.scroll_right(2);
+    /// assert_eq!(
+    ///     status_bar.areas_with(area, &state),
+    ///     [Rect::new(0, 0, 1, 1), Rect::new(2, 0, 3, 1)]
+    /// );
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn areas_with(&self, area: Rect, state: &StatusBarState) -> Vec<Rect> {
+        let mut areas = vec![Rect { width: 0, ..area }; self.sections.len()];
+        for (index, rect) in self.visible_layout(area, state) {
+            areas[index] = rect;
+        }
+        areas
+    }
+
+    /// Returns the index and area of each section that is visible when the [`StatusBar`] is
+    /// rendered into `area` with `state`, clipped to `area`.
+    fn visible_layout(&self, area: Rect, state: &StatusBarState) -> Vec<(usize, Rect)> {
+        let toggled = self.toggled(state);
+        let toggled = toggled.as_ref().unwrap_or(self);
+        let resolved = toggled.resolved(area);
+        let bar = resolved.as_ref().unwrap_or(toggled);
+        let Some((scrolled, full)) = bar.scrolled(area) else {
+            return bar.layout(area);
+        };
+        let offset = state.scroll_position(full.width - area.width, self.right_to_left);
+        scrolled
+            .layout(full)
+            .into_iter()
+            .filter_map(|(index, rect)| {
+                let left = rect.left().max(offset);
+                let right = rect.right().min(offset.saturating_add(area.width));
+                (left < right).then(|| {
+                    let rect = Rect {
+                        x: area.x + left - offset,
+                        y: area.y + rect.y,
+                        width: right - left,
+                        height: rect.height,
+                    };
+                    (index, rect)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the index, width and gap after each visible section.
+    fn slots(&self, area: Rect) -> Vec<(usize, u16, u16)> {
+        let context = RenderContext {
+            area,
+            inactive: self.inactive,
+        };
+        let breakpoint = self.active_breakpoint(area.width);
+        let short = breakpoint.is_some_and(|breakpoint| breakpoint.short);
+        let spacing = breakpoint
+            .and_then(|breakpoint| breakpoint.spacing)
+            .unwrap_or(self.spacing);
+        let mut slots = (0..self.sections.len())
+            .filter(|index| {
+                let section = &self.sections[*index];
+                section.is_visible(&context)
+                    && (section.pinned
+                        || !breakpoint
+                            .is_some_and(|breakpoint| breakpoint.hides(section.name.as_deref())))
+            })
+            .map(|index| {
+                let section = &self.sections[index];
+                let width = u16::try_from(section.width_for(short)).unwrap_or(u16::MAX);
+                (index, width, section.gap_after.unwrap_or(spacing))
+            })
+            .collect::<Vec<_>>();
+        if let Some((_, _, gap)) = slots.last_mut() {
+            *gap = 0;
+        }
+        slots
+    }
+
+    /// Shrinks and leaves out the slots of sections that are not pinned until the slots fit into
+    /// `area`, if any section is pinned.
+    fn make_room_for_pinned(&self, area: Rect, slots: &mut Vec<(usize, u16, u16)>) {
+        if !slots
+            .iter()
+            .any(|(index, _, _)| self.sections[*index].pinned)
+        {
+            return;
+        }
+        let required: usize = slots
+            .iter()
+            .map(|(_, width, gap)| usize::from(*width) + usize::from(*gap))
+            .sum();
+        let mut excess = required.saturating_sub(usize::from(area.width));
+        let mut positions = (0..slots.len())
+            .filter(|position| !self.sections[slots[*position].0].pinned)
+            .collect::<Vec<_>>();
+        if !self.fill_from_end {
+            positions.reverse();
+        }
+        for position in positions {
+            if excess == 0 {
+                break;
+            }
+            let (_, width, gap) = &mut slots[position];
+            let cut = u16::try_from(excess).unwrap_or(u16::MAX).min(*width);
+            *width -= cut;
+            excess -= usize::from(cut);
+            if *width == 0 {
+                excess = excess.saturating_sub(usize::from(*gap));
+                *gap = 0;
+            }
+        }
+        slots.retain(|(_, width, _)| *width > 0);
+        if let Some((_, _, gap)) = slots.last_mut() {
+            *gap = 0;
+        }
+    }
+
+    /// Returns the number of columns the visible sections need in `area`.
+    fn required_width(&self, area: Rect) -> usize {
+        self.slots(area)
+            .into_iter()
+            .map(|(_, width, gap)| usize::from(width) + usize::from(gap))
+            .sum()
+    }
+
+    /// Returns the index of the section at `position` when the [`StatusBar`] is rendered into
+    /// `area`, including its separators.
+    #[must_use]
+    pub fn hit_test(&self, area: Rect, position: impl Into<Position>) -> Option<usize> {
+        self.hit_test_with(area, &StatusBarState::default(), position)
+    }
+
+    /// Returns the index of the section at `position` when the [`StatusBar`] is rendered into
+    /// `area` with `state`, including its separators. See [`StatusBar::areas_with`].
+    #[must_use]
+    pub fn hit_test_with(
+        &self,
+        area: Rect,
+        state: &StatusBarState,
+        position: impl Into<Position>,
+    ) -> Option<usize> {
+        let position = position.into();
+        self.visible_layout(area, state)
+            .into_iter()
+            .find(|(_, rect)| rect.contains(position))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the [data](StatusBarSection::data) of the section at `position` when the
+    /// [`StatusBar`] is rendered into `area`, if it is a `T`.
+    #[must_use]
+    pub fn data_at<T: Any>(&self, area: Rect, position: impl Into<Position>) -> Option<&T> {
+        self.sections[self.hit_test(area, position)?].data_ref()
+    }
+
+    /// Returns the [`Tooltip`] of the section selected with [`StatusBarState::show_tooltip`],
+    /// anchored to the section when the [`StatusBar`] is rendered into `area`.
+    ///
+    /// Returns `None` if no section is selected or the section isn't visible or has no tooltip.
+    /// See [`Tooltip`] for an example.
+    #[must_use]
+    pub fn tooltip(&self, area: Rect, state: &StatusBarState) -> Option<Tooltip<'a>> {
+        let index = state.tooltip()?;
+        let text = self.sections.get(index)?.tooltip.clone()?;
+        let (_, anchor) = self
+            .visible_layout(area, state)
+            .into_iter()
+            .find(|(visible, _)| *visible == index)?;
+        Some(Tooltip::new(text, anchor).style(self.style))
+    }
+
+    /// Returns the indices of the visible sections that are left out for lack of space when the
+    /// [`StatusBar`] is rendered into `area`, see [`StatusBar::overflow_indicator`].
+    #[must_use]
+    pub fn overflowed(&self, area: Rect) -> Vec<usize> {
+        let resolved = self.resolved(area);
+        let bar = resolved.as_ref().unwrap_or(self);
+        bar.overflow(area)
+            .map_or_else(|| bar.left_out(area, area), |(_, _, hidden)| hidden)
+    }
+
+    /// Returns whether `position` is on the [overflow indicator](Self::overflow_indicator) when
+    /// the [`StatusBar`] is rendered into `area`.
+    #[must_use]
+    pub fn overflow_hit(&self, area: Rect, position: impl Into<Position>) -> bool {
+        let resolved = self.resolved(area);
+        let bar = resolved.as_ref().unwrap_or(self);
+        bar.overflow(area)
+            .is_some_and(|(indicator, _, _)| indicator.contains(position.into()))
+    }
+
+    /// Returns a [`Tooltip`] listing the content of the sections left out for lack of space,
+    /// anchored to the [overflow indicator](Self::overflow_indicator) when the [`StatusBar`] is
+    /// rendered into `area`.
+    ///
+    /// Returns `None` if the indicator isn't shown, e.g. to show the list after a
+    /// [`BarEvent::Overflow`](events::BarEvent::Overflow).
+    #[must_use]
+    pub fn overflow_tooltip(&self, area: Rect) -> Option<Tooltip<'a>> {
+        let resolved = self.resolved(area);
+        let bar = resolved.as_ref().unwrap_or(self);
+        let (anchor, _, hidden) = bar.overflow(area)?;
+        let lines = hidden
+            .into_iter()
+            .map(|index| bar.sections[index].plain_text())
+            .filter(|text| !text.is_empty())
+            .map(Line::from)
+            .collect::<Vec<_>>();
+        Some(Tooltip::new(lines, anchor).style(self.style))
+    }
+
+    /// Returns the copy of a [scrollable](Self::scrollable) [`StatusBar`] that is rendered
+    /// off-screen at the width it needs, and the off-screen area, or `None` if the bar isn't
+    /// scrollable or fits into `area`.
+    fn scrolled(&self, area: Rect) -> Option<(StatusBar<'a>, Rect)> {
+        let required = self.required_width(area);
+        if !self.scrollable || required <= usize::from(area.width) {
+            return None;
+        }
+        // keep the breakpoint of the visible width for the wider off-screen area
+        let bar = StatusBar {
+            breakpoints: self
+                .active_breakpoint(area.width)
+                .map(|breakpoint| (u16::MAX, breakpoint.clone()))
+                .into_iter()
+                .collect(),
+            ..self.clone()
+        };
+        let full = Rect {
+            x: 0,
+            y: 0,
+            width: u16::try_from(required).unwrap_or(u16::MAX),
+            height: area.height,
+        };
+        Some((bar, full))
+    }
+
+    /// Renders the [`StatusBar`] off-screen into `full`, the area returned with it by
+    /// [`StatusBar::scrolled`], and copies the window at the scroll offset of `state` into
+    /// `area`.
+    fn draw_scrolled(&self, area: Rect, full: Rect, buf: &mut Buffer, state: &mut StatusBarState) {
+        let mut scratch = Buffer::empty(full);
+        self.draw(full, &mut scratch, state, self.ascii);
+        let max = full.width - area.width;
+        let offset = state.scroll_to(max, self.right_to_left);
+        for y in 0..area.height {
+            for x in 0..area.width {
+                *buf.get_mut(area.x + x, area.y + y) = scratch.get(offset + x, y).clone();
+            }
+        }
+        if self.scroll_indicators {
+            let (left, right) = if self.ascii {
+                ("<", ">")
+            } else {
+                ("‹", "›")
+            };
+            if offset > 0 {
+                buf.set_string(area.left(), area.top(), left, self.style);
+            }
+            if offset < max {
+                buf.set_string(area.right() - 1, area.top(), right, self.style);
+            }
+        }
+    }
+
+    /// Finishes dragging the section grabbed with [`StatusBarState::start_drag`] and moves it to
+    /// where it was dropped when the [`StatusBar`] is rendered into `area`.
+    ///
+    /// Returns the old and the new index of the section if it moved. Status bars are usually
+    /// rebuilt every frame, so the application should store the new order, e.g. by applying
+    /// [`StatusBar::move_section`] when building the bar.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_statusbar::{StatusBar, StatusBarState};
+    /// let mut status_bar = StatusBar::new(3).section(0, "a")?.section(1, "b")?.section(2, "c")?;
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let mut state = StatusBarState::default();
+    ///
+    /// // on mouse down, drag and up
+    /// if let Some(index) = status_bar.hit_test(area, (0, 0)) {
+    ///     state.start_drag(index, 0);
+    /// }
+    /// state.drag_to(5);
+    /// assert_eq!(status_bar.end_drag(area, &mut state), Some((0, 2)));
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    pub fn end_drag(&mut self, area: Rect, state: &mut StatusBarState) -> Option<(usize, usize)> {
+        let (_, column) = state.drag()?;
+        let from = state.end_drag()?;
+        let insert = self.drop_target(area, state, column)?.0;
+        let to = if insert > from { insert - 1 } else { insert };
+        if from == to || from >= self.sections.len() {
+            return None;
+        }
+        let section = self.sections.remove(from);
+        self.sections.insert(to, section);
+        Some((from, to))
+    }
+
+    /// Returns the index the dragged section is inserted before when dropped at `column`, and the
+    /// column of the insertion marker.
+    fn drop_target(&self, area: Rect, state: &StatusBarState, column: u16) -> Option<(usize, u16)> {
+        if area.is_empty() {
+            return None;
+        }
+        let mut visible = self.visible_layout(area, state);
+        visible.sort_by_key(|(_, rect)| rect.x);
+        let slot = visible
+            .iter()
+            .position(|(_, rect)| column < rect.x + rect.width / 2)
+            .unwrap_or(visible.len());
+        let (insert, marker) = match (visible.get(slot), visible.last()) {
+            (Some((index, rect)), _) => {
+                let insert = if self.right_to_left {
+                    index + 1
+                } else {
+                    *index
+                };
+                let marker = if slot > 0 {
+                    rect.x.saturating_sub(1)
+                } else {
+                    rect.x
+                };
+                (insert, marker)
+            }
+            (None, Some((index, rect))) => {
+                let insert = if self.right_to_left {
+                    *index
+                } else {
+                    index + 1
+                };
+                (insert, rect.right().min(area.right().saturating_sub(1)))
+            }
+            (None, None) => return None,
+        };
+        Some((insert, marker.max(area.x)))
+    }
+
+    /// Returns the index and area of each visible section, leaving room for the
+    /// [overflow indicator](Self::overflow_indicator).
+    fn layout(&self, area: Rect) -> Vec<(usize, Rect)> {
+        match self.overflow(area) {
+            Some((_, rest, _)) => self.place(rest),
+            None => self.place(area),
+        }
+    }
+
+    /// Returns the sections that are visible in `area` but left out of [`StatusBar::place`] for
+    /// lack of space.
+    fn left_out(&self, area: Rect, placed: Rect) -> Vec<usize> {
+        let layout = self.place(placed);
+        self.slots(area)
+            .into_iter()
+            .map(|(index, _, _)| index)
+            .filter(|index| {
+                !layout
+                    .iter()
+                    .any(|(placed, rect)| placed == index && rect.width > 0)
+            })
+            .collect()
+    }
+
+    /// Returns the area of the [overflow indicator](Self::overflow_indicator), the area left for
+    /// the sections and the sections left out, or `None` if no indicator is shown.
+    fn overflow(&self, area: Rect) -> Option<(Rect, Rect, Vec<usize>)> {
+        if !self.overflow_indicator
+            || self.scrollable
+            || self.required_width(area) <= usize::from(area.width)
+        {
+            return None;
+        }
+        let mut count = self.left_out(area, area).len().max(1);
+        let at_start = self.fill_from_end != self.right_to_left;
+        let split = |count: usize| {
+            let width = u16::try_from(width::width(&labels::format(labels::OVERFLOW, count)))
+                .unwrap_or(u16::MAX)
+                .min(area.width);
+            // keep a column between the indicator and the sections
+            let rest = area.width.saturating_sub(width + 1);
+            if at_start {
+                (
+                    Rect { width, ..area },
+                    Rect {
+                        x: area.right() - rest,
+                        width: rest,
+                        ..area
+                    },
+                )
+            } else {
+                (
+                    Rect {
+                        x: area.right() - width,
+                        width,
+                        ..area
+                    },
+                    Rect {
+                        width: rest,
+                        ..area
+                    },
+                )
+            }
+        };
+        // making room for the indicator can leave out more sections and widen the indicator
+        loop {
+            let (indicator, rest) = split(count);
+            let hidden = self.left_out(area, rest);
+            if hidden.is_empty() {
+                return None;
+            }
+            if split(hidden.len()).0.width == indicator.width {
+                return Some((indicator, rest, hidden));
+            }
+            count = hidden.len();
+        }
+    }
+
+    /// Returns the index and area of each visible section in `area`.
+    fn place(&self, area: Rect) -> Vec<(usize, Rect)> {
+        let mut slots = self.slots(area);
+        self.make_room_for_pinned(area, &mut slots);
+        let required: usize = slots
+            .iter()
+            .map(|(_, width, gap)| usize::from(*width) + usize::from(*gap))
+            .sum();
+        let layout = if self.fill_from_end && required > usize::from(area.width) {
+            Self::fill_end(area, &slots)
+        } else if let Some(slots) = self.weighted(area, &slots) {
+            Self::fill_start(area, &slots)
+        } else {
+            self.fill(area, &slots)
+        };
+        if !self.right_to_left {
+            return layout;
+        }
+        layout
+            .into_iter()
+            .map(|(index, rect)| {
+                let x = area.right() - (rect.x - area.x) - rect.width;
+                (index, Rect { x, ..rect })
+            })
+            .collect()
+    }
+
+    /// Lays out `slots` from left to right according to the flex mode.
+    fn fill(&self, area: Rect, slots: &[(usize, u16, u16)]) -> Vec<(usize, Rect)> {
+        // gaps are part of the slot of the preceding section, so that the flex modes distribute
+        // the remaining space between sections and not between sections and gaps
+        let rects = Layout::horizontal(
+            slots
+                .iter()
+                .map(|(_, width, gap)| Constraint::Length(width.saturating_add(*gap))),
+        )
+        .flex(self.flex)
+        .split(area);
+        let last = slots.len().saturating_sub(1);
+        slots
+            .iter()
+            .zip(rects.iter())
+            .enumerate()
+            .map(|(position, ((index, width, _), rect))| {
+                // the last slot has no gap and may be stretched by the legacy flex mode
+                let width = if position < last {
+                    rect.width.min(*width)
+                } else {
+                    rect.width
+                };
+                (*index, Rect { width, ..*rect })
+            })
+            .collect()
+    }
+
+    /// Returns `slots` with the free space of `area` added to the gaps according to their
+    /// weights, or `None` if no gap has a weight or there is no free space.
+    fn weighted(&self, area: Rect, slots: &[(usize, u16, u16)]) -> Option<Vec<(usize, u16, u16)>> {
+        let last = slots.len().checked_sub(1)?;
+        let weight = |position: usize| {
+            if position < last {
+                usize::from(self.sections[slots[position].0].gap_weight)
+            } else {
+                0
+            }
+        };
+        let total: usize = (0..slots.len()).map(weight).sum();
+        let required: usize = slots
+            .iter()
+            .map(|(_, width, gap)| usize::from(*width) + usize::from(*gap))
+            .sum();
+        let free = usize::from(area.width).checked_sub(required)?;
+        if total == 0 || free == 0 {
+            return None;
+        }
+        let mut cumulative = 0;
+        let weighted = slots
+            .iter()
+            .enumerate()
+            .map(|(position, &(index, width, gap))| {
+                let before = free * cumulative / total;
+                cumulative += weight(position);
+                let extra = free * cumulative / total - before;
+                (index, width, gap + u16::try_from(extra).unwrap_or(u16::MAX))
+            })
+            .collect();
+        Some(weighted)
+    }
+
+    /// Lays out `slots` one after the other from the left edge of `area`.
+    fn fill_start(area: Rect, slots: &[(usize, u16, u16)]) -> Vec<(usize, Rect)> {
+        let mut x = area.x;
+        slots
+            .iter()
+            .map(|&(index, width, gap)| {
+                let rect = Rect { x, width, ..area };
+                x += width + gap;
+                (index, rect)
+            })
+            .collect()
+    }
+
+    /// Lays out `slots` that don't fit into `area` from the right edge, clipping the first slot
+    /// that doesn't fit in full and leaving out the slots before it.
+    fn fill_end(area: Rect, slots: &[(usize, u16, u16)]) -> Vec<(usize, Rect)> {
+        let mut layout = Vec::new();
+        let mut right = area.right();
+        for &(index, width, gap) in slots.iter().rev() {
+            let available = right.saturating_sub(area.x).saturating_sub(gap);
+            let width = width.min(available);
+            if width == 0 {
+                break;
+            }
+            right -= gap + width;
+            layout.push((
+                index,
+                Rect {
+                    x: right,
+                    width,
+                    ..area
+                },
+            ));
+        }
+        layout.reverse();
+        layout
+    }
+
+    /// Returns the copy of the [`StatusBar`] that is rendered into `area`, or `None` if it is
+    /// rendered as is.
+    fn resolved(&self, area: Rect) -> Option<StatusBar<'a>> {
+        let ascii = self.ascii.then(|| self.to_ascii());
+        let bar = ascii.as_ref().unwrap_or(self);
+        let merged = bar.merged(area).or(ascii);
+        let bar = merged.as_ref().unwrap_or(self);
+        bar.compacted(area).or(merged)
+    }
+
+    /// Returns a copy of the [`StatusBar`] without the separators that lose to the separator
+    /// of a neighbor according to the [`SeparatorMerge`], or `None` if no separator is dropped.
+    fn merged(&self, area: Rect) -> Option<StatusBar<'a>> {
+        if self.separator_merge == SeparatorMerge::Keep {
+            return None;
+        }
+        let visible = self
+            .slots(area)
+            .into_iter()
+            .map(|(index, _, _)| index)
+            .collect::<Vec<_>>();
+        let mut bar = self.clone();
+        let mut merged = false;
+        for pair in visible.windows(2) {
+            let (before, after) = (pair[0], pair[1]);
+            if self.sections[before].post_separator.is_none()
+                || self.sections[after].pre_separator.is_none()
+            {
+                continue;
+            }
+            match self.separator_merge {
+                SeparatorMerge::Keep => {}
+                SeparatorMerge::Preceding => bar.sections[after].pre_separator = None,
+                SeparatorMerge::Following => bar.sections[before].post_separator = None,
+            }
+            merged = true;
+        }
+        merged.then_some(bar)
+    }
+
+    /// Returns a copy of the [`StatusBar`] with all text converted to ASCII.
+    fn to_ascii(&self) -> StatusBar<'a> {
+        let mut bar = StatusBar {
+            ascii: false,
+            ..self.clone()
+        };
+        for section in &mut bar.sections {
+            section.pre_separator = section.pre_separator.as_ref().map(charset::span_to_ascii);
+            section.post_separator = section.post_separator.as_ref().map(charset::span_to_ascii);
+            section.content = charset::line_to_ascii(&section.content);
+            section.continuation = section
+                .continuation
+                .iter()
+                .map(charset::line_to_ascii)
+                .collect();
+            section.short_content = section.short_content.as_ref().map(charset::line_to_ascii);
+            if let Some(group) = &mut section.group {
+                group.ascii = true;
+            }
+        }
+        bar
+    }
+
+    /// Returns a copy of the [`StatusBar`] with just enough chrome removed to fit into `area`,
+    /// or `None` if it fits as is or isn't [compact](Self::compact).
+    fn compacted(&self, area: Rect) -> Option<StatusBar<'a>> {
+        if !self.compact || self.required_width(area) <= usize::from(area.width) {
+            return None;
+        }
+        let mut bar = StatusBar {
+            compact: false,
+            ..self.clone()
+        };
+        let steps: [fn(&mut StatusBarSection<'a>); 3] = [
+            |section| section.gap_after = Some(0),
+            |section| {
+                section.pre_separator = None;
+                section.post_separator = None;
+            },
+            |section| {
+                section.content = truncation::trim(&section.content);
+                section.continuation = section.continuation.iter().map(truncation::trim).collect();
+                section.short_content = section.short_content.as_ref().map(truncation::trim);
+            },
+        ];
+        for step in steps {
+            bar.sections.iter_mut().for_each(step);
+            if bar.required_width(area) <= usize::from(area.width) {
+                break;
+            }
+        }
+        Some(bar)
+    }
+
+    /// Renders the [`StatusBar`] as is, after [compaction](Self::compact) and
+    /// [ASCII conversion](Self::ascii) have been resolved. `ascii` converts the ellipsis of
+    /// truncated content.
+    fn draw(&self, area: Rect, buf: &mut Buffer, state: &StatusBarState, ascii: bool) {
+        buf.set_style(area, self.style);
+        let short = self
+            .active_breakpoint(area.width)
+            .is_some_and(|breakpoint| breakpoint.short);
+        let layout = self.layout(area);
+        for (position, &(index, rect)) in layout.iter().enumerate() {
+            let section = &self.sections[index];
+            let previous = position.checked_sub(1).map(|position| layout[position].0);
+            let next = layout.get(position + 1).map(|(index, _)| *index);
+            buf.set_style(
+                rect,
+                state.section_style(index, self.section_style(section)),
+            );
+            let y = rect.top();
+            let pre = section.pre_separator.as_ref().map(|separator| {
+                let style = self
+                    .separator_base_style(index, false, previous, state)
+                    .patch(separator.style);
+                Span::styled(separator.content.as_ref(), style)
+            });
+            let post = section.post_separator.as_ref().map(|separator| {
+                let style = self
+                    .separator_base_style(index, true, next, state)
+                    .patch(separator.style);
+                Span::styled(separator.content.as_ref(), style)
+            });
+            let (left, right) = if self.right_to_left {
+                (post, pre)
+            } else {
+                (pre, post)
+            };
+            let mut x = rect.left();
+            if let Some(separator) = &left {
+                x = width::set_span(buf, x, y, separator, rect.right().saturating_sub(x));
+            }
+            let right_width = right.as_ref().map_or(0, width::span_width);
+            let available = rect
+                .right()
+                .saturating_sub(x)
+                .saturating_sub(u16::try_from(right_width).unwrap_or(u16::MAX));
+            if let Some(group) = &section.group {
+                let area = Rect {
+                    x,
+                    width: available,
+                    ..rect
+                };
+                if group.theme.is_none() && self.theme.is_some() {
+                    let group = StatusBar {
+                        theme: self.theme,
+                        ..group.as_ref().clone()
+                    };
+                    WidgetRef::render_ref(&group, area, buf);
+                } else {
+                    WidgetRef::render_ref(group.as_ref(), area, buf);
+                }
+            } else if let Some(widget) = &section.widget {
+                let area = Rect {
+                    x,
+                    width: available,
+                    ..rect
+                };
+                widget.widget.render_ref(area, buf);
+            } else if rect.height > 1 {
+                let lines = section
+                    .displayed_lines(short)
+                    .map(|line| {
+                        let alignment = self.alignment(line.alignment);
+                        line.clone().alignment(alignment)
+                    })
+                    .collect::<Vec<_>>();
+                Paragraph::new(lines).wrap(Wrap { trim: true }).render(
+                    Rect {
+                        x,
+                        width: available,
+                        ..rect
+                    },
+                    buf,
+                );
+            } else {
+                let displayed = section.displayed_page(short, state.page(index));
+                let transitional = state.content_transition(index).map(
+                    |(transition, previous, shown, progress)| {
+                        let base = self
+                            .style
+                            .patch(state.section_style(index, self.section_style(section)));
+                        transition.frame(previous, shown, progress, base)
+                    },
+                );
+                let content = section.truncate(
+                    transitional.as_ref().unwrap_or(displayed),
+                    usize::from(available),
+                );
+                let free = available
+                    .saturating_sub(u16::try_from(width::line_width(&content)).unwrap_or(u16::MAX));
+                let offset = match self.alignment(content.alignment) {
+                    Alignment::Left => 0,
+                    Alignment::Center => free / 2,
+                    Alignment::Right => free,
+                };
+                let content = if ascii {
+                    charset::line_to_ascii(&content)
+                } else {
+                    content
+                };
+                width::set_line(buf, x + offset, y, &content, available - offset);
+            }
+            x += available;
+            if let Some(separator) = &right {
+                width::set_span(buf, x, y, separator, rect.right().saturating_sub(x));
+            }
+        }
+        if let Some((indicator, _, hidden)) = self.overflow(area) {
+            let text = labels::format(labels::OVERFLOW, hidden.len());
+            buf.set_stringn(
+                indicator.x,
+                indicator.y,
+                text,
+                usize::from(indicator.width),
+                self.style,
+            );
+        }
+        if self.inactive {
+            let style = self.inactive_style.unwrap_or(Self::DEFAULT_INACTIVE_STYLE);
+            buf.set_style(area, style);
+        }
+    }
+
+    /// Returns where content with `alignment` is placed, mirrored in right-to-left mode.
+    fn alignment(&self, alignment: Option<Alignment>) -> Alignment {
+        match (alignment, self.right_to_left) {
+            (Some(Alignment::Center), _) => Alignment::Center,
+            (Some(Alignment::Right), false) | (Some(Alignment::Left) | None, true) => {
+                Alignment::Right
+            }
+            (Some(Alignment::Left) | None, false) | (Some(Alignment::Right), true) => {
+                Alignment::Left
+            }
+        }
+    }
+
+    /// Returns the style of `section`: the style of its role patched with its own style.
+    fn section_style(&self, section: &StatusBarSection<'a>) -> Style {
+        let role = section
+            .role
+            .zip(self.theme)
+            .map(|(role, theme)| theme.style(role))
+            .unwrap_or_default();
+        role.patch(section.style)
+    }
+
+    /// Returns the style a separator of the section at `index` is rendered on, where `neighbor`
+    /// is the visible section on the side of the separator.
+    fn separator_base_style(
+        &self,
+        index: usize,
+        post: bool,
+        neighbor: Option<usize>,
+        state: &StatusBarState,
+    ) -> Style {
+        let section_style = |index: Option<usize>| {
+            let style = index.and_then(|index| {
+                Some(state.section_style(index, self.section_style(self.sections.get(index)?)))
+            });
+            self.style.patch(style.unwrap_or_default())
+        };
+        match self.separator_style {
+            SeparatorStyle::Section => section_style(Some(index)),
+            SeparatorStyle::Preceding if post => section_style(Some(index)),
+            SeparatorStyle::Following if !post => section_style(Some(index)),
+            SeparatorStyle::Preceding | SeparatorStyle::Following => {
+                Style::reset().patch(section_style(neighbor))
+            }
+            SeparatorStyle::Bar => Style::reset().patch(self.style),
+            SeparatorStyle::Explicit => Style::reset(),
+        }
+    }
+}
+
+/// Splits the bottom `height` rows off `area`.
+pub(crate) fn split_bottom(area: Rect, height: u16) -> (Rect, Rect) {
+    let height = height.min(area.height);
+    let content = Rect {
+        height: area.height - height,
+        ..area
+    };
+    let bar = Rect {
+        y: content.bottom(),
+        height,
+        ..area
+    };
+    (content, bar)
+}
+
+impl<'a> Styled for StatusBar<'a> {
+    type Item = StatusBar<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
 }
 
 impl Widget for StatusBar<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_ref(area, buf);
+        WidgetRef::render_ref(&self, area, buf);
     }
 }
 
+/// Renders the [`StatusBar`] by reference, so a bar kept in the application state can be
+/// rendered every frame without rebuilding it.
+///
+/// # Examples
+/// ```
+/// # use ratatui::{backend::TestBackend, prelude::*};
+/// # use ratatui_statusbar::StatusBar;
+/// # let mut terminal = Terminal::new(TestBackend::new(20, 2))?;
+/// let status_bar = StatusBar::new(2).section(0, "NORMAL")?.section(1, "main")?;
+/// for _ in 0..3 {
+///     terminal.draw(|frame| {
+///         let (_, bar) = status_bar.split(frame.size());
+///         frame.render_widget(&status_bar, bar);
+///     })?;
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Through this implementation, `&StatusBar` implements [`Widget`] as well.
 impl WidgetRef for StatusBar<'_> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        StatefulWidgetRef::render_ref(self, area, buf, &mut StatusBarState::default());
+    }
+}
+
+impl StatefulWidget for StatusBar<'_> {
+    type State = StatusBarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &StatusBar<'_> {
+    type State = StatusBarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for StatusBar<'_> {
+    type State = StatusBarState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         if area.is_empty() {
             return;
         }
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("statusbar_render", width = area.width).entered();
+        #[cfg(feature = "debug-trace")]
+        self.trace_layout(area);
+        // `Instant` panics on the web, so time is only measured for a hook
+        let start = self.on_render.is_some().then(Instant::now);
+        self.render_stateful(area, buf, state);
+        if let (Some(RenderHook(hook)), Some(start)) = (&self.on_render, start) {
+            hook(start.elapsed());
+        }
+    }
+}
 
-        let layout = Layout::horizontal(
-            self.sections
-                .iter()
-                .map(|s| Constraint::Length(u16::try_from(s.content.width()).unwrap())),
-        )
-        .flex(self.flex)
-        .spacing(self.spacing);
-
-        let areas = layout.split(area);
-        let areas = areas.iter().collect_vec();
+impl StatusBar<'_> {
+    /// Starts the [transitions](StatusBarSection::transition) of sections whose content changed
+    /// since the last render.
+    fn track_transitions(&self, area: Rect, state: &mut StatusBarState) {
+        let short = self
+            .active_breakpoint(area.width)
+            .is_some_and(|breakpoint| breakpoint.short);
+        for (index, section) in self.sections.iter().enumerate() {
+            if let Some((transition, duration)) = section.transition {
+                let content = section.displayed_page(short, state.page(index));
+                state.track_content(index, content, transition, duration);
+            }
+        }
+    }
 
-        for (section, rect) in self.sections.iter().zip(areas) {
-            buf.set_line(
-                rect.left(),
-                rect.top(),
-                &section.content,
-                u16::try_from(section.content.width()).unwrap(),
-            );
+    fn render_stateful(&self, area: Rect, buf: &mut Buffer, state: &mut StatusBarState) {
+        let toggled = self.toggled(state);
+        let toggled = toggled.as_ref().unwrap_or(self);
+        let resolved = toggled.resolved(area);
+        let bar = resolved.as_ref().unwrap_or(toggled);
+        bar.track_transitions(area, state);
+        if let Some((scrolled, full)) = bar.scrolled(area) {
+            StatusBar {
+                ascii: self.ascii,
+                ..scrolled
+            }
+            .draw_scrolled(area, full, buf, state);
+        } else {
+            bar.draw(area, buf, state, self.ascii);
+            if let Some((_, x)) = state
+                .drag()
+                .and_then(|(_, column)| self.drop_target(area, state, column))
+            {
+                let marker = if self.ascii { "|" } else { "▏" };
+                buf.set_string(x, area.top(), marker, self.style);
+            }
         }
+        color::convert_area(buf, area, self.color_depth);
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use ratatui::backend::TestBackend;
 
     use super::*;
@@ -223,4 +2885,912 @@ mod tests {
         terminal.backend().assert_buffer(&expected);
         Ok(())
     }
+
+    #[test]
+    fn named_sections() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(2)
+            .section(1, StatusBarSection::from("a").name("mode"))?
+            .named_section("mode", "b")?;
+        assert_eq!(status_bar.sections[1].content, Line::from("b"));
+        assert_eq!(status_bar.sections[1].name.as_deref(), Some("mode"));
+        assert!(matches!(
+            status_bar.named_section("git", "c"),
+            Err(StatusBarError::UnknownSection(name)) if name == "git"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn size_hints() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .spacing(2u16)
+            .section(0, StatusBarSection::from("abc").pre_separator("<"))?
+            .section(2, StatusBarSection::from("de").post_separator(">"))?;
+        assert_eq!(status_bar.desired_width(), 11);
+        assert_eq!(status_bar.min_width(), 8);
+        assert_eq!(status_bar.height(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn visibility() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(4)
+            .separator_style(SeparatorStyle::Following)
+            .section(0, StatusBarSection::from("a").post_separator("|"))?
+            .section(1, StatusBarSection::from("b").hidden(true).on_red())?
+            .section(
+                2,
+                StatusBarSection::from("c").visible_when(|c| c.area.width > 6),
+            )?
+            .section(3, StatusBarSection::from("d").on_green())?;
+        let render = |width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+            buf
+        };
+        let mut expected = Buffer::with_lines(vec!["a| c d "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::reset());
+        expected.set_style(Rect::new(5, 0, 1, 1), Style::new().on_green());
+        assert_eq!(render(7), expected);
+
+        let mut expected = Buffer::with_lines(vec!["a| d  "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::reset().on_green());
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().on_green());
+        assert_eq!(render(6), expected);
+        assert_eq!(status_bar.areas(Rect::new(0, 0, 6, 1))[1].width, 0);
+        assert_eq!(status_bar.desired_width(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn breakpoints() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(0, StatusBarSection::from("NORMAL").short_content("N"))?
+            .section(1, StatusBarSection::from("main").name("git"))?
+            .section(2, "12:00")?
+            .breakpoint(20, Breakpoint::new().short(true))
+            .breakpoint(10, Breakpoint::new().short(true).spacing(0).hide("git"));
+        let render = |width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+            buf
+        };
+        assert_eq!(
+            render(21),
+            Buffer::with_lines(vec!["NORMAL main 12:00    "])
+        );
+        assert_eq!(render(20), Buffer::with_lines(vec!["N main 12:00        "]));
+        assert_eq!(render(10), Buffer::with_lines(vec!["N12:00    "]));
+        Ok(())
+    }
+
+    #[test]
+    fn render_truncated() -> Result<(), StatusBarError> {
+        let render = |truncation| -> Result<Buffer, StatusBarError> {
+            let status_bar = StatusBar::new(1).section(
+                0,
+                StatusBarSection::from("abcdef")
+                    .pre_separator("<")
+                    .post_separator(">")
+                    .truncation(truncation),
+            )?;
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+            Ok(buf)
+        };
+        assert_eq!(
+            render(Truncation::Clip)?,
+            Buffer::with_lines(vec!["<abcd>"])
+        );
+        assert_eq!(render(Truncation::End)?, Buffer::with_lines(vec!["<abc…>"]));
+        assert_eq!(
+            render(Truncation::Start)?,
+            Buffer::with_lines(vec!["<…def>"])
+        );
+        assert_eq!(
+            render(Truncation::Middle)?,
+            Buffer::with_lines(vec!["<ab…f>"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn width_clamps() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(
+                0,
+                StatusBarSection::from(Line::from("7").right_aligned())
+                    .post_separator("|")
+                    .min_width(4),
+            )?
+            .section(
+                1,
+                StatusBarSection::from("a long message")
+                    .truncation(Truncation::End)
+                    .max_width(6),
+            )?
+            .section(2, "end")?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["  7| a lon… end "]));
+        assert_eq!(status_bar.desired_width(), 15);
+        assert_eq!(status_bar.min_width(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn gaps() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(4)
+            .section(0, "a")?
+            .section(1, StatusBarSection::from("b").gap_after(3))?
+            .section(2, "c")?
+            .section(3, "d")?
+            .gaps([0]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["ab   c d "]));
+        assert_eq!(status_bar.desired_width(), 8);
+
+        let status_bar = status_bar.flex(Flex::SpaceBetween);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["a b   c  d"]));
+        Ok(())
+    }
+
+    #[test]
+    fn right_to_left() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(0, StatusBarSection::from("a").pre_separator("<"))?
+            .section(1, "bc")?
+            .section(
+                2,
+                StatusBarSection::from(Line::from("d").alignment(Alignment::Right)).min_width(3),
+            )?
+            .right_to_left(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec![" d   bc a<"]));
+        assert_eq!(
+            status_bar.areas(buf.area),
+            vec![
+                Rect::new(8, 0, 2, 1),
+                Rect::new(5, 0, 2, 1),
+                Rect::new(1, 0, 3, 1)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn multi_line() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(2)
+            .section(
+                0,
+                StatusBarSection::default()
+                    .text("error:\nfile not found")
+                    .max_width(8),
+            )?
+            .section(1, StatusBarSection::from("x").pre_separator("|"))?;
+        assert_eq!(status_bar.height(), 2);
+        assert_eq!(status_bar.desired_width(), 11);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["error:   |x ", "file not    "])
+        );
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["error:   |x "]));
+        Ok(())
+    }
+
+    #[test]
+    fn conversions() -> Result<(), StatusBarError> {
+        let name = String::from("main");
+        let status_bar = StatusBar::new(4)
+            .section(0, format!("{name}*"))?
+            .section(1, Cow::Borrowed("utf-8"))?
+            .section(2, ("NORMAL", Style::new().bold()))?
+            .section(3, Text::from(vec![Line::from("a"), Line::from("b")]))?;
+        assert_eq!(status_bar.sections[0].content, Line::from("main*"));
+        assert_eq!(status_bar.sections[1].content, Line::from("utf-8"));
+        assert_eq!(status_bar.sections[2].style, Style::new().bold());
+        assert_eq!(status_bar.sections[3].continuation, [Line::from("b")]);
+
+        let section = StatusBarSection::default().content(name);
+        assert_eq!(section.content, Line::from("main"));
+        let section = StatusBarSection::default()
+            .text("a\nb")
+            .content(vec![Span::raw("x"), Span::raw("y").bold()]);
+        assert_eq!(section.content.to_string(), "xy");
+        assert!(section.continuation.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn compact() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(2)
+            .section(0, StatusBarSection::from(" a ").post_separator("|"))?
+            .section(1, " b ")?
+            .spacing(2u16)
+            .compact(true);
+        let render = |width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+            buf
+        };
+        assert_eq!(render(9), Buffer::with_lines(vec![" a |   b "]));
+        assert_eq!(render(7), Buffer::with_lines(vec![" a | b "]));
+        assert_eq!(render(6), Buffer::with_lines(vec![" a  b "]));
+        assert_eq!(render(3), Buffer::with_lines(vec!["ab "]));
+        assert_eq!(render(1), Buffer::with_lines(vec!["a"]));
+        assert_eq!(
+            status_bar.areas(Rect::new(0, 0, 3, 1)),
+            [Rect::new(0, 0, 1, 1), Rect::new(1, 0, 1, 1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn optional_sections() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(4)
+            .section(0, StatusBarSection::from("a").name("mode"))?
+            .section(1, StatusBarSection::from("clock").optional(true))?
+            .section(2, StatusBarSection::from("battery").optional(true))?
+            .section(3, "z")?;
+        let mut state = StatusBarState::default();
+        let render = |state: &mut StatusBarState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            StatefulWidgetRef::render_ref(&status_bar, buf.area, &mut buf, state);
+            buf
+        };
+        assert_eq!(render(&mut state), Buffer::with_lines(vec!["a z        "]));
+        assert_eq!(status_bar.desired_width(), 3);
+        assert_eq!(status_bar.cycle_optional(&mut state), Some(1));
+        assert_eq!(render(&mut state), Buffer::with_lines(vec!["a clock z  "]));
+        assert_eq!(status_bar.cycle_optional(&mut state), Some(2));
+        assert_eq!(render(&mut state), Buffer::with_lines(vec!["a battery z"]));
+        assert!(!status_bar.toggle_section("mode", &mut state)?);
+        assert_eq!(render(&mut state), Buffer::with_lines(vec!["battery z  "]));
+        assert_eq!(status_bar.cycle_optional(&mut state), None);
+        assert_eq!(render(&mut state), Buffer::with_lines(vec!["z          "]));
+        assert!(status_bar.toggle_section("mode", &mut state)?);
+        assert!(matches!(
+            status_bar.toggle_section("clock", &mut state),
+            Err(StatusBarError::UnknownSection(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn overflow_indicator() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(4)
+            .section(0, "aaa")?
+            .section(1, "bbb")?
+            .section(2, "ccc")?
+            .section(3, "d")?
+            .fill_from_end(true)
+            .overflow_indicator(true);
+        let render = |status_bar: &StatusBar, width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            WidgetRef::render_ref(status_bar, buf.area, &mut buf);
+            buf
+        };
+        assert_eq!(
+            render(&status_bar, 14),
+            Buffer::with_lines(vec!["aaa bbb ccc d "])
+        );
+        assert!(status_bar.overflowed(Rect::new(0, 0, 14, 1)).is_empty());
+        assert_eq!(render(&status_bar, 8), Buffer::with_lines(vec!["+2 ccc d"]));
+        let area = Rect::new(0, 0, 8, 1);
+        assert_eq!(status_bar.overflowed(area), [0, 1]);
+        assert!(status_bar.overflow_hit(area, (1, 0)));
+        assert!(!status_bar.overflow_hit(area, (3, 0)));
+        assert_eq!(status_bar.hit_test(area, (1, 0)), None);
+        let tooltip = status_bar.overflow_tooltip(Rect::new(0, 4, 8, 1)).unwrap();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        tooltip.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐   ",
+                "│aaa│   ",
+                "│bbb│   ",
+                "└───┘   ",
+                "        "
+            ])
+        );
+
+        let status_bar = status_bar.right_to_left(true);
+        assert_eq!(render(&status_bar, 8), Buffer::with_lines(vec!["d ccc +2"]));
+        let status_bar = status_bar.right_to_left(false).fill_from_end(false);
+        assert_eq!(render(&status_bar, 3), Buffer::with_lines(vec![" +4"]));
+        assert_eq!(render(&status_bar, 8), Buffer::with_lines(vec!["aabbbccd"]));
+        assert!(status_bar
+            .overflow_tooltip(Rect::new(0, 0, 14, 1))
+            .is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn fill_from_end() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(0, "aaa")?
+            .section(
+                1,
+                StatusBarSection::from("bbb").truncation(Truncation::Start),
+            )?
+            .section(2, "c")?
+            .fill_from_end(true);
+        let render = |status_bar: &StatusBar, width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            WidgetRef::render_ref(status_bar, buf.area, &mut buf);
+            buf
+        };
+        assert_eq!(
+            render(&status_bar, 10),
+            Buffer::with_lines(vec!["aaa bbb c "])
+        );
+        assert_eq!(render(&status_bar, 7), Buffer::with_lines(vec!["a bbb c"]));
+        assert_eq!(render(&status_bar, 4), Buffer::with_lines(vec!["…b c"]));
+        assert_eq!(
+            status_bar.areas(Rect::new(0, 0, 4, 1)),
+            [
+                Rect::new(0, 0, 0, 1),
+                Rect::new(0, 0, 2, 1),
+                Rect::new(3, 0, 1, 1)
+            ]
+        );
+
+        let status_bar = status_bar.right_to_left(true);
+        assert_eq!(render(&status_bar, 4), Buffer::with_lines(vec!["c …b"]));
+        Ok(())
+    }
+
+    #[test]
+    fn groups() -> Result<(), StatusBarError> {
+        let group = StatusBar::new(2)
+            .spacing(0u16)
+            .section(0, "b")?
+            .section(1, "c")?;
+        let status_bar = StatusBar::new(3)
+            .flex(Flex::SpaceBetween)
+            .section(0, "a")?
+            .section(
+                1,
+                StatusBarSection::from(group)
+                    .pre_separator("[")
+                    .post_separator("]"),
+            )?
+            .section(2, "d")?;
+        assert_eq!(status_bar.desired_width(), 8);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["a  [bc]  d"]));
+        Ok(())
+    }
+
+    #[test]
+    fn ascii() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(2)
+            .section(0, StatusBarSection::from("日本").post_separator("│"))?
+            .section(
+                1,
+                StatusBarSection::from("abcdef").truncation(Truncation::End),
+            )?
+            .ascii(true);
+        assert_eq!(status_bar.desired_width(), 10);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["??| abc."]));
+        assert_eq!(
+            status_bar.areas(buf.area),
+            [Rect::new(0, 0, 3, 1), Rect::new(4, 0, 4, 1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn scrolling() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(0, "one")?
+            .section(1, "two")?
+            .section(2, "three")?
+            .scrollable(true);
+        let render = |status_bar: &StatusBar, state: &mut StatusBarState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+            StatefulWidgetRef::render_ref(status_bar, buf.area, &mut buf, state);
+            buf
+        };
+        let mut state = StatusBarState::default();
+        assert_eq!(
+            render(&status_bar, &mut state),
+            Buffer::with_lines(vec!["one two "])
+        );
+        state.scroll_right(100);
+        assert_eq!(
+            render(&status_bar, &mut state),
+            Buffer::with_lines(vec!["wo three"])
+        );
+        assert_eq!(state.scroll_offset(), 5);
+        state.scroll_left(2);
+        let status_bar = status_bar.scroll_indicators(true);
+        assert_eq!(
+            render(&status_bar, &mut state),
+            Buffer::with_lines(vec!["‹two th›"])
+        );
+
+        let mut state = StatusBarState::default();
+        let status_bar = status_bar.right_to_left(true).ascii(true);
+        assert_eq!(
+            render(&status_bar, &mut state),
+            Buffer::with_lines(vec!["<two one"])
+        );
+        state.scroll_left(1);
+        assert_eq!(
+            render(&status_bar, &mut state),
+            Buffer::with_lines(vec!["< two o>"])
+        );
+        assert_eq!(state.scroll_offset(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn scrolled_hit_test() -> Result<(), StatusBarError> {
+        let mut status_bar = StatusBar::new(3)
+            .section(0, "a".repeat(10))?
+            .section(1, "b".repeat(10))?
+            .section(2, "c".repeat(10))?
+            .scrollable(true);
+        let area = Rect::new(0, 0, 10, 1);
+        let mut state = StatusBarState::default();
+        state.scroll_right(22);
+        assert_eq!(status_bar.hit_test_with(area, &state, (2, 0)), Some(2));
+        let mut buf = Buffer::empty(area);
+        StatefulWidgetRef::render_ref(&status_bar, area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(vec!["c".repeat(10)]));
+        assert_eq!(
+            status_bar.handle_mouse(
+                area,
+                events::MouseEvent::new(events::MouseEventKind::Moved, 2, 0),
+                &mut state
+            ),
+            Some(events::BarEvent::Hover(Some(2)))
+        );
+
+        state.scroll_left(7);
+        assert_eq!(
+            status_bar.areas_with(area, &state),
+            [
+                Rect::new(0, 0, 0, 1),
+                Rect::new(0, 0, 6, 1),
+                Rect::new(7, 0, 3, 1)
+            ]
+        );
+        // drag the partially visible b past the middle of c
+        state.start_drag(1, 1);
+        state.drag_to(9);
+        assert_eq!(status_bar.end_drag(area, &mut state), Some((1, 2)));
+        Ok(())
+    }
+
+    #[test]
+    fn drag_reorder() -> Result<(), StatusBarError> {
+        let mut status_bar = StatusBar::new(3)
+            .section(0, "aa")?
+            .section(1, "bb")?
+            .section(2, "cc")?;
+        let area = Rect::new(0, 0, 10, 1);
+        let mut state = StatusBarState::default();
+        state.start_drag(2, 7);
+        state.drag_to(0);
+        let mut buf = Buffer::empty(area);
+        StatefulWidgetRef::render_ref(&status_bar, area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["▏a bb cc  "]);
+        expected.set_style(Rect::new(6, 0, 2, 1), Modifier::REVERSED);
+        assert_eq!(buf, expected);
+
+        assert_eq!(status_bar.end_drag(area, &mut state), Some((2, 0)));
+        assert_eq!(status_bar.sections[0].content, Line::from("cc"));
+        assert_eq!(state.drag(), None);
+
+        state.start_drag(1, 4);
+        state.drag_to(5);
+        assert_eq!(status_bar.end_drag(area, &mut state), None);
+
+        let status_bar = status_bar.right_to_left(true);
+        let mut state = StatusBarState::default();
+        state.start_drag(0, 9);
+        state.drag_to(0);
+        assert_eq!(status_bar.clone().end_drag(area, &mut state), Some((0, 2)));
+        assert!(status_bar.move_section(0, 3).is_err());
+
+        let mut status_bar = StatusBar::new(2)
+            .section(0, StatusBarSection::from("aa").hidden(true))?
+            .section(1, StatusBarSection::from("bb").hidden(true))?;
+        let mut state = StatusBarState::default();
+        state.start_drag(0, 1);
+        assert_eq!(status_bar.end_drag(area, &mut state), None);
+        assert_eq!(state.drag(), None);
+
+        let mut status_bar = StatusBar::new(2).section(0, "aa")?.section(1, "bb")?;
+        state.start_drag(0, 5);
+        assert_eq!(status_bar.end_drag(Rect::new(0, 0, 0, 1), &mut state), None);
+        assert_eq!(state.drag(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn paging() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(2)
+            .section(0, StatusBarSection::default().pages(["a", "bbb", "cc"]))?
+            .section(1, "|")?;
+        assert_eq!(status_bar.desired_width(), 5);
+        let mut state = StatusBarState::default();
+        let render = |state: &mut StatusBarState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            StatefulWidgetRef::render_ref(&status_bar, buf.area, &mut buf, state);
+            buf
+        };
+        assert_eq!(render(&mut state), Buffer::with_lines(vec!["a   | "]));
+        state.next_page(0);
+        assert_eq!(render(&mut state), Buffer::with_lines(vec!["bbb | "]));
+
+        state.rotate_pages(0, Duration::from_secs(2));
+        assert!(!state.advance(Duration::from_secs(1)));
+        assert!(state.advance(Duration::from_secs(1)));
+        assert_eq!(state.page(0), 2);
+        assert!(state.advance(Duration::from_secs(2)));
+        assert_eq!(render(&mut state), Buffer::with_lines(vec!["a   | "]));
+        Ok(())
+    }
+
+    #[test]
+    fn pinned() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(0, StatusBarSection::from("NORMAL").pinned(true))?
+            .section(
+                1,
+                StatusBarSection::from("file").truncation(Truncation::End),
+            )?
+            .section(2, StatusBarSection::from("git").name("git"))?
+            .breakpoint(20, Breakpoint::new().hide("git"));
+        let render = |status_bar: &StatusBar, width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            WidgetRef::render_ref(status_bar, buf.area, &mut buf);
+            buf
+        };
+        assert_eq!(
+            render(&status_bar, 9),
+            Buffer::with_lines(vec!["NORMAL f…"])
+        );
+        assert_eq!(render(&status_bar, 7), Buffer::with_lines(vec!["NORMAL "]));
+        assert_eq!(render(&status_bar, 4), Buffer::with_lines(vec!["NORM"]));
+
+        let status_bar = status_bar
+            .breakpoint(20, Breakpoint::new().hide("mode"))
+            .fill_from_end(true)
+            .named_section("git", StatusBarSection::from("git").pinned(true))?;
+        assert_eq!(
+            render(&status_bar, 12),
+            Buffer::with_lines(vec!["NORMAL … git"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn separator_merge() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(4)
+            .section(0, StatusBarSection::from("a").post_separator("|"))?
+            .section(1, StatusBarSection::from("b").hidden(true))?
+            .section(
+                2,
+                StatusBarSection::from("c")
+                    .pre_separator("<")
+                    .post_separator(">"),
+            )?
+            .section(3, StatusBarSection::from("d").pre_separator("["))?;
+        let render = |status_bar: &StatusBar| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            WidgetRef::render_ref(status_bar, buf.area, &mut buf);
+            buf.content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        assert_eq!(render(&status_bar), "a| <c> [d ");
+        let status_bar = status_bar.separator_merge(SeparatorMerge::Preceding);
+        assert_eq!(render(&status_bar), "a| c> d   ");
+        let status_bar = status_bar.separator_merge(SeparatorMerge::Following);
+        assert_eq!(render(&status_bar), "a <c [d   ");
+        Ok(())
+    }
+
+    #[test]
+    fn style_cascade() -> Result<(), StatusBarError> {
+        let theme = Theme::dark();
+        let group = StatusBar::new(1).section(
+            0,
+            StatusBarSection::from(Span::styled("b", Style::new().red())).role(Role::Error),
+        )?;
+        let status_bar = StatusBar::new(2)
+            .theme(theme)
+            .section(
+                0,
+                StatusBarSection::from(Span::styled("a", Style::new().red()))
+                    .role(Role::Accent)
+                    .italic(),
+            )?
+            .section(1, StatusBarSection::from(group).underlined())?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        let style = |x| {
+            let cell = buf.get(x, 0);
+            Style::new()
+                .fg(cell.fg)
+                .bg(cell.bg)
+                .add_modifier(cell.modifier)
+        };
+        let expected = theme.bar.patch(theme.accent).italic().red();
+        assert_eq!(style(0), expected);
+        let expected = theme.bar.underlined().patch(theme.error).red();
+        assert_eq!(style(2), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn gap_weights() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(4)
+            .spacing(0u16)
+            .section(0, "a")?
+            .section(1, StatusBarSection::from("b").gap_weight(1))?
+            .section(2, "c")?
+            .section(3, StatusBarSection::from("d").gap_weight(5))?
+            .right_to_left(true);
+        let render = |status_bar: &StatusBar, width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            WidgetRef::render_ref(status_bar, buf.area, &mut buf);
+            buf
+        };
+        // the weight of the last section has no gap to apply to
+        assert_eq!(render(&status_bar, 7), Buffer::with_lines(vec!["dc   ba"]));
+        assert_eq!(render(&status_bar, 4), Buffer::with_lines(vec!["dcba"]));
+        let status_bar = status_bar.right_to_left(false).gap_weights([1, 0, 2]);
+        assert_eq!(render(&status_bar, 7), Buffer::with_lines(vec!["a bc  d"]));
+        Ok(())
+    }
+
+    #[test]
+    fn section_style_covers_separators() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(1).section(
+            0,
+            StatusBarSection::from(Span::styled("a", Style::new().red()))
+                .pre_separator("<")
+                .post_separator(Span::styled(">", Style::new().bold()))
+                .min_width(5)
+                .style(Style::new().on_blue()),
+        )?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["<a  > "]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 0, 1, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn equality() -> Result<(), StatusBarError> {
+        let section = StatusBarSection::from("a")
+            .visible_when(|context| context.area.width > 10)
+            .data(1u8);
+        let status_bar = StatusBar::new(1).section(0, section.clone())?;
+        assert_eq!(status_bar.clone(), status_bar);
+        assert_eq!(section.clone().content("b"), section.clone().content("b"));
+        assert_ne!(section.clone().data(1u8), section);
+        assert_ne!(status_bar.clone().compact(true), status_bar);
+        Ok(())
+    }
+
+    #[test]
+    fn render_by_reference() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(1).section(0, "a")?;
+        let mut state = StatusBarState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Widget::render(&status_bar, buf.area, &mut buf);
+        StatefulWidget::render(&status_bar, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(vec!["a "]));
+        Ok(())
+    }
+
+    #[test]
+    fn widgets() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(2)
+            .section(
+                0,
+                StatusBarSection::default()
+                    .widget(Paragraph::new("abcdef"), 3)
+                    .post_separator("|"),
+            )?
+            .section(1, "x")?;
+        assert_eq!(status_bar.desired_width(), 6);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        WidgetRef::render_ref(&status_bar, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["abc| x"]));
+        Ok(())
+    }
+
+    #[test]
+    fn describe() -> Result<(), StatusBarError> {
+        let group = StatusBar::new(2).section(0, "+1")?.section(1, " -2 ")?;
+        let status_bar = StatusBar::new(4)
+            .section(0, StatusBarSection::from(group).name("diff"))?
+            .section(1, StatusBarSection::default().text("two\nlines"))?
+            .section(2, StatusBarSection::from("hidden").hidden(true))?
+            .section(3, "  ")?;
+        assert_eq!(
+            status_bar.describe(),
+            [
+                ("diff".to_string(), "+1 -2".to_string()),
+                ("1".to_string(), "two lines".to_string())
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn data() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(0, StatusBarSection::from("a").data(1u8))?
+            .section(
+                1,
+                StatusBarSection::from("b").pre_separator("|").data("two"),
+            )?
+            .section(2, "c")?;
+        let area = Rect::new(0, 0, 6, 1);
+        assert_eq!(status_bar.hit_test(area, (2, 0)), Some(1));
+        assert_eq!(status_bar.hit_test(area, (1, 0)), None);
+        assert_eq!(status_bar.data_at::<u8>(area, (0, 0)), Some(&1));
+        assert_eq!(status_bar.data_at::<u8>(area, (3, 0)), None);
+        assert_eq!(status_bar.data_at::<&str>(area, (3, 0)), Some(&"two"));
+        assert_eq!(status_bar.data_at::<u8>(area, (5, 0)), None);
+        Ok(())
+    }
+
+    #[test]
+    fn split() {
+        let status_bar = StatusBar::new(1);
+        assert_eq!(
+            status_bar.split(Rect::new(2, 3, 10, 5)),
+            (Rect::new(2, 3, 10, 4), Rect::new(2, 7, 10, 1))
+        );
+        assert_eq!(
+            status_bar.split(Rect::new(0, 0, 10, 0)),
+            (Rect::new(0, 0, 10, 0), Rect::new(0, 0, 10, 0))
+        );
+    }
+
+    #[test]
+    fn render_styled() -> color_eyre::Result<()> {
+        let status_bar = StatusBar::new(2)
+            .on_blue()
+            .section(0, StatusBarSection::from("hello").red().bold())?
+            .section(1, Line::from("world").green())?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 15, 1));
+        Widget::render(status_bar, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["hello world    "]);
+        expected.set_style(expected.area, Style::new().on_blue());
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().red().bold());
+        expected.set_style(Rect::new(6, 0, 5, 1), Style::new().green());
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_inactive() -> color_eyre::Result<()> {
+        let status_bar = StatusBar::new(1)
+            .on_blue()
+            .section(0, Span::from("a").red())?;
+        let render = |status_bar: &StatusBar| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+            WidgetRef::render_ref(status_bar, buf.area, &mut buf);
+            buf
+        };
+        let active = render(&status_bar);
+        let status_bar = status_bar.inactive(true);
+        let mut expected = active.clone();
+        expected.set_style(expected.area, Style::new().dim());
+        assert_eq!(render(&status_bar), expected);
+
+        let status_bar = status_bar.inactive_style(Style::new().gray().on_black());
+        let mut expected = Buffer::with_lines(vec!["a "]);
+        expected.set_style(expected.area, Style::new().gray().on_black());
+        assert_eq!(render(&status_bar), expected);
+        assert_eq!(render(&status_bar.inactive(false)), active);
+        Ok(())
+    }
+
+    #[test]
+    fn render_separators() -> color_eyre::Result<()> {
+        let status_bar = |separator_style| -> Result<StatusBar, StatusBarError> {
+            StatusBar::new(2)
+                .spacing(0u16)
+                .on_black()
+                .separator_style(separator_style)
+                .section(
+                    0,
+                    StatusBarSection::from("a")
+                        .pre_separator("<")
+                        .post_separator(">")
+                        .on_red(),
+                )?
+                .section(
+                    1,
+                    StatusBarSection::from("b")
+                        .pre_separator("[")
+                        .post_separator(Span::from("]").bold())
+                        .on_green(),
+                )
+        };
+        let render = |status_bar: StatusBar| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+            Widget::render(status_bar, buf.area, &mut buf);
+            buf
+        };
+        let expected = |styles: [Style; 7]| {
+            let mut buf = Buffer::with_lines(vec!["<a>[b] "]);
+            for (x, style) in (0..).zip(styles) {
+                buf.get_mut(x, 0).set_style(style);
+            }
+            buf
+        };
+        let (bar, red, green) = (
+            Style::new().on_black(),
+            Style::new().on_red(),
+            Style::new().on_green(),
+        );
+        let reset = Style::reset();
+
+        assert_eq!(
+            render(status_bar(SeparatorStyle::Section)?),
+            expected([red, red, red, green, green, green.bold(), bar])
+        );
+        assert_eq!(
+            render(status_bar(SeparatorStyle::Preceding)?),
+            expected([
+                reset.on_black(),
+                red,
+                red,
+                reset.on_red(),
+                green,
+                green.bold(),
+                bar
+            ])
+        );
+        assert_eq!(
+            render(status_bar(SeparatorStyle::Following)?),
+            expected([
+                red,
+                red,
+                reset.on_green(),
+                green,
+                green,
+                reset.on_black().bold(),
+                bar
+            ])
+        );
+        assert_eq!(
+            render(status_bar(SeparatorStyle::Explicit)?),
+            expected([reset, red, reset, reset, green, reset.bold(), bar])
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,116 @@
+//! A [`log::Log`] implementation that shows the most recent record in a [`LogSegment`].
+//!
+//! # Examples
+//! ```
+//! use ratatui_statusbar::logger::StatusBarLogger;
+//! use ratatui_statusbar::segments::LogSegment;
+//!
+//! let log = LogSegment::with_history(100);
+//! StatusBarLogger::new(log.clone())
+//!     .level(log::LevelFilter::Info)
+//!     .init()
+//!     .unwrap();
+//!
+//! log::error!("connection lost");
+//! assert_eq!(log.latest().unwrap().message, "connection lost");
+//! assert_eq!(log.history().len(), 1);
+//! ```
+
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::segments::{LogLevel, LogMessage, LogSegment};
+
+/// A [`Log`] implementation forwarding records at or above a level to a [`LogSegment`].
+#[derive(Debug, Clone)]
+pub struct StatusBarLogger {
+    segment: LogSegment,
+    level: LevelFilter,
+}
+
+impl StatusBarLogger {
+    /// Creates a logger forwarding warnings and errors to `segment`.
+    #[must_use]
+    pub const fn new(segment: LogSegment) -> Self {
+        Self {
+            segment,
+            level: LevelFilter::Warn,
+        }
+    }
+
+    /// Sets the least severe level that is forwarded.
+    #[must_use]
+    pub const fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Installs the logger as the global logger and sets the maximum log level accordingly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a global logger was already installed.
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        let level = self.level;
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+
+impl Log for StatusBarLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        // more verbose levels compare greater in log
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        self.segment.push(LogMessage {
+            level: log_level(record.level()),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+const fn log_level(level: Level) -> LogLevel {
+    match level {
+        Level::Error => LogLevel::Error,
+        Level::Warn => LogLevel::Warn,
+        Level::Info => LogLevel::Info,
+        Level::Debug => LogLevel::Debug,
+        Level::Trace => LogLevel::Trace,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_by_level() {
+        let segment = LogSegment::with_history(10);
+        let logger = StatusBarLogger::new(segment.clone());
+        for (level, message) in [(Level::Info, "ignored"), (Level::Warn, "low battery")] {
+            logger.log(
+                &Record::builder()
+                    .level(level)
+                    .target("app")
+                    .args(format_args!("{message}"))
+                    .build(),
+            );
+        }
+        assert_eq!(
+            segment.history(),
+            [LogMessage {
+                level: LogLevel::Warn,
+                target: "app".to_string(),
+                message: "low battery".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! A small tmux-style markup for styled status bar content.
+//!
+//! Text is written as-is and styles are switched with `#[...]` directives containing a comma
+//! separated list of attributes:
+//!
+//! - `fg=<color>` / `bg=<color>` set the foreground / background color. Colors are anything
+//!   ratatui's [`Color`] parses (`red`, `light-blue`, `#ff8800`, `42`) plus tmux's `colour42`.
+//! - `bold`, `dim`, `italics`, `underscore`, `blink`, `rapid-blink`, `reverse`, `hidden` and
+//!   `strikethrough` add a modifier, and the same names prefixed with `no` remove it.
+//! - `default` (or `none`) resets the style.
+//!
+//! A literal `#` is written as `##`.
+//!
+//! [`parse`] is forgiving: it ignores unknown attributes and keeps malformed directives as text.
+//! [`parse_strict`] rejects malformed markup with the byte span of the error instead, e.g. to
+//! validate configuration files, and [`parse_lossy`] shows directives it doesn't understand
+//! literally, so mistakes in quickly written markup are visible.
+//!
+//! # Examples
+//! ```
+//! use ratatui::prelude::*;
+//! use ratatui_statusbar::markup;
+//!
+//! let line = markup::parse("#[fg=black,bg=green,bold] NORMAL #[default] main");
+//! assert_eq!(
+//!     line,
+//!     Line::from(vec![
+//!         Span::styled(" NORMAL ", Style::new().black().on_green().bold()),
+//!         Span::raw(" main"),
+//!     ])
+//! );
+//! assert_eq!(markup::parse(&markup::to_markup(&line)), line);
+//! ```
+
+use std::fmt::Write;
+
+use ratatui::prelude::*;
+
+use crate::StatusBarError;
+
+const MODIFIERS: [(&str, Modifier); 9] = [
+    ("bold", Modifier::BOLD),
+    ("dim", Modifier::DIM),
+    ("italics", Modifier::ITALIC),
+    ("underscore", Modifier::UNDERLINED),
+    ("blink", Modifier::SLOW_BLINK),
+    ("rapid-blink", Modifier::RAPID_BLINK),
+    ("reverse", Modifier::REVERSED),
+    ("hidden", Modifier::HIDDEN),
+    ("strikethrough", Modifier::CROSSED_OUT),
+];
+
+/// Parses markup into a [`Line`].
+///
+/// Unknown attributes are ignored and an unterminated `#[` is kept as literal text.
+#[must_use]
+pub fn parse(markup: &str) -> Line<'static> {
+    parse_with(markup, Mode::Forgiving).unwrap_or_default()
+}
+
+/// Parses markup into a [`Line`], rejecting malformed markup.
+///
+/// # Errors
+///
+/// Returns [`StatusBarError::TemplateParse`] with the byte span of the first unknown attribute,
+/// invalid color, unterminated `#[` or unescaped `#`.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::{markup, StatusBarError};
+///
+/// let error = markup::parse_strict("#[fg=red,blod] main").unwrap_err();
+/// assert!(matches!(
+///     error,
+///     StatusBarError::TemplateParse { position: 9, length: 4, .. }
+/// ));
+/// assert_eq!(error.to_string(), "Invalid template at byte 9: unknown attribute `blod`");
+/// ```
+pub fn parse_strict(markup: &str) -> Result<Line<'static>, StatusBarError> {
+    parse_with(markup, Mode::Strict)
+}
+
+/// Parses markup into a [`Line`], showing directives with unknown attributes or invalid colors
+/// literally instead of applying them.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::markup;
+///
+/// assert_eq!(markup::parse_lossy("#[fg=red,blod]main").to_string(), "#[fg=red,blod]main");
+/// ```
+#[must_use]
+pub fn parse_lossy(markup: &str) -> Line<'static> {
+    parse_with(markup, Mode::Lossy).unwrap_or_default()
+}
+
+/// How malformed markup is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Unknown attributes are ignored.
+    Forgiving,
+    /// Directives with unknown attributes are kept as text.
+    Lossy,
+    /// Malformed markup is an error.
+    Strict,
+}
+
+fn parse_with(markup: &str, mode: Mode) -> Result<Line<'static>, StatusBarError> {
+    let error = |position: usize, length: usize, message: String| StatusBarError::TemplateParse {
+        position,
+        length,
+        message,
+    };
+    let mut spans = Vec::new();
+    let mut text = String::new();
+    let mut style = Style::default();
+    let mut rest = markup;
+
+    while let Some(index) = rest.find('#') {
+        text.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let position = markup.len() - rest.len();
+        if let Some(after) = rest.strip_prefix("##") {
+            text.push('#');
+            rest = after;
+        } else if let Some(end) = rest.find(']').filter(|_| rest.starts_with("#[")) {
+            let mut directive = style;
+            let mut offset = position + 2;
+            let mut malformed = false;
+            for attribute in rest[2..end].split(',') {
+                match try_apply_attribute(directive, attribute) {
+                    Ok(applied) => directive = applied,
+                    Err(message) if mode == Mode::Strict => {
+                        let trimmed = attribute.trim_start();
+                        let start = offset + attribute.len() - trimmed.len();
+                        return Err(error(start, trimmed.trim_end().len(), message));
+                    }
+                    Err(_) => malformed = true,
+                }
+                offset += attribute.len() + 1;
+            }
+            if malformed && mode == Mode::Lossy {
+                text.push_str(&rest[..=end]);
+            } else {
+                if !text.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut text), style));
+                }
+                style = directive;
+            }
+            rest = &rest[end + 1..];
+        } else if mode == Mode::Strict {
+            return Err(if rest.starts_with("#[") {
+                error(position, rest.len(), "unterminated `#[`".to_string())
+            } else {
+                error(position, 1, "unescaped `#`, write `##`".to_string())
+            });
+        } else {
+            text.push('#');
+            rest = &rest[1..];
+        }
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        spans.push(Span::styled(text, style));
+    }
+    Ok(Line::from(spans))
+}
+
+/// Parses markup into a single [`Span`].
+///
+/// The text of all styled runs is concatenated and the span takes the style of the first run,
+/// which is what separators and other single-style content need.
+#[must_use]
+pub fn parse_span(markup: &str) -> Span<'static> {
+    let line = parse(markup);
+    let style = line
+        .spans
+        .first()
+        .map(|span| span.style)
+        .unwrap_or_default();
+    Span::styled(line.to_string(), style)
+}
+
+/// Parses markup into a single [`Span`] like [`parse_span`], rejecting malformed markup.
+///
+/// # Errors
+///
+/// Returns [`StatusBarError::TemplateParse`] like [`parse_strict`].
+pub fn parse_span_strict(markup: &str) -> Result<Span<'static>, StatusBarError> {
+    let line = parse_strict(markup)?;
+    let style = line
+        .spans
+        .first()
+        .map(|span| span.style)
+        .unwrap_or_default();
+    Ok(Span::styled(line.to_string(), style))
+}
+
+/// Parses a comma separated list of attributes (without the surrounding `#[` and `]`) into a
+/// [`Style`].
+#[must_use]
+pub fn parse_style(attributes: &str) -> Style {
+    attributes
+        .split(',')
+        .fold(Style::default(), apply_attribute)
+}
+
+/// Parses a comma separated list of attributes into a [`Style`] like [`parse_style`], rejecting
+/// unknown attributes and invalid colors.
+///
+/// # Errors
+///
+/// Returns [`StatusBarError::TemplateParse`] with the byte span of the first invalid attribute.
+///
+/// # Examples
+/// ```
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui_statusbar::{markup, StatusBarError};
+///
+/// assert_eq!(markup::parse_style_strict("fg=red,bold")?, Style::new().red().bold());
+/// assert!(matches!(
+///     markup::parse_style_strict("fg=red, blod"),
+///     Err(StatusBarError::TemplateParse { position: 8, length: 4, .. })
+/// ));
+/// # Ok::<(), StatusBarError>(())
+/// ```
+pub fn parse_style_strict(attributes: &str) -> Result<Style, StatusBarError> {
+    let mut style = Style::default();
+    let mut offset = 0;
+    for attribute in attributes.split(',') {
+        style = try_apply_attribute(style, attribute).map_err(|message| {
+            let trimmed = attribute.trim_start();
+            StatusBarError::TemplateParse {
+                position: offset + attribute.len() - trimmed.len(),
+                length: trimmed.trim_end().len(),
+                message,
+            }
+        })?;
+        offset += attribute.len() + 1;
+    }
+    Ok(style)
+}
+
+/// Writes a [`Line`] as markup that [`parse`] turns back into an equivalent line.
+///
+/// The line style and alignment are not preserved on their own; the line style is folded into
+/// the style of every span.
+#[must_use]
+pub fn to_markup(line: &Line) -> String {
+    let mut markup = String::new();
+    let mut current = Style::default();
+    for span in &line.spans {
+        let style = line.style.patch(span.style);
+        if style != current {
+            let _ = write!(markup, "#[{}]", style_to_markup(style));
+            current = style;
+        }
+        markup.push_str(&escape(&span.content));
+    }
+    markup
+}
+
+/// Writes a [`Span`] as markup.
+#[must_use]
+pub fn span_to_markup(span: &Span) -> String {
+    to_markup(&Line::from(span.clone()))
+}
+
+/// Writes a [`Style`] as a comma separated list of attributes that [`parse_style`] turns back
+/// into the same style.
+#[must_use]
+pub fn style_to_markup(style: Style) -> String {
+    let mut attributes = vec!["default".to_string()];
+    if let Some(fg) = style.fg {
+        attributes.push(format!("fg={fg}"));
+    }
+    if let Some(bg) = style.bg {
+        attributes.push(format!("bg={bg}"));
+    }
+    for (name, modifier) in MODIFIERS {
+        if style.add_modifier.contains(modifier) {
+            attributes.push(name.to_string());
+        }
+        if style.sub_modifier.contains(modifier) {
+            attributes.push(format!("no{name}"));
+        }
+    }
+    attributes.join(",")
+}
+
+/// Escapes `#` so that text is reproduced literally by [`parse`].
+#[must_use]
+pub fn escape(text: &str) -> String {
+    text.replace('#', "##")
+}
+
+fn apply_attribute(style: Style, attribute: &str) -> Style {
+    try_apply_attribute(style, attribute).unwrap_or(style)
+}
+
+/// Applies `attribute` to `style`, or describes why it is invalid. Empty attributes are ignored.
+fn try_apply_attribute(style: Style, attribute: &str) -> Result<Style, String> {
+    let attribute = attribute.trim().to_lowercase();
+    if attribute.is_empty() {
+        return Ok(style);
+    }
+    if let Some(color) = attribute.strip_prefix("fg=") {
+        return parse_color(color)
+            .map(|color| style.fg(color))
+            .ok_or_else(|| format!("invalid color `{color}`"));
+    }
+    if let Some(color) = attribute.strip_prefix("bg=") {
+        return parse_color(color)
+            .map(|color| style.bg(color))
+            .ok_or_else(|| format!("invalid color `{color}`"));
+    }
+    if matches!(attribute.as_str(), "default" | "none") {
+        return Ok(Style::default());
+    }
+    if let Some(modifier) = parse_modifier(&attribute) {
+        return Ok(style.add_modifier(modifier));
+    }
+    if let Some(modifier) = attribute.strip_prefix("no").and_then(parse_modifier) {
+        return Ok(style.remove_modifier(modifier));
+    }
+    Err(format!("unknown attribute `{attribute}`"))
+}
+
+fn parse_color(color: &str) -> Option<Color> {
+    if color == "default" {
+        return Some(Color::Reset);
+    }
+    let color = color
+        .strip_prefix("colour")
+        .or_else(|| color.strip_prefix("color"))
+        .filter(|index| index.parse::<u8>().is_ok())
+        .unwrap_or(color);
+    color.parse().ok()
+}
+
+fn parse_modifier(name: &str) -> Option<Modifier> {
+    let name = match name {
+        "bright" => "bold",
+        "italic" => "italics",
+        "underline" | "underlined" => "underscore",
+        "reversed" => "reverse",
+        "crossed-out" => "strikethrough",
+        name => name,
+    };
+    MODIFIERS
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, modifier)| *modifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_styles() {
+        let line = parse("a#[fg=red,bold]b#[nobold,bg=colour4]c##d#[default]e");
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("a"),
+                Span::styled("b", Style::new().red().bold()),
+                Span::styled("c#d", Style::new().red().bg(Color::Indexed(4)).not_bold()),
+                Span::raw("e"),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_lossy() {
+        assert_eq!(parse("#[unknown]x #[fg=red").to_string(), "x #[fg=red");
+        assert_eq!(parse("#x").to_string(), "#x");
+    }
+
+    #[test]
+    fn parse_modes() {
+        let markup = "a#[fg=red,  nope ]b#[fg=nocolor]c";
+        assert_eq!(parse(markup), parse("a#[fg=red]b#[]c"));
+        assert_eq!(
+            super::parse_lossy(markup),
+            Line::from("a#[fg=red,  nope ]b#[fg=nocolor]c")
+        );
+        let error = |markup| match parse_strict(markup) {
+            Err(StatusBarError::TemplateParse {
+                position, length, ..
+            }) => Some((position, length)),
+            _ => None,
+        };
+        assert_eq!(error(markup), Some((12, 4)));
+        assert_eq!(error("#[fg=nocolor]"), Some((2, 10)));
+        assert_eq!(error("ä #[bold"), Some((3, 6)));
+        assert_eq!(error("50#"), Some((2, 1)));
+        assert_eq!(parse_strict("#[bold,]x##").ok(), Some(parse("#[bold]x##")));
+    }
+
+    #[test]
+    fn round_trip() {
+        let line = Line::from(vec![
+            Span::styled("#1", Style::new().fg(Color::Rgb(1, 2, 3)).italic()),
+            Span::raw(" "),
+            Span::styled("x", Style::new().on_light_cyan().not_dim()),
+        ]);
+        assert_eq!(parse(&to_markup(&line)), line);
+    }
+}
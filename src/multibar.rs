@@ -0,0 +1,182 @@
+//! A stack of status bars.
+
+use ratatui::layout::Flex;
+use ratatui::prelude::*;
+use ratatui::widgets::WidgetRef;
+
+use crate::{split_bottom, StatusBar, StatusBarError};
+
+/// Several [`StatusBar`]s stacked on top of each other, e.g. a global status bar with a transient
+/// message bar above it.
+///
+/// Bars are rendered in order from top to bottom, aligned to the bottom of the area. Hidden bars
+/// take no space, so the remaining bars move down when one is hidden.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::{MultiBar, StatusBar};
+///
+/// let mut multi_bar = MultiBar::new()
+///     .bar(StatusBar::new(1).section(0, "error: file not found").unwrap())
+///     .bar(StatusBar::new(2).section(0, "NORMAL").unwrap());
+/// assert_eq!(multi_bar.height(), 2);
+///
+/// // Hide the message bar once the message is dismissed.
+/// multi_bar.set_visible(0, false).unwrap();
+/// assert_eq!(multi_bar.height(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct MultiBar<'a> {
+    bars: Vec<(StatusBar<'a>, bool)>,
+}
+
+impl<'a> MultiBar<'a> {
+    /// Creates an empty [`MultiBar`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a visible bar below the existing ones.
+    #[must_use]
+    pub fn bar(mut self, bar: StatusBar<'a>) -> Self {
+        self.bars.push((bar, true));
+        self
+    }
+
+    /// Returns the number of bars, including hidden ones.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bars.len()
+    }
+
+    /// Returns whether the [`MultiBar`] contains no bars.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bars.is_empty()
+    }
+
+    /// Returns the bar at `index`.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&StatusBar<'a>> {
+        self.bars.get(index).map(|(bar, _)| bar)
+    }
+
+    /// Returns the bar at `index` mutably, e.g. to replace it with updated content.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut StatusBar<'a>> {
+        self.bars.get_mut(index).map(|(bar, _)| bar)
+    }
+
+    /// Returns whether the bar at `index` is visible.
+    #[must_use]
+    pub fn is_visible(&self, index: usize) -> bool {
+        self.bars.get(index).is_some_and(|(_, visible)| *visible)
+    }
+
+    /// Shows or hides the bar at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::IndexOutOfBounds`] if there is no bar at `index`.
+    pub fn set_visible(&mut self, index: usize, visible: bool) -> Result<(), StatusBarError> {
+        let (_, shown) = self
+            .bars
+            .get_mut(index)
+            .ok_or(StatusBarError::IndexOutOfBounds(index))?;
+        *shown = visible;
+        Ok(())
+    }
+
+    /// Returns the number of rows the visible bars need.
+    #[must_use]
+    pub fn height(&self) -> u16 {
+        self.visible()
+            .map(|(_, bar)| bar.height())
+            .fold(0, u16::saturating_add)
+    }
+
+    /// Splits `area`, typically the whole frame, into the content area above the bars and the
+    /// area of the visible bars at the bottom.
+    #[must_use]
+    pub fn split(&self, area: Rect) -> (Rect, Rect) {
+        split_bottom(area, self.height())
+    }
+
+    /// Returns the index and area of each visible bar when the [`MultiBar`] is rendered into
+    /// `area`.
+    #[must_use]
+    pub fn areas(&self, area: Rect) -> Vec<(usize, Rect)> {
+        let rects = Layout::vertical(
+            self.visible()
+                .map(|(_, bar)| Constraint::Length(bar.height())),
+        )
+        .flex(Flex::End)
+        .split(area);
+        self.visible()
+            .map(|(index, _)| index)
+            .zip(rects.iter().copied())
+            .collect()
+    }
+
+    fn visible(&self) -> impl Iterator<Item = (usize, &StatusBar<'a>)> {
+        self.bars
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, visible))| *visible)
+            .map(|(index, (bar, _))| (index, bar))
+    }
+}
+
+impl Widget for MultiBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for MultiBar<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        for (index, rect) in self.areas(area) {
+            WidgetRef::render_ref(&self.bars[index].0, rect, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_and_hide() -> Result<(), StatusBarError> {
+        let mut multi_bar = MultiBar::new()
+            .bar(StatusBar::new(1).section(0, "top")?)
+            .bar(StatusBar::new(1).section(0, "bottom")?);
+        let render = |multi_bar: &MultiBar| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+            multi_bar.render_ref(buf.area, &mut buf);
+            buf
+        };
+        assert_eq!(
+            render(&multi_bar),
+            Buffer::with_lines(vec!["      ", "top   ", "bottom"])
+        );
+
+        let (content, bars) = multi_bar.split(Rect::new(0, 0, 6, 3));
+        assert_eq!(
+            (content, bars),
+            (Rect::new(0, 0, 6, 1), Rect::new(0, 1, 6, 2))
+        );
+
+        multi_bar.set_visible(1, false)?;
+        assert!(!multi_bar.is_visible(1));
+        assert_eq!(
+            render(&multi_bar),
+            Buffer::with_lines(vec!["      ", "      ", "top   "])
+        );
+        assert!(matches!(
+            multi_bar.set_visible(2, true),
+            Err(StatusBarError::IndexOutOfBounds(2))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,463 @@
+//! Operating System Command escape sequences for terminal features beyond the cell grid.
+//!
+//! These sequences can't be expressed through a ratatui [`Buffer`], so they are written
+//! directly to the terminal, typically right after [`Terminal::draw`]. Terminals that don't
+//! support a sequence ignore it.
+//!
+//! # Examples
+//! ```no_run
+//! use std::io::stdout;
+//!
+//! use ratatui::prelude::*;
+//! use ratatui::widgets::WidgetRef;
+//! use ratatui_statusbar::{osc, StatusBar, StatusBarSection};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+//! let status_bar = StatusBar::new(1)
+//!     .section(
+//!         0,
+//!         StatusBarSection::from("main").hyperlink("https://github.com/kdheepak/ratatui-statusbar"),
+//!     )
+//!     .unwrap();
+//! let area = Rect::new(0, 0, 80, 1);
+//! let frame = terminal.draw(|frame| status_bar.render_ref(area, frame.buffer_mut()))?;
+//! osc::write_hyperlinks(&mut stdout(), &status_bar, area, frame.buffer)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use ratatui::prelude::*;
+use unicode_width::UnicodeWidthStr;
+
+#[cfg(feature = "segments")]
+use crate::segments::ProgressState;
+use crate::{StatusBar, StatusBarState};
+
+/// Wraps `text` in an OSC 8 hyperlink to `url`.
+///
+/// Control characters are percent-encoded in the URL and left out of the text, so that neither
+/// can end the sequence early, e.g. for URLs taken from a git remote.
+#[must_use]
+pub fn hyperlink(url: &str, text: &str) -> String {
+    let text: String = text.chars().filter(|c| !c.is_control()).collect();
+    format!("\x1b]8;;{}\x1b\\{text}\x1b]8;;\x1b\\", escape_url(url))
+}
+
+/// Percent-encodes the control characters in `url`.
+fn escape_url(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+    for c in url.chars() {
+        if c.is_control() {
+            for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                let _ = write!(escaped, "%{byte:02X}");
+            }
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// Returns the OSC 52 sequence that copies `text` to the system clipboard.
+///
+/// Terminals copy from the remote end too, so this works over SSH. Some terminals ask for
+/// permission first or only allow it once enabled in their settings.
+#[must_use]
+pub fn copy_to_clipboard(text: &str) -> String {
+    format!("\x1b]52;c;{}\x1b\\", base64(text.as_bytes()))
+}
+
+/// Encodes `bytes` as padded standard base64.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | u32::from(*byte) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(char::from(
+                    ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize],
+                ));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// The title set by [`set_title`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TitleKind {
+    /// The window title and the icon or tab title (OSC 0).
+    #[default]
+    WindowAndIcon,
+    /// Only the window title (OSC 2).
+    Window,
+}
+
+/// Returns the OSC 0 or 2 sequence that sets the terminal title to `title`.
+///
+/// Control characters are left out, so the title can't end the sequence early.
+#[must_use]
+pub fn set_title(title: &str, kind: TitleKind) -> String {
+    let code = match kind {
+        TitleKind::WindowAndIcon => 0,
+        TitleKind::Window => 2,
+    };
+    let title: String = title.chars().filter(|c| !c.is_control()).collect();
+    format!("\x1b]{code};{title}\x1b\\")
+}
+
+/// How a terminal is asked to get the attention of the user, see [`notification`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notification {
+    /// The terminal bell, which most terminals turn into an urgency hint or a sound.
+    #[default]
+    Bell,
+    /// A desktop notification with OSC 9, supported by iTerm2, WezTerm, Windows Terminal and
+    /// others.
+    Osc9,
+    /// A desktop notification with a title with OSC 777, supported by VTE based terminals,
+    /// foot, WezTerm and others.
+    Osc777,
+}
+
+/// Returns the sequence that notifies the user of `body`, titled `title` where supported.
+///
+/// Control characters are left out, so the text can't end the sequence early.
+#[must_use]
+pub fn notification(kind: Notification, title: &str, body: &str) -> String {
+    let printable = |text: &str| -> String { text.chars().filter(|c| !c.is_control()).collect() };
+    match kind {
+        Notification::Bell => "\x07".to_string(),
+        Notification::Osc9 => format!("\x1b]9;{}\x1b\\", printable(body)),
+        Notification::Osc777 => format!(
+            "\x1b]777;notify;{};{}\x1b\\",
+            printable(title).replace(';', ","),
+            printable(body)
+        ),
+    }
+}
+
+/// Returns the OSC 9;4 sequence that reports the progress of an operation to the terminal, which
+/// ConEmu, Windows Terminal and WezTerm show in the tab or taskbar.
+#[cfg(feature = "segments")]
+#[must_use]
+pub fn taskbar_progress(state: ProgressState) -> String {
+    let (code, percent) = match state {
+        ProgressState::Hidden => (0, 0),
+        ProgressState::Normal(percent) => (1, percent),
+        ProgressState::Error(percent) => (2, percent),
+        ProgressState::Indeterminate => (3, 0),
+        ProgressState::Paused(percent) => (4, percent),
+    };
+    format!("\x1b]9;4;{code};{}\x1b\\", percent.min(100))
+}
+
+/// Mirrors the content of a named section to the terminal title.
+///
+/// Call [`update`](Self::update) after each draw: the title is only written when the plain text
+/// of the section changed since the last update, so it follows the section without flooding the
+/// terminal.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::osc::TitleMirror;
+/// use ratatui_statusbar::{StatusBar, StatusBarSection};
+///
+/// let mut title = TitleMirror::new("file").prefix("edit: ");
+/// let status_bar = StatusBar::new(1).section(0, StatusBarSection::from(" main.rs ").name("file"))?;
+///
+/// let mut terminal = Vec::new();
+/// assert!(title.update(&mut terminal, &status_bar)?);
+/// assert!(!title.update(&mut terminal, &status_bar)?);
+/// assert_eq!(terminal, b"\x1b]0;edit: main.rs\x1b\\");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TitleMirror {
+    section: String,
+    kind: TitleKind,
+    prefix: String,
+    last: Option<String>,
+}
+
+impl TitleMirror {
+    /// Creates a mirror of the section named `section`.
+    #[must_use]
+    pub fn new(section: impl Into<String>) -> Self {
+        Self {
+            section: section.into(),
+            kind: TitleKind::default(),
+            prefix: String::new(),
+            last: None,
+        }
+    }
+
+    /// Sets which title is set, both the window and the icon title by default.
+    #[must_use]
+    pub const fn kind(mut self, kind: TitleKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets text shown before the content of the section, e.g. the name of the application.
+    #[must_use]
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Writes the title if the content of the section changed and returns whether it did.
+    ///
+    /// The title is left alone while no section of `status_bar` has the name.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the underlying writer.
+    pub fn update<W: Write>(&mut self, writer: &mut W, status_bar: &StatusBar) -> io::Result<bool> {
+        let Some(section) = status_bar
+            .sections
+            .iter()
+            .find(|section| section.name.as_deref() == Some(self.section.as_str()))
+        else {
+            return Ok(false);
+        };
+        let title = format!("{}{}", self.prefix, section.plain_text());
+        if self.last.as_ref() == Some(&title) {
+            return Ok(false);
+        }
+        writer.write_all(set_title(&title, self.kind).as_bytes())?;
+        writer.flush()?;
+        self.last = Some(title);
+        Ok(true)
+    }
+}
+
+/// Writes the linked sections of a rendered [`StatusBar`] again, wrapped in OSC 8 hyperlinks.
+///
+/// `area` must be the area the status bar was rendered into and `buffer` the buffer it was
+/// rendered to, e.g. the one in the [`CompletedFrame`](ratatui::terminal::CompletedFrame)
+/// returned by [`Terminal::draw`]. The cursor position is saved and restored. Use
+/// [`write_hyperlinks_with`] for a status bar rendered with a [`StatusBarState`].
+///
+/// # Errors
+///
+/// Returns any error of the underlying writer.
+pub fn write_hyperlinks<W: Write>(
+    writer: &mut W,
+    status_bar: &StatusBar,
+    area: Rect,
+    buffer: &Buffer,
+) -> io::Result<()> {
+    write_hyperlinks_with(writer, status_bar, area, &StatusBarState::default(), buffer)
+}
+
+/// Like [`write_hyperlinks`], for a [`StatusBar`] rendered with `state`, e.g. a
+/// [scrolled](StatusBar::scrollable) one.
+///
+/// # Errors
+///
+/// Returns any error of the underlying writer.
+pub fn write_hyperlinks_with<W: Write>(
+    writer: &mut W,
+    status_bar: &StatusBar,
+    area: Rect,
+    state: &StatusBarState,
+    buffer: &Buffer,
+) -> io::Result<()> {
+    let links = status_bar
+        .sections
+        .iter()
+        .zip(status_bar.areas_with(area, state))
+        .filter_map(|(section, rect)| Some((section.hyperlink.as_deref()?, rect)))
+        .filter(|(_, rect)| !rect.is_empty());
+    for (url, rect) in links {
+        write!(writer, "\x1b7\x1b[{};{}H", rect.y + 1, rect.x + 1)?;
+        write!(writer, "\x1b]8;;{}\x1b\\", escape_url(url))?;
+        let mut skip = 0;
+        for x in rect.left()..rect.right() {
+            let cell = buffer.get(x, rect.y);
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            write!(writer, "\x1b[0{}m{}", sgr(cell.style()), cell.symbol())?;
+            skip = cell.symbol().width().saturating_sub(1);
+        }
+        write!(writer, "\x1b]8;;\x1b\\\x1b[0m\x1b8")?;
+    }
+    writer.flush()
+}
+
+/// Returns the SGR parameters selecting `style`, each prefixed with `;`.
+fn sgr(style: Style) -> String {
+    const MODIFIERS: [(Modifier, u8); 9] = [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ];
+    let mut params = String::new();
+    if let Some(fg) = style.fg {
+        params.push_str(&color_params(fg, 30));
+    }
+    if let Some(bg) = style.bg {
+        params.push_str(&color_params(bg, 40));
+    }
+    for (modifier, code) in MODIFIERS {
+        if style.add_modifier.contains(modifier) {
+            let _ = write!(params, ";{code}");
+        }
+    }
+    params
+}
+
+/// Returns the SGR parameters selecting a foreground (`base` 30) or background (`base` 40) color.
+fn color_params(color: Color, base: u8) -> String {
+    let named = |index: u8| {
+        if index < 8 {
+            format!(";{}", base + index)
+        } else {
+            format!(";{}", base + 60 + index - 8)
+        }
+    };
+    match color {
+        Color::Reset => format!(";{}", base + 9),
+        Color::Black => named(0),
+        Color::Red => named(1),
+        Color::Green => named(2),
+        Color::Yellow => named(3),
+        Color::Blue => named(4),
+        Color::Magenta => named(5),
+        Color::Cyan => named(6),
+        Color::Gray => named(7),
+        Color::DarkGray => named(8),
+        Color::LightRed => named(9),
+        Color::LightGreen => named(10),
+        Color::LightYellow => named(11),
+        Color::LightBlue => named(12),
+        Color::LightMagenta => named(13),
+        Color::LightCyan => named(14),
+        Color::White => named(15),
+        Color::Indexed(index) => format!(";{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => format!(";{};2;{r};{g};{b}", base + 8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::WidgetRef;
+
+    use super::*;
+    use crate::StatusBarSection;
+
+    #[test]
+    fn writes_linked_sections() -> color_eyre::Result<()> {
+        let status_bar = StatusBar::new(2).section(0, "plain")?.section(
+            1,
+            StatusBarSection::from(Span::styled("link", Style::new().red().bold()))
+                .hyperlink("https://example.com"),
+        )?;
+        let area = Rect::new(0, 1, 12, 1);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        status_bar.render_ref(area, &mut buffer);
+
+        let mut output = Vec::new();
+        write_hyperlinks(&mut output, &status_bar, area, &buffer)?;
+        let expected = [
+            "\x1b7\x1b[2;7H\x1b]8;;https://example.com\x1b\\",
+            "\x1b[0;31;49;1ml\x1b[0;31;49;1mi\x1b[0;31;49;1mn\x1b[0;31;49;1mk",
+            "\x1b]8;;\x1b\\\x1b[0m\x1b8",
+        ];
+        assert_eq!(String::from_utf8(output)?, expected.concat());
+        Ok(())
+    }
+
+    #[test]
+    fn escapes_urls() {
+        assert_eq!(
+            hyperlink("https://x.test/\x1b\\\x1b]2;pwned\x07", "a\x1bb"),
+            "\x1b]8;;https://x.test/%1B\\%1B]2;pwned%07\x1b\\ab\x1b]8;;\x1b\\"
+        );
+        assert_eq!(
+            hyperlink("https://x.test/ü\u{9b}", "x"),
+            "\x1b]8;;https://x.test/ü%C2%9B\x1b\\x\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn encodes_clipboard_text() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64("main ✓".as_bytes()), "bWFpbiDinJM=");
+        assert_eq!(copy_to_clipboard("main"), "\x1b]52;c;bWFpbg==\x1b\\");
+    }
+
+    #[test]
+    fn mirrors_title() -> color_eyre::Result<()> {
+        let mut mirror = TitleMirror::new("mode").kind(TitleKind::Window);
+        let mut output = Vec::new();
+        let status_bar = StatusBar::new(1).section(0, "NORMAL")?;
+        assert!(!mirror.update(&mut output, &status_bar)?);
+
+        for mode in ["NORMAL", "NORMAL", "INSERT\x07"] {
+            let status_bar =
+                StatusBar::new(1).section(0, StatusBarSection::from(mode).name("mode"))?;
+            mirror.update(&mut output, &status_bar)?;
+        }
+        assert_eq!(
+            String::from_utf8(output)?,
+            "\x1b]2;NORMAL\x1b\\\x1b]2;INSERT\x1b\\"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn notifications() {
+        assert_eq!(notification(Notification::Bell, "app", "failed"), "\x07");
+        assert_eq!(
+            notification(Notification::Osc9, "app", "failed\x1b"),
+            "\x1b]9;failed\x1b\\"
+        );
+        assert_eq!(
+            notification(Notification::Osc777, "app;db", "failed; retrying"),
+            "\x1b]777;notify;app,db;failed; retrying\x1b\\"
+        );
+    }
+
+    #[cfg(feature = "segments")]
+    #[test]
+    fn taskbar() {
+        assert_eq!(
+            taskbar_progress(ProgressState::Paused(120)),
+            "\x1b]9;4;4;100\x1b\\"
+        );
+        assert_eq!(
+            taskbar_progress(ProgressState::Hidden),
+            "\x1b]9;4;0;0\x1b\\"
+        );
+    }
+
+    #[test]
+    fn wraps_text() {
+        assert_eq!(
+            hyperlink("https://example.com", "x"),
+            "\x1b]8;;https://example.com\x1b\\x\x1b]8;;\x1b\\"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Ready-made status bar layouts.
+
+use ratatui::prelude::*;
+
+use crate::{Role, SeparatorStyle, StatusBar, StatusBarSection, Theme};
+
+/// A ready-made layout for [`StatusBar::preset`].
+///
+/// Presets create empty, [named](StatusBarSection::name) sections that are filled in with
+/// [`StatusBar::named_content`], which keeps the separators and styles of the preset:
+///
+/// | Preset | Sections |
+/// |---|---|
+/// | [`Minimal`](Self::Minimal) | `mode`, `file`, `position` |
+/// | [`Powerline`](Self::Powerline) | `mode`, `branch`, `file`, `position` |
+/// | [`Lualine`](Self::Lualine) | `mode`, `branch`, `file`, `diagnostics`, `filetype`, `position` |
+/// | [`Helix`](Self::Helix) | `mode`, `file`, `diagnostics`, `position` |
+///
+/// The first sections are kept together on the left and the others on the right. Sections that
+/// are left empty still draw their separators, so fill in all of them. Powerline and
+/// Lualine draw Nerd Font glyphs, which [`StatusBar::ascii`] replaces with ASCII characters.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Preset {
+    /// A highlighted mode, the file and the cursor position, without decorations.
+    #[default]
+    Minimal,
+    /// Powerline arrows around a highlighted mode and position.
+    Powerline,
+    /// The layout of the lualine Neovim plugin, with three sections on either side.
+    Lualine,
+    /// The layout of the Helix editor.
+    Helix,
+}
+
+impl<'a> StatusBar<'a> {
+    /// Creates a status bar with the sections, separators and [`Theme::dark`] of `preset`.
+    ///
+    /// The theme can be replaced with [`StatusBar::theme`], e.g. with [`Theme::light`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ratatui::prelude::*;
+    /// use ratatui_statusbar::{Preset, StatusBar};
+    ///
+    /// let status_bar = StatusBar::preset(Preset::Helix)
+    ///     .named_content("mode", "NOR")?
+    ///     .named_content("file", "src/lib.rs")?
+    ///     .named_content("position", "12:4")?;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 30, 1));
+    /// Widget::render(status_bar, buf.area, &mut buf);
+    /// assert_eq!(
+    ///     buf.content().iter().map(|cell| cell.symbol()).collect::<String>(),
+    ///     " NOR  src/lib.rs         12:4 "
+    /// );
+    /// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+    /// ```
+    #[must_use]
+    pub fn preset(preset: Preset) -> Self {
+        let theme = Theme::dark();
+        let section = |name: &str| StatusBarSection::default().name(name);
+        let padded = |name: &str, role| {
+            section(name)
+                .pre_separator(" ")
+                .post_separator(" ")
+                .role(role)
+        };
+        let (sections, left) = match preset {
+            Preset::Minimal => (
+                vec![
+                    padded("mode", Role::Accent),
+                    section("file"),
+                    section("position").role(Role::Muted),
+                ],
+                2,
+            ),
+            Preset::Powerline => (
+                vec![
+                    section("mode")
+                        .pre_separator(Span::styled(" ", theme.accent))
+                        .post_separator(arrow("\u{e0b0}", theme.accent, theme.bar))
+                        .role(Role::Accent),
+                    section("branch").role(Role::Muted),
+                    section("file"),
+                    section("position")
+                        .pre_separator(arrow("\u{e0b2}", theme.accent, theme.bar))
+                        .post_separator(Span::styled(" ", theme.accent))
+                        .role(Role::Accent),
+                ],
+                3,
+            ),
+            Preset::Lualine => (
+                vec![
+                    section("mode")
+                        .pre_separator(Span::styled(" ", theme.accent))
+                        .post_separator(arrow("\u{e0b0}", theme.accent, theme.bar))
+                        .role(Role::Accent),
+                    section("branch")
+                        .post_separator(Span::styled(" \u{e0b1}", theme.muted))
+                        .role(Role::Muted),
+                    section("file"),
+                    section("diagnostics")
+                        .post_separator(Span::styled(" \u{e0b3}", theme.muted))
+                        .role(Role::Warning),
+                    section("filetype").role(Role::Muted),
+                    section("position")
+                        .pre_separator(arrow("\u{e0b2}", theme.accent, theme.bar))
+                        .post_separator(Span::styled(" ", theme.accent))
+                        .role(Role::Accent),
+                ],
+                3,
+            ),
+            Preset::Helix => (
+                vec![
+                    padded("mode", Role::Accent),
+                    section("file"),
+                    section("diagnostics").role(Role::Warning),
+                    padded("position", Role::Muted),
+                ],
+                2,
+            ),
+        };
+        let separator_style = match preset {
+            Preset::Minimal | Preset::Helix => SeparatorStyle::Section,
+            Preset::Powerline | Preset::Lualine => SeparatorStyle::Explicit,
+        };
+        let mut status_bar = Self::new(0).theme(theme).separator_style(separator_style);
+        status_bar.sections = sections;
+        status_bar.gap_weights((0..left).map(|index| u16::from(index + 1 == left)))
+    }
+}
+
+/// Returns a powerline arrow leading from the background of `from` to the background of `to`.
+fn arrow(glyph: &'static str, from: Style, to: Style) -> Span<'static> {
+    let mut style = Style::new();
+    if let Some(color) = from.bg {
+        style = style.fg(color);
+    }
+    if let Some(color) = to.bg {
+        style = style.bg(color);
+    }
+    Span::styled(glyph, style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StatusBarError;
+
+    #[test]
+    fn presets() -> Result<(), StatusBarError> {
+        let fill = |preset| -> Result<StatusBar<'static>, StatusBarError> {
+            let names: Vec<_> = StatusBar::preset(preset)
+                .describe()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect();
+            assert!(names.is_empty(), "{names:?}");
+            StatusBar::preset(preset)
+                .named_content("mode", "NORMAL")?
+                .named_content("file", "main.rs")?
+                .named_content("position", "1:1")
+        };
+        let render = |status_bar: StatusBar| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 40, 1));
+            Widget::render(status_bar, buf.area, &mut buf);
+            buf.content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        assert_eq!(
+            render(fill(Preset::Minimal)?),
+            " NORMAL  main.rs                     1:1"
+        );
+        assert_eq!(
+            render(fill(Preset::Powerline)?.ascii(true)),
+            " NORMAL>  main.rs                  <1:1 "
+        );
+        assert_eq!(
+            render(
+                fill(Preset::Lualine)?
+                    .named_content("branch", "dev")?
+                    .named_content("diagnostics", "W1")?
+                    .named_content("filetype", "rust")?
+                    .ascii(true)
+            ),
+            " NORMAL> dev > main.rs   W1 < rust <1:1 "
+        );
+        assert!(StatusBar::preset(Preset::Lualine)
+            .named_content("clock", "12:00")
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+//! Command and search prompts edited with `tui-textarea`.
+
+use ratatui::prelude::*;
+use ratatui::widgets::WidgetRef;
+use tui_textarea::TextArea;
+
+use crate::{width, StatusBarSection};
+
+/// A command or search prompt shown in a section, edited with a [`TextArea`] from
+/// [`tui-textarea`](https://docs.rs/tui-textarea).
+///
+/// The application owns the text area and passes key events to [`TextArea::input`], which
+/// provides undo history, a kill ring and Unicode aware editing. The prompt shows a prefix such as
+/// `:` or `/` followed by the first line of the text area with its cursor, and scrolls long input
+/// to keep the cursor visible. Convert it into a [`StatusBarSection`] to add it to a status bar.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::{Prompt, StatusBar};
+/// use tui_textarea::TextArea;
+///
+/// let mut textarea = TextArea::default();
+/// textarea.insert_str("write");
+///
+/// let status_bar = StatusBar::new(2)
+///     .section(0, Prompt::new(&textarea).prefix(":"))?
+///     .section(1, "NORMAL")?;
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 14, 1));
+/// Widget::render(&status_bar, buf.area, &mut buf);
+/// assert_eq!(buf.content[0].symbol(), ":");
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prompt<'a> {
+    textarea: &'a TextArea<'a>,
+    prefix: Span<'a>,
+    max_width: Option<u16>,
+}
+
+impl<'a> Prompt<'a> {
+    /// Creates a prompt showing the input of `textarea`.
+    #[must_use]
+    pub fn new(textarea: &'a TextArea<'a>) -> Self {
+        Self {
+            textarea,
+            prefix: Span::default(),
+            max_width: None,
+        }
+    }
+
+    /// Sets the text shown before the input, e.g. `:` or `/`.
+    #[must_use]
+    pub fn prefix(mut self, prefix: impl Into<Span<'a>>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Sets the width of the prompt, including the prefix. Longer input is scrolled to keep the
+    /// cursor visible.
+    #[must_use]
+    pub const fn max_width(mut self, max_width: u16) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Returns the width of the prefix, the first line of the input and a cell for the cursor at
+    /// its end, limited to the maximum width.
+    #[must_use]
+    pub fn width(&self) -> u16 {
+        let input = self
+            .textarea
+            .lines()
+            .first()
+            .map_or(0, |line| width::width(line));
+        let width = width::span_width(&self.prefix) + input + 1;
+        let width = u16::try_from(width).unwrap_or(u16::MAX);
+        self.max_width
+            .map_or(width, |max_width| width.min(max_width))
+    }
+}
+
+impl WidgetRef for Prompt<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let x = width::set_span(buf, area.x, area.y, &self.prefix, area.width);
+        let input = Rect {
+            x,
+            width: area.right().saturating_sub(x),
+            height: 1,
+            ..area
+        };
+        if !input.is_empty() {
+            self.textarea.widget().render(input, buf);
+        }
+    }
+}
+
+impl<'a> From<Prompt<'a>> for StatusBarSection<'a> {
+    fn from(prompt: Prompt<'a>) -> Self {
+        let width = prompt.width();
+        StatusBarSection::default().widget(prompt, width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tui_textarea::{Input, Key};
+
+    use super::*;
+    use crate::StatusBar;
+
+    #[test]
+    fn edits_and_scrolls() -> Result<(), crate::StatusBarError> {
+        let mut textarea = TextArea::default();
+        textarea.set_cursor_style(Style::default());
+        textarea.set_cursor_line_style(Style::default());
+        for c in "write".chars() {
+            textarea.input(Input {
+                key: Key::Char(c),
+                ..Input::default()
+            });
+        }
+        let prompt = Prompt::new(&textarea).prefix(":");
+        assert_eq!(prompt.width(), 7);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Widget::render(
+            &StatusBar::new(1).section(0, prompt.max_width(4))?,
+            buf.area,
+            &mut buf,
+        );
+        assert_eq!(buf, Buffer::with_lines(vec![":te "]));
+
+        // deleting a word goes to the kill ring and can be pasted back
+        textarea.input(Input {
+            key: Key::Char('w'),
+            ctrl: true,
+            ..Input::default()
+        });
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Widget::render(
+            &StatusBar::new(1).section(0, Prompt::new(&textarea).prefix(":"))?,
+            buf.area,
+            &mut buf,
+        );
+        assert_eq!(buf, Buffer::with_lines(vec![":   "]));
+        assert!(textarea.paste());
+        assert_eq!(textarea.lines(), ["write"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+//! Dynamic content providers for status bar sections.
+//!
+//! A [`Segment`] produces the content of a section on demand, for example the current time or
+//! the checked out git branch. Call [`Segment::content`] whenever the status bar is built to get
+//! an up to date [`Line`]. Animated segments such as a [`Spinner`] are driven by
+//! [`Segment::advance`], usually through
+//! [`StatusBarState::advance_with`](crate::StatusBarState::advance_with).
+//!
+//! Segments don't read the time or spawn threads on their own, so they work on the web with
+//! ratatui's wasm backends: time passes through [`Segment::advance`], and the clock of a [`Clock`]
+//! or [`Timed`] can be replaced. Only [`Threaded`] needs threads and isn't available on
+//! `wasm32-unknown-unknown`.
+//!
+//! # Examples
+//! ```
+//! use ratatui_statusbar::segments::{Clock, Segment};
+//! use ratatui_statusbar::StatusBar;
+//!
+//! let mut clock = Clock::new().format("%H:%M:%S");
+//! let status_bar = StatusBar::new(1).section(0, clock.content()).unwrap();
+//! ```
+
+mod breadcrumbs;
+mod cached;
+#[cfg(feature = "segments-clock")]
+mod clock;
+mod fallback;
+#[cfg(feature = "segments-git")]
+mod git;
+mod log;
+mod progress;
+mod registry;
+#[cfg(feature = "scripting")]
+mod script;
+mod spinner;
+mod stale;
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+mod threaded;
+mod timed;
+
+use std::time::Duration;
+
+use ratatui::text::Line;
+
+use crate::StatusBarError;
+
+pub use breadcrumbs::{Breadcrumbs, Crumb};
+pub use cached::Cached;
+#[cfg(feature = "segments-clock")]
+pub use clock::Clock;
+pub use fallback::{Fallback, OnError};
+#[cfg(feature = "segments-git")]
+pub use git::GitBranch;
+pub use log::{LogLevel, LogMessage, LogSegment};
+pub use progress::{ProgressSegment, ProgressState};
+pub use registry::{OptionValue, SegmentFactory, SegmentOptions, SegmentRegistry};
+#[cfg(feature = "scripting")]
+pub use script::Script;
+pub use spinner::Spinner;
+pub use stale::{Stale, StaleIndicator};
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+pub use threaded::Threaded;
+pub use timed::Timed;
+
+/// A provider of dynamic section content.
+pub trait Segment {
+    /// Returns the current content of the segment.
+    fn content(&mut self) -> Line<'static>;
+
+    /// Returns the current content of the segment, or an error if the provider failed, e.g.
+    /// because a command couldn't be run.
+    ///
+    /// Segments that can fail implement this and usually show the error from
+    /// [`Segment::content`]. Wrap them in a [`Fallback`] to show other content instead. The
+    /// default returns the [content](Segment::content).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, usually [`StatusBarError::Segment`], if the content can't be produced.
+    fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+        Ok(self.content())
+    }
+
+    /// Advances time-based behavior such as animations or expiring content by `elapsed`.
+    ///
+    /// Returns whether the content changed, i.e. whether the status bar needs to be redrawn.
+    /// Segments without time-based behavior don't need to implement this.
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        let _ = elapsed;
+        false
+    }
+
+    /// Returns how long until the content next changes on its own, e.g. until the next minute
+    /// for a [`Clock`] showing hours and minutes, or `None` if it doesn't change with time.
+    ///
+    /// Applications can sleep for the shortest of these durations instead of polling, and still
+    /// redraw exactly when a value changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use ratatui_statusbar::segments::{Clock, Segment};
+    ///
+    /// let segments: Vec<Box<dyn Segment>> =
+    ///     vec![Box::new(Clock::new()), Box::new(Clock::new().format("%H:%M:%S"))];
+    /// let timeout = segments.iter().filter_map(|segment| segment.until_change()).min();
+    /// assert!(timeout.unwrap().as_secs() < 1);
+    /// ```
+    fn until_change(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Returns whether the content is outdated because the last refresh failed or hasn't
+    /// finished, e.g. a [`Fallback`] showing fallback content or a [`Threaded`] segment waiting
+    /// for its background thread. [`Stale`] uses this to tell successful updates apart.
+    fn is_stale(&self) -> bool {
+        false
+    }
+}
+
+impl<S: Segment + ?Sized> Segment for Box<S> {
+    fn content(&mut self) -> Line<'static> {
+        (**self).content()
+    }
+
+    fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+        (**self).try_content()
+    }
+
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        (**self).advance(elapsed)
+    }
+
+    fn until_change(&self) -> Option<Duration> {
+        (**self).until_change()
+    }
+
+    fn is_stale(&self) -> bool {
+        (**self).is_stale()
+    }
+}
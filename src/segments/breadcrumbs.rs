@@ -0,0 +1,251 @@
+use std::path::Path;
+
+use ratatui::prelude::*;
+
+use super::Segment;
+use crate::icons::{icon_set, IconSet};
+
+/// A single component of [`Breadcrumbs`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Crumb {
+    label: String,
+    icon: Option<String>,
+    separator: Option<Span<'static>>,
+    style: Style,
+}
+
+impl Crumb {
+    /// Creates a component showing `label`.
+    #[must_use]
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets an icon shown before the label.
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the separator shown after this component, overriding the separator of the
+    /// [`Breadcrumbs`].
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<Span<'static>>) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Sets the style of the icon and label.
+    #[must_use]
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn spans(&self) -> impl Iterator<Item = Span<'static>> + '_ {
+        let icon = self
+            .icon
+            .as_ref()
+            .map(|icon| Span::styled(format!("{icon} "), self.style));
+        icon.into_iter()
+            .chain([Span::styled(self.label.clone(), self.style)])
+    }
+
+    fn width(&self) -> usize {
+        self.spans().map(|span| span.width()).sum()
+    }
+}
+
+impl From<&str> for Crumb {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for Crumb {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// A [`Segment`] showing a trail of components, such as a file path, a menu trail or the symbols
+/// enclosing the cursor.
+///
+/// With a [maximum width](Self::max_width), components are hidden from the middle outwards and
+/// replaced by an ellipsis until the trail fits, so the root and the current location stay
+/// visible as long as possible.
+///
+/// The default separator and ellipsis are ` > ` and `...` if the global
+/// [`IconSet`](crate::icons::IconSet) is [ASCII](crate::icons::IconSet::Ascii).
+///
+/// # Examples
+/// ```
+/// use ratatui::text::Line;
+/// use ratatui_statusbar::segments::{Breadcrumbs, Crumb, Segment};
+///
+/// let mut breadcrumbs = Breadcrumbs::from_path("src/segments/breadcrumbs.rs").max_width(20);
+/// assert_eq!(breadcrumbs.content().to_string(), "src › … › breadcrumbs.rs");
+///
+/// let mut outline = Breadcrumbs::new([Crumb::new("StatusBar").icon("S"), Crumb::new("render")]);
+/// assert_eq!(outline.content().to_string(), "S StatusBar › render");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breadcrumbs {
+    crumbs: Vec<Crumb>,
+    separator: Span<'static>,
+    ellipsis: String,
+    max_width: Option<usize>,
+}
+
+impl Default for Breadcrumbs {
+    fn default() -> Self {
+        let ascii = icon_set() == IconSet::Ascii;
+        Self {
+            crumbs: Vec::new(),
+            separator: Span::raw(if ascii { " > " } else { " › " }),
+            ellipsis: if ascii { "..." } else { "…" }.to_string(),
+            max_width: None,
+        }
+    }
+}
+
+impl Breadcrumbs {
+    /// Creates breadcrumbs from components.
+    #[must_use]
+    pub fn new<I>(crumbs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Crumb>,
+    {
+        Self {
+            crumbs: crumbs.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates breadcrumbs from the components of a path.
+    #[must_use]
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        Self::new(
+            path.as_ref()
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned()),
+        )
+    }
+
+    /// Sets the separator shown between components that don't set their own, ` › ` by default.
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<Span<'static>>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the text that replaces hidden components, `…` by default.
+    #[must_use]
+    pub fn ellipsis(mut self, ellipsis: impl Into<String>) -> Self {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Sets the width the breadcrumbs should fit in.
+    #[must_use]
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Returns the components to show, with hidden components replaced by the ellipsis.
+    ///
+    /// Components are hidden from the middle outwards, preferring those closer to the start. The
+    /// first and last components are never hidden.
+    fn visible(&self) -> Vec<Crumb> {
+        let hideable = self.crumbs.len().saturating_sub(2);
+        let fits = |crumbs: &[Crumb]| {
+            self.max_width
+                .is_none_or(|max_width| self.width(crumbs) <= max_width)
+        };
+        if hideable == 0 || fits(&self.crumbs) {
+            return self.crumbs.clone();
+        }
+        let mut crumbs = Vec::new();
+        for count in 1..=hideable {
+            let start = 1 + (hideable - count) / 2;
+            let hidden = start..start + count;
+            let ellipsis = Crumb {
+                separator: self.crumbs[hidden.end - 1].separator.clone(),
+                ..Crumb::new(self.ellipsis.clone())
+            };
+            crumbs = self.crumbs.clone();
+            crumbs.splice(hidden, [ellipsis]);
+            if fits(&crumbs) {
+                break;
+            }
+        }
+        crumbs
+    }
+
+    fn width(&self, crumbs: &[Crumb]) -> usize {
+        let separators = crumbs
+            .iter()
+            .take(crumbs.len().saturating_sub(1))
+            .map(|crumb| crumb.separator.as_ref().unwrap_or(&self.separator).width());
+        crumbs.iter().map(Crumb::width).chain(separators).sum()
+    }
+}
+
+impl Segment for Breadcrumbs {
+    fn content(&mut self) -> Line<'static> {
+        let crumbs = self.visible();
+        let mut spans = Vec::new();
+        for (index, crumb) in crumbs.iter().enumerate() {
+            if index > 0 {
+                let previous = &crumbs[index - 1];
+                spans.push(
+                    previous
+                        .separator
+                        .clone()
+                        .unwrap_or_else(|| self.separator.clone()),
+                );
+            }
+            spans.extend(crumb.spans());
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_middle() {
+        let mut breadcrumbs = Breadcrumbs::new(["a", "bb", "cc", "dd", "e"]).separator("/");
+        assert_eq!(breadcrumbs.content().to_string(), "a/bb/cc/dd/e");
+        let truncate = |width| breadcrumbs.clone().max_width(width).content().to_string();
+        assert_eq!(truncate(11), "a/bb/…/dd/e");
+        assert_eq!(truncate(8), "a/…/dd/e");
+        assert_eq!(truncate(5), "a/…/e");
+        assert_eq!(truncate(1), "a/…/e");
+    }
+
+    #[test]
+    fn icons_and_separators() {
+        let mut breadcrumbs = Breadcrumbs::new([
+            Crumb::new("mod").icon("M").separator(Span::raw("::")),
+            Crumb::new("fn").style(Style::new().bold()),
+        ]);
+        assert_eq!(
+            breadcrumbs.content(),
+            Line::from(vec![
+                Span::raw("M "),
+                Span::raw("mod"),
+                Span::raw("::"),
+                Span::styled("fn", Style::new().bold()),
+            ])
+        );
+    }
+}
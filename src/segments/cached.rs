@@ -0,0 +1,184 @@
+use std::time::Duration;
+
+use ratatui::text::Line;
+
+use super::Segment;
+use crate::StatusBarError;
+
+/// A [`Segment`] that reuses the content of an expensive segment, such as [`GitBranch`], until
+/// its time to live has passed.
+///
+/// Time passes through [`Segment::advance`], like for animated segments, so the content is
+/// recomputed at most once per `ttl` however often the status bar is built.
+///
+/// [`GitBranch`]: super::GitBranch
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use ratatui_statusbar::segments::{Cached, GitBranch, Segment};
+///
+/// let mut branch = Cached::new(GitBranch::new("."), Duration::from_secs(5));
+/// let first = branch.content();
+/// // on every frame, without running git again until 5 seconds have passed
+/// branch.advance(Duration::from_millis(16));
+/// assert_eq!(branch.content(), first);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cached<S> {
+    segment: S,
+    ttl: Duration,
+    age: Duration,
+    content: Option<Line<'static>>,
+}
+
+impl<S: Segment> Cached<S> {
+    /// Caches the content of `segment` for `ttl`.
+    #[must_use]
+    pub const fn new(segment: S, ttl: Duration) -> Self {
+        Self {
+            segment,
+            ttl,
+            age: Duration::ZERO,
+            content: None,
+        }
+    }
+
+    /// Drops the cached content, so it is recomputed the next time it is requested.
+    pub fn invalidate(&mut self) {
+        self.content = None;
+    }
+
+    /// Returns the wrapped segment.
+    pub fn get_ref(&self) -> &S {
+        &self.segment
+    }
+
+    /// Returns the wrapped segment mutably, e.g. to change its options. Call
+    /// [`Cached::invalidate`] to show the changes before the cache expires.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.segment
+    }
+
+    /// Returns the wrapped segment.
+    pub fn into_inner(self) -> S {
+        self.segment
+    }
+}
+
+impl<S: Segment> Segment for Cached<S> {
+    fn content(&mut self) -> Line<'static> {
+        if let Some(content) = &self.content {
+            return content.clone();
+        }
+        let content = self.segment.content();
+        self.content = Some(content.clone());
+        self.age = Duration::ZERO;
+        content
+    }
+
+    /// Returns the cached content, or refreshes it from the wrapped segment. Errors aren't
+    /// cached, so the next call tries again.
+    fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+        if let Some(content) = &self.content {
+            return Ok(content.clone());
+        }
+        let content = self.segment.try_content()?;
+        self.content = Some(content.clone());
+        self.age = Duration::ZERO;
+        Ok(content)
+    }
+
+    /// Advances the wrapped segment and expires the cached content once it is older than the
+    /// time to live.
+    ///
+    /// Returns whether the content may have changed, i.e. whether the wrapped segment changed or
+    /// the cache expired.
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        let mut changed = self.segment.advance(elapsed);
+        if changed {
+            self.content = None;
+        }
+        if self.content.is_some() {
+            self.age += elapsed;
+            if self.age >= self.ttl {
+                self.content = None;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Returns how long until the wrapped segment changes or the cache expires, whichever comes
+    /// first.
+    fn until_change(&self) -> Option<Duration> {
+        let expiry = self
+            .content
+            .is_some()
+            .then(|| self.ttl.saturating_sub(self.age));
+        match (self.segment.until_change(), expiry) {
+            (Some(change), Some(expiry)) => Some(change.min(expiry)),
+            (change, expiry) => change.or(expiry),
+        }
+    }
+
+    fn is_stale(&self) -> bool {
+        self.segment.is_stale()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(usize);
+
+    impl Segment for Counter {
+        fn content(&mut self) -> Line<'static> {
+            self.0 += 1;
+            Line::from(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn expires() {
+        let mut cached = Cached::new(Counter(0), Duration::from_secs(2));
+        assert_eq!(cached.content(), Line::from("1"));
+        assert!(!cached.advance(Duration::from_secs(1)));
+        assert_eq!(cached.content(), Line::from("1"));
+        assert!(cached.advance(Duration::from_secs(1)));
+        assert_eq!(cached.content(), Line::from("2"));
+        cached.invalidate();
+        assert_eq!(cached.content(), Line::from("3"));
+        assert_eq!(cached.get_ref().0, 3);
+    }
+
+    struct Flaky(Vec<Option<&'static str>>);
+
+    impl Segment for Flaky {
+        fn content(&mut self) -> Line<'static> {
+            self.try_content().unwrap_or_default()
+        }
+
+        fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+            self.0
+                .remove(0)
+                .map(Line::from)
+                .ok_or_else(|| StatusBarError::Segment("offline".to_string()))
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let mut cached = Cached::new(Flaky(vec![None, Some("a"), None]), Duration::from_secs(1));
+        assert!(matches!(
+            cached.try_content(),
+            Err(StatusBarError::Segment(message)) if message == "offline"
+        ));
+        assert_eq!(cached.try_content().ok(), Some(Line::from("a")));
+        assert_eq!(cached.try_content().ok(), Some(Line::from("a")));
+        assert!(cached.advance(Duration::from_secs(1)));
+        assert!(cached.try_content().is_err());
+    }
+}
@@ -0,0 +1,270 @@
+use std::fmt::{self, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{Datelike, Local, NaiveDateTime, Timelike};
+use ratatui::text::Line;
+
+use super::Segment;
+use crate::labels;
+
+/// A [`Segment`] showing the local time.
+///
+/// The time is formatted with a [`chrono` format string](chrono::format::strftime), `%H:%M` by
+/// default. Names of weekdays and months and the AM/PM marker are taken from the global
+/// [`Labels`](crate::labels::Labels).
+///
+/// The local time is read from [`chrono::Local`] unless another source is set with
+/// [`Clock::now`], e.g. a fixed time in tests. On `wasm32-unknown-unknown`, [`chrono::Local`]
+/// reads the time of the browser.
+#[derive(Clone)]
+pub struct Clock {
+    format: String,
+    now: Now,
+}
+
+type Now = Arc<dyn Fn() -> NaiveDateTime + Send + Sync>;
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clock")
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self {
+            format: "%H:%M".to_string(),
+            now: Arc::new(local_now),
+        }
+    }
+}
+
+/// Clocks are equal if they have the same format, whatever their source of time.
+impl PartialEq for Clock {
+    fn eq(&self, other: &Self) -> bool {
+        self.format == other.format
+    }
+}
+
+impl Eq for Clock {}
+
+fn local_now() -> NaiveDateTime {
+    Local::now().naive_local()
+}
+
+impl Clock {
+    /// Creates a clock using the default `%H:%M` format.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the format string used to display the time.
+    ///
+    /// An invalid format string is displayed as-is instead of the time.
+    #[must_use]
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = format.into();
+        self
+    }
+
+    /// Sets the function returning the current local time, which may capture state such as a
+    /// simulated clock.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ratatui::text::Line;
+    /// use ratatui_statusbar::segments::{Clock, Segment};
+    ///
+    /// let mut clock = Clock::new().now(|| {
+    ///     NaiveDate::from_ymd_opt(2024, 3, 4)
+    ///         .and_then(|date| date.and_hms_opt(15, 30, 0))
+    ///         .unwrap()
+    /// });
+    /// assert_eq!(clock.content(), Line::from("15:30"));
+    /// ```
+    #[must_use]
+    pub fn now<F>(mut self, now: F) -> Self
+    where
+        F: Fn() -> NaiveDateTime + Send + Sync + 'static,
+    {
+        self.now = Arc::new(now);
+        self
+    }
+
+    /// Returns the smallest step in which the displayed time changes: a second if the format
+    /// shows seconds, a minute if it shows minutes, and so on up to a day. Formats with
+    /// fractional seconds change every millisecond.
+    #[must_use]
+    pub fn resolution(&self) -> Duration {
+        resolution(&self.format)
+    }
+
+    /// Returns the instant at which the displayed time next changes, aligned to the wall clock,
+    /// so an application can sleep until then instead of polling.
+    ///
+    /// [`Instant`] isn't available on `wasm32-unknown-unknown`, use [`Segment::until_change`]
+    /// there instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Instant;
+    ///
+    /// use ratatui_statusbar::segments::Clock;
+    ///
+    /// let clock = Clock::new().format("%H:%M:%S");
+    /// let redraw_at = clock.next_change();
+    /// assert!(redraw_at <= Instant::now() + clock.resolution());
+    /// ```
+    #[must_use]
+    pub fn next_change(&self) -> Instant {
+        Instant::now() + until_next(self.resolution(), (self.now)())
+    }
+}
+
+impl Segment for Clock {
+    fn content(&mut self) -> Line<'static> {
+        Line::from(format_time(&self.format, (self.now)()))
+    }
+
+    fn until_change(&self) -> Option<Duration> {
+        Some(until_next(self.resolution(), (self.now)()))
+    }
+}
+
+/// Returns the smallest step in which a time formatted with `format` changes.
+fn resolution(format: &str) -> Duration {
+    let mut resolution = Duration::from_secs(86_400);
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        // skip padding, width and precision modifiers such as `%-d`, `%.3f` or `%::z`
+        let Some(specifier) = chars
+            .by_ref()
+            .find(|c| !matches!(c, '-' | '_' | '0'..='9' | '.' | ':' | '#'))
+        else {
+            break;
+        };
+        let step = match specifier {
+            'f' => Duration::from_millis(1),
+            'S' | 'T' | 'X' | 'r' | 'c' | 's' | '+' => Duration::from_secs(1),
+            'M' | 'R' => Duration::from_secs(60),
+            'H' | 'k' | 'I' | 'l' | 'p' | 'P' => Duration::from_secs(3600),
+            _ => continue,
+        };
+        resolution = resolution.min(step);
+    }
+    resolution
+}
+
+/// Returns the time from `time` to the next multiple of `resolution` since midnight.
+fn until_next(resolution: Duration, time: NaiveDateTime) -> Duration {
+    let step = resolution.as_nanos().max(1);
+    // leap seconds are reported as a nanosecond value past a second
+    let since_midnight = u128::from(time.num_seconds_from_midnight()) * 1_000_000_000
+        + u128::from(time.nanosecond().min(999_999_999));
+    let remaining = step - since_midnight % step;
+    Duration::from_nanos(u64::try_from(remaining).unwrap_or(u64::MAX))
+}
+
+/// Formats `time` with `format`, or returns `format` if it is invalid.
+fn format_time(format: &str, time: NaiveDateTime) -> String {
+    let format = localize(format, time);
+    let mut formatted = String::new();
+    if write!(formatted, "{}", time.format(&format)).is_err() {
+        return format;
+    }
+    formatted
+}
+
+/// Replaces the specifiers of names in `format` with the labels for `time`.
+fn localize(format: &str, time: NaiveDateTime) -> String {
+    let weekday = time.weekday().num_days_from_monday() as usize;
+    let month = time.month0() as usize;
+    let mut localized = String::with_capacity(format.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+        let label = match chars.clone().next() {
+            Some('A') => labels::WEEKDAYS[weekday],
+            Some('a') => labels::WEEKDAYS_SHORT[weekday],
+            Some('B') => labels::MONTHS[month],
+            Some('b' | 'h') => labels::MONTHS_SHORT[month],
+            Some('p') if time.hour12().0 => labels::PM,
+            Some('p') => labels::AM,
+            Some('%') => {
+                chars.next();
+                localized.push_str("%%");
+                continue;
+            }
+            _ => {
+                localized.push(c);
+                continue;
+            }
+        };
+        chars.next();
+        localized.push_str(&labels::label(label).replace('%', "%%"));
+    }
+    localized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn localized_names() {
+        let time = chrono::NaiveDate::from_ymd_opt(2024, 3, 4)
+            .and_then(|date| date.and_hms_opt(15, 30, 0))
+            .unwrap();
+        assert_eq!(
+            format_time("%a %A %b %B %I%p %%a", time),
+            "Mon Monday Mar March 03PM %a"
+        );
+    }
+
+    #[test]
+    fn next_change() {
+        let time = chrono::NaiveDate::from_ymd_opt(2024, 3, 4)
+            .and_then(|date| date.and_hms_milli_opt(15, 30, 10, 250))
+            .unwrap();
+        let until = |format| until_next(resolution(format), time);
+        assert_eq!(until("%H:%M:%S"), Duration::from_millis(750));
+        assert_eq!(until("%-I:%M %p"), Duration::from_millis(49_750));
+        assert_eq!(until("%I %p"), Duration::from_millis(29 * 60_000 + 49_750));
+        assert_eq!(
+            until("%a %d"),
+            Duration::from_millis(8 * 3_600_000 + 29 * 60_000 + 49_750)
+        );
+        assert_eq!(resolution("%H:%M:%S%.3f"), Duration::from_millis(1));
+        assert_eq!(resolution("%%M"), Duration::from_secs(86_400));
+    }
+
+    #[test]
+    fn captured_time() {
+        let time = chrono::NaiveDate::from_ymd_opt(2024, 3, 4)
+            .and_then(|date| date.and_hms_opt(9, 5, 0))
+            .unwrap();
+        let mut clock = Clock::new().now(move || time);
+        assert_eq!(clock.content(), Line::from("09:05"));
+        assert_eq!(clock.clone(), clock);
+    }
+
+    #[test]
+    fn invalid_format() {
+        assert_eq!(Clock::new().format("%Q").content(), Line::from("%Q"));
+        assert_eq!(
+            Clock::new().format("static").content(),
+            Line::from("static")
+        );
+    }
+}
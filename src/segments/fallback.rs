@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use ratatui::prelude::*;
+
+use super::Segment;
+use crate::StatusBarError;
+
+/// What a [`Fallback`] shows while the wrapped segment fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OnError {
+    /// Shows fixed content instead, e.g. `git: ?`.
+    Show(Line<'static>),
+    /// Shows nothing.
+    Hide,
+    /// Shows the last content the segment produced, followed by a marker such as `*`, or nothing
+    /// if it never succeeded.
+    LastKnown(Span<'static>),
+}
+
+/// A [`Segment`] that shows fallback content while the wrapped segment fails, so that one failing
+/// provider doesn't break the status bar.
+///
+/// Segments report failures from [`Segment::try_content`].
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::segments::{Fallback, OnError, Segment};
+/// use ratatui_statusbar::StatusBarError;
+///
+/// struct Battery;
+///
+/// impl Segment for Battery {
+///     fn content(&mut self) -> Line<'static> {
+///         self.try_content().unwrap_or_default()
+///     }
+///
+///     fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+///         Err(StatusBarError::Segment("no battery found".to_string()))
+///     }
+/// }
+///
+/// let mut battery = Fallback::new(Battery, OnError::Show(Line::from("bat: ?").dim()));
+/// assert_eq!(battery.content().to_string(), "bat: ?");
+/// assert!(battery.error().is_some());
+/// ```
+#[derive(Debug)]
+pub struct Fallback<S> {
+    segment: S,
+    on_error: OnError,
+    last_known: Option<Line<'static>>,
+    error: Option<StatusBarError>,
+}
+
+impl<S: Segment> Fallback<S> {
+    /// Wraps `segment`, showing `on_error` while it fails.
+    #[must_use]
+    pub const fn new(segment: S, on_error: OnError) -> Self {
+        Self {
+            segment,
+            on_error,
+            last_known: None,
+            error: None,
+        }
+    }
+
+    /// Returns the error of the last failed attempt, or `None` if the last attempt succeeded.
+    ///
+    /// This can be used to hide the section of a failing segment or to explain the failure in a
+    /// [tooltip](crate::StatusBarSection::tooltip).
+    pub const fn error(&self) -> Option<&StatusBarError> {
+        self.error.as_ref()
+    }
+
+    /// Returns the wrapped segment.
+    pub fn get_ref(&self) -> &S {
+        &self.segment
+    }
+
+    /// Returns the wrapped segment mutably.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.segment
+    }
+}
+
+impl<S: Segment> Segment for Fallback<S> {
+    fn content(&mut self) -> Line<'static> {
+        match self.segment.try_content() {
+            Ok(content) => {
+                self.error = None;
+                self.last_known = Some(content.clone());
+                content
+            }
+            Err(err) => {
+                self.error = Some(err);
+                match &self.on_error {
+                    OnError::Show(content) => content.clone(),
+                    OnError::Hide => Line::default(),
+                    OnError::LastKnown(marker) => self
+                        .last_known
+                        .clone()
+                        .map(|mut content| {
+                            content.spans.push(marker.clone());
+                            content
+                        })
+                        .unwrap_or_default(),
+                }
+            }
+        }
+    }
+
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        self.segment.advance(elapsed)
+    }
+
+    fn until_change(&self) -> Option<Duration> {
+        self.segment.until_change()
+    }
+
+    /// Returns whether the last attempt failed or the wrapped segment is stale.
+    fn is_stale(&self) -> bool {
+        self.error.is_some() || self.segment.is_stale()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Flaky(Vec<Option<&'static str>>);
+
+    impl Segment for Flaky {
+        fn content(&mut self) -> Line<'static> {
+            self.try_content().unwrap_or_default()
+        }
+
+        fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+            match self.0.remove(0) {
+                Some(content) => Ok(Line::from(content)),
+                None => Err(StatusBarError::Segment("offline".to_string())),
+            }
+        }
+    }
+
+    #[test]
+    fn policies() {
+        let flaky = || Flaky(vec![None, Some("a"), None, Some("b")]);
+        let contents = |mut fallback: Fallback<Flaky>| {
+            (0..4)
+                .map(|_| fallback.content().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            contents(Fallback::new(flaky(), OnError::Show(Line::from("?")))),
+            ["?", "a", "?", "b"]
+        );
+        assert_eq!(
+            contents(Fallback::new(flaky(), OnError::Hide)),
+            ["", "a", "", "b"]
+        );
+        assert_eq!(
+            contents(Fallback::new(flaky(), OnError::LastKnown(Span::raw("*")))),
+            ["", "a", "a*", "b"]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ratatui::text::Line;
+
+use super::Segment;
+use crate::icons::Icon;
+
+/// A [`Segment`] showing the checked out git branch.
+///
+/// The repository is discovered by walking up from the given path. When `HEAD` is detached the
+/// abbreviated commit hash is shown instead, and outside of a repository the content is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitBranch {
+    path: PathBuf,
+    icon: bool,
+}
+
+impl Default for GitBranch {
+    fn default() -> Self {
+        Self::new(".")
+    }
+}
+
+impl GitBranch {
+    /// Creates a segment for the repository containing `path`.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            icon: false,
+        }
+    }
+
+    /// Shows the [branch icon](Icon::Branch) of the global [`IconSet`](crate::icons::IconSet)
+    /// before the branch.
+    #[must_use]
+    pub fn icon(mut self, icon: bool) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Returns the name of the checked out branch, or the abbreviated commit hash when `HEAD` is
+    /// detached.
+    #[must_use]
+    pub fn branch(&self) -> Option<String> {
+        let git_dir = find_git_dir(&self.path)?;
+        let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+        let head = head.trim();
+        match head.strip_prefix("ref: ") {
+            Some(reference) => Some(
+                reference
+                    .strip_prefix("refs/heads/")
+                    .unwrap_or(reference)
+                    .to_string(),
+            ),
+            None => Some(head.chars().take(7).collect()),
+        }
+    }
+
+    /// Returns the web URL of the `origin` remote, e.g. to
+    /// [link](crate::StatusBarSection::hyperlink) the branch to the repository.
+    ///
+    /// SSH remotes like `git@github.com:user/repo.git` are converted to `https://` URLs.
+    #[must_use]
+    pub fn remote_url(&self) -> Option<String> {
+        let git_dir = find_git_dir(&self.path)?;
+        // worktrees share the configuration of the main repository
+        let common_dir = fs::read_to_string(git_dir.join("commondir"))
+            .map_or_else(|_| git_dir.clone(), |dir| git_dir.join(dir.trim()));
+        let config = fs::read_to_string(common_dir.join("config")).ok()?;
+        let url = config
+            .lines()
+            .map(str::trim)
+            .skip_while(|line| *line != r#"[remote "origin"]"#)
+            .skip(1)
+            .take_while(|line| !line.starts_with('['))
+            .find_map(|line| {
+                let (key, value) = line.split_once('=')?;
+                (key.trim() == "url").then(|| value.trim())
+            })?;
+        Some(web_url(url))
+    }
+}
+
+impl Segment for GitBranch {
+    fn content(&mut self) -> Line<'static> {
+        let Some(branch) = self.branch() else {
+            return Line::default();
+        };
+        if self.icon {
+            Line::from(format!("{} {branch}", Icon::Branch.glyph()))
+        } else {
+            Line::from(branch)
+        }
+    }
+}
+
+/// Converts a remote URL to the URL of the repository's web page.
+fn web_url(url: &str) -> String {
+    let url = url.strip_suffix(".git").unwrap_or(url);
+    if let Some(rest) = url.strip_prefix("ssh://") {
+        let rest = rest.split_once('@').map_or(rest, |(_, host)| host);
+        return format!("https://{rest}");
+    }
+    match url.split_once(':') {
+        Some((user_host, path)) if !url.contains("://") => {
+            let host = user_host
+                .split_once('@')
+                .map_or(user_host, |(_, host)| host);
+            format!("https://{host}/{path}")
+        }
+        _ => url.to_string(),
+    }
+}
+
+/// Finds the git directory of the repository containing `path`, following `gitdir:` links used
+/// by worktrees and submodules.
+fn find_git_dir(path: &Path) -> Option<PathBuf> {
+    let path = path.canonicalize().ok()?;
+    path.ancestors().find_map(|dir| {
+        let dot_git = dir.join(".git");
+        if dot_git.is_dir() {
+            Some(dot_git)
+        } else if dot_git.is_file() {
+            let link = fs::read_to_string(&dot_git).ok()?;
+            let target = link.trim().strip_prefix("gitdir: ")?;
+            Some(dir.join(target))
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remote_web_urls() {
+        assert_eq!(
+            web_url("https://github.com/u/r.git"),
+            "https://github.com/u/r"
+        );
+        assert_eq!(
+            web_url("ssh://git@example.com/u/r"),
+            "https://example.com/u/r"
+        );
+        assert_eq!(web_url("git@gitlab.com:u/r.git"), "https://gitlab.com/u/r");
+    }
+
+    #[test]
+    fn branch_from_head() -> std::io::Result<()> {
+        let root = std::env::temp_dir().join(format!("statusbar-git-{}", std::process::id()));
+        fs::create_dir_all(root.join(".git"))?;
+        fs::create_dir_all(root.join("src"))?;
+
+        fs::write(root.join(".git/HEAD"), "ref: refs/heads/feature/x\n")?;
+        assert_eq!(
+            GitBranch::new(root.join("src")).branch().as_deref(),
+            Some("feature/x")
+        );
+
+        fs::write(root.join(".git/HEAD"), "0123456789abcdef\n")?;
+        assert_eq!(GitBranch::new(&root).content(), Line::from("0123456"));
+        assert!(GitBranch::new(&root)
+            .icon(true)
+            .content()
+            .to_string()
+            .ends_with(" 0123456"));
+
+        fs::write(
+            root.join(".git/config"),
+            "[core]\n\tbare = false\n[remote \"origin\"]\n\turl = git@github.com:user/repo.git\n",
+        )?;
+        assert_eq!(
+            GitBranch::new(&root).remote_url().as_deref(),
+            Some("https://github.com/user/repo")
+        );
+
+        fs::remove_dir_all(root)
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use ratatui::prelude::*;
+
+use super::Segment;
+use crate::osc::{self, Notification};
+
+/// How many notifications are queued until they are written, see [`LogSegment::notify`].
+const MAX_PENDING: usize = 64;
+
+/// The severity of a [`LogMessage`], ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Very verbose diagnostic information.
+    Trace,
+    /// Diagnostic information.
+    Debug,
+    /// Informational messages.
+    Info,
+    /// Something unexpected that doesn't prevent the application from working.
+    Warn,
+    /// Something failed.
+    Error,
+}
+
+/// A message shown by a [`LogSegment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogMessage {
+    /// The severity of the message.
+    pub level: LogLevel,
+    /// The module or component the message originates from.
+    pub target: String,
+    /// The text of the message.
+    pub message: String,
+}
+
+/// A [`Segment`] showing the most recent log message.
+///
+/// The segment is a cheap handle to shared state: clone it, hand one clone to whatever produces
+/// messages (for example the `tracing` layer in [`tracing_layer`](crate::tracing_layer) or the
+/// [`logger`](crate::logger) bridge) and keep the other one to build the status bar.
+///
+/// A bounded history of the most recent messages is kept as well, e.g. to list them in a popup.
+/// With a [timeout](Self::timeout), the shown message is hidden once it has been shown for that
+/// long, as measured by [`Segment::advance`].
+///
+/// Messages at or above a severity can also [notify](Self::notify) the user through the terminal,
+/// so errors are noticed while the terminal isn't focused.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::osc::Notification;
+/// use ratatui_statusbar::segments::{LogLevel, LogMessage, LogSegment};
+///
+/// let log = LogSegment::new().notify(LogLevel::Error, Notification::Osc9);
+/// log.push(LogMessage {
+///     level: LogLevel::Error,
+///     target: "sync".to_string(),
+///     message: "upload failed".to_string(),
+/// });
+///
+/// // after drawing the frame
+/// let mut terminal = Vec::new();
+/// assert_eq!(log.write_notifications(&mut terminal)?, 1);
+/// assert_eq!(terminal, b"\x1b]9;upload failed\x1b\\");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogSegment {
+    state: Arc<Mutex<LogState>>,
+}
+
+#[derive(Debug)]
+struct LogState {
+    messages: VecDeque<LogMessage>,
+    capacity: usize,
+    shown: bool,
+    timeout: Option<Duration>,
+    age: Duration,
+    notify: Option<(LogLevel, Notification)>,
+    pending: VecDeque<LogMessage>,
+}
+
+impl Default for LogSegment {
+    fn default() -> Self {
+        Self::with_history(1)
+    }
+}
+
+impl LogSegment {
+    /// Creates an empty log segment that only keeps the latest message.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty log segment that keeps up to `capacity` messages of history.
+    #[must_use]
+    pub fn with_history(capacity: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(LogState {
+                messages: VecDeque::with_capacity(capacity),
+                capacity: capacity.max(1),
+                shown: false,
+                timeout: None,
+                age: Duration::ZERO,
+                notify: None,
+                pending: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Hides each message after it has been shown for `timeout`.
+    #[must_use]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.lock().timeout = Some(timeout);
+        self
+    }
+
+    /// Notifies the user of messages at or above `level` with `notification`.
+    ///
+    /// Messages may be pushed from any thread, so they are queued and only written to the
+    /// terminal by [`write_notifications`](Self::write_notifications). Up to 64 notifications are
+    /// queued, dropping the oldest ones if they aren't written.
+    #[must_use]
+    pub fn notify(self, level: LogLevel, notification: Notification) -> Self {
+        self.lock().notify = Some((level, notification));
+        self
+    }
+
+    /// Writes the notifications of the messages pushed since the last call and returns how many
+    /// messages were notified. The bell rings once for all of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the underlying writer.
+    pub fn write_notifications<W: Write>(&self, writer: &mut W) -> io::Result<usize> {
+        let (pending, notification) = {
+            let mut state = self.lock();
+            let Some((_, notification)) = state.notify else {
+                return Ok(0);
+            };
+            (std::mem::take(&mut state.pending), notification)
+        };
+        if pending.is_empty() {
+            return Ok(0);
+        }
+        if notification == Notification::Bell {
+            writer.write_all(osc::notification(notification, "", "").as_bytes())?;
+        } else {
+            for message in &pending {
+                let sequence = osc::notification(notification, &message.target, &message.message);
+                writer.write_all(sequence.as_bytes())?;
+            }
+        }
+        writer.flush()?;
+        Ok(pending.len())
+    }
+
+    /// Shows a message and adds it to the history, dropping the oldest message when the history
+    /// is full.
+    pub fn push(&self, message: LogMessage) {
+        let mut state = self.lock();
+        if state.messages.len() == state.capacity {
+            state.messages.pop_front();
+        }
+        if state
+            .notify
+            .is_some_and(|(level, _)| message.level >= level)
+        {
+            if state.pending.len() == MAX_PENDING {
+                state.pending.pop_front();
+            }
+            state.pending.push_back(message.clone());
+        }
+        state.messages.push_back(message);
+        state.shown = true;
+        state.age = Duration::ZERO;
+    }
+
+    /// Returns the shown message, if any.
+    #[must_use]
+    pub fn latest(&self) -> Option<LogMessage> {
+        let state = self.lock();
+        state.messages.back().filter(|_| state.shown).cloned()
+    }
+
+    /// Returns the recent messages, oldest first.
+    #[must_use]
+    pub fn history(&self) -> Vec<LogMessage> {
+        self.lock().messages.iter().cloned().collect()
+    }
+
+    /// Hides the shown message. The history is kept.
+    pub fn clear(&self) {
+        self.lock().shown = false;
+    }
+
+    /// Hides the shown message and forgets the history.
+    pub fn clear_history(&self) {
+        let mut state = self.lock();
+        state.messages.clear();
+        state.shown = false;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LogState> {
+        // a panic while holding the lock can't leave the state inconsistent
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Segment for LogSegment {
+    fn content(&mut self) -> Line<'static> {
+        self.latest().map_or_else(Line::default, |message| {
+            let style = match message.level {
+                LogLevel::Error => Style::new().red(),
+                LogLevel::Warn => Style::new().yellow(),
+                LogLevel::Info => Style::new(),
+                LogLevel::Debug | LogLevel::Trace => Style::new().dark_gray(),
+            };
+            Line::styled(message.message, style)
+        })
+    }
+
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        let mut state = self.lock();
+        let Some(timeout) = state.timeout.filter(|_| state.shown) else {
+            return false;
+        };
+        state.age += elapsed;
+        state.shown = state.age < timeout;
+        !state.shown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(level: LogLevel, message: &str) -> LogMessage {
+        LogMessage {
+            level,
+            target: "app".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn shared_latest_message() {
+        let mut segment = LogSegment::new();
+        assert_eq!(segment.content(), Line::default());
+        segment.clone().push(message(LogLevel::Error, "failed"));
+        assert_eq!(
+            segment.content(),
+            Line::styled("failed", Style::new().red())
+        );
+        segment.clear();
+        assert_eq!(segment.latest(), None);
+    }
+
+    #[test]
+    fn message_expires() {
+        let mut segment = LogSegment::new().timeout(Duration::from_secs(2));
+        segment.push(message(LogLevel::Warn, "slow"));
+        assert!(!segment.advance(Duration::from_secs(1)));
+        assert!(segment.latest().is_some());
+        assert!(segment.advance(Duration::from_secs(1)));
+        assert_eq!(segment.latest(), None);
+        assert!(!segment.advance(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn notifications() -> io::Result<()> {
+        let segment = LogSegment::new().notify(LogLevel::Warn, Notification::Bell);
+        let mut output = Vec::new();
+        assert_eq!(segment.write_notifications(&mut output)?, 0);
+        segment.push(message(LogLevel::Info, "saved"));
+        segment.push(message(LogLevel::Warn, "slow"));
+        segment.push(message(LogLevel::Error, "failed"));
+        assert_eq!(segment.write_notifications(&mut output)?, 2);
+        assert_eq!(segment.write_notifications(&mut output)?, 0);
+        assert_eq!(output, b"\x07");
+
+        let segment = LogSegment::new();
+        segment.push(message(LogLevel::Error, "failed"));
+        assert_eq!(segment.write_notifications(&mut output)?, 0);
+
+        let segment = LogSegment::new().notify(LogLevel::Info, Notification::Osc9);
+        for _ in 0..MAX_PENDING + 1 {
+            segment.push(message(LogLevel::Info, "saved"));
+        }
+        segment.push(message(LogLevel::Error, "failed"));
+        assert_eq!(segment.lock().pending.len(), MAX_PENDING);
+        assert_eq!(
+            segment.lock().pending.back(),
+            Some(&message(LogLevel::Error, "failed"))
+        );
+        assert_eq!(segment.write_notifications(&mut output)?, MAX_PENDING);
+        Ok(())
+    }
+
+    #[test]
+    fn bounded_history() {
+        let segment = LogSegment::with_history(2);
+        for text in ["a", "b", "c"] {
+            segment.push(message(LogLevel::Info, text));
+        }
+        segment.clear();
+        let history = segment.history();
+        assert_eq!(
+            history,
+            [message(LogLevel::Info, "b"), message(LogLevel::Info, "c")]
+        );
+        segment.clear_history();
+        assert!(segment.history().is_empty());
+    }
+}
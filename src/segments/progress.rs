@@ -0,0 +1,234 @@
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use ratatui::prelude::*;
+
+use super::Segment;
+use crate::format::Humanize;
+use crate::icons::{icon_set, IconSet};
+use crate::osc;
+use crate::Thresholds;
+
+/// The state of a long-running operation shown by a [`ProgressSegment`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProgressState {
+    /// No operation is running and the segment is empty.
+    #[default]
+    Hidden,
+    /// The operation is running and done to the given percentage.
+    Normal(u8),
+    /// The operation failed at the given percentage.
+    Error(u8),
+    /// The operation is running, but how far along it is isn't known.
+    Indeterminate,
+    /// The operation is paused at the given percentage.
+    Paused(u8),
+}
+
+/// A [`Segment`] showing the progress of a long-running operation as a bar and a percentage.
+///
+/// Like a [`LogSegment`](super::LogSegment), the segment is a cheap handle to shared state: hand
+/// a clone to the operation and [`set`](Self::set) its progress from any thread.
+///
+/// Running operations are styled by their percentage with [`thresholds`](Self::thresholds),
+/// failed ones in red and paused ones in yellow.
+///
+/// The progress can also be reported to the terminal with OSC 9;4, which ConEmu, Windows Terminal
+/// and WezTerm show in the tab or taskbar. Call [`write_taskbar`](Self::write_taskbar) after
+/// drawing the frame.
+///
+/// # Examples
+/// ```
+/// use ratatui::text::Line;
+/// use ratatui_statusbar::segments::{ProgressSegment, ProgressState, Segment};
+///
+/// let mut progress = ProgressSegment::new().width(4);
+/// progress.clone().set(ProgressState::Normal(50));
+/// assert_eq!(progress.content().to_string(), "██░░  50%");
+///
+/// let mut terminal = Vec::new();
+/// assert!(progress.write_taskbar(&mut terminal)?);
+/// assert!(!progress.write_taskbar(&mut terminal)?);
+/// assert_eq!(terminal, b"\x1b]9;4;1;50\x1b\\");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProgressSegment {
+    state: Arc<Mutex<Progress>>,
+}
+
+#[derive(Debug)]
+struct Progress {
+    state: ProgressState,
+    width: u16,
+    reported: Option<ProgressState>,
+    thresholds: Option<Thresholds>,
+}
+
+impl Default for ProgressSegment {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Progress {
+                state: ProgressState::Hidden,
+                width: 10,
+                reported: None,
+                thresholds: None,
+            })),
+        }
+    }
+}
+
+impl ProgressSegment {
+    /// Creates a hidden progress segment with a bar 10 columns wide.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the width of the bar, without the percentage. A width of 0 shows only the percentage.
+    #[must_use]
+    pub fn width(self, width: u16) -> Self {
+        self.lock().width = width;
+        self
+    }
+
+    /// Styles the bar and percentage of running operations by their percentage, e.g. to show
+    /// disk usage in red when nearly full.
+    #[must_use]
+    pub fn thresholds(self, thresholds: Thresholds) -> Self {
+        self.lock().thresholds = Some(thresholds);
+        self
+    }
+
+    /// Sets the state of the operation. Percentages above 100 are shown as 100.
+    pub fn set(&self, state: ProgressState) {
+        self.lock().state = match state {
+            ProgressState::Normal(percent) => ProgressState::Normal(percent.min(100)),
+            ProgressState::Error(percent) => ProgressState::Error(percent.min(100)),
+            ProgressState::Paused(percent) => ProgressState::Paused(percent.min(100)),
+            state => state,
+        };
+    }
+
+    /// Returns the state of the operation.
+    #[must_use]
+    pub fn state(&self) -> ProgressState {
+        self.lock().state
+    }
+
+    /// Writes the state to the terminal with [OSC 9;4](osc::taskbar_progress) if it changed since
+    /// the last call and returns whether it did.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the underlying writer.
+    pub fn write_taskbar<W: Write>(&self, writer: &mut W) -> io::Result<bool> {
+        let mut progress = self.lock();
+        if progress.reported == Some(progress.state) {
+            return Ok(false);
+        }
+        writer.write_all(osc::taskbar_progress(progress.state).as_bytes())?;
+        writer.flush()?;
+        progress.reported = Some(progress.state);
+        Ok(true)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Progress> {
+        // a panic while holding the lock can't leave the state inconsistent
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Segment for ProgressSegment {
+    fn content(&mut self) -> Line<'static> {
+        let mut progress = self.lock();
+        let (percent, style) = match progress.state {
+            ProgressState::Hidden => return Line::default(),
+            ProgressState::Indeterminate => (None, Style::new()),
+            ProgressState::Normal(percent) => {
+                let style = progress
+                    .thresholds
+                    .as_mut()
+                    .map_or_else(Style::new, |thresholds| {
+                        thresholds.update(f64::from(percent))
+                    });
+                (Some(percent), style)
+            }
+            ProgressState::Error(percent) => (Some(percent), Style::new().red()),
+            ProgressState::Paused(percent) => (Some(percent), Style::new().yellow()),
+        };
+        let (full, empty) = if icon_set() == IconSet::Ascii {
+            ("#", "-")
+        } else {
+            ("█", "░")
+        };
+        let width = usize::from(progress.width);
+        let mut spans = Vec::new();
+        if let Some(percent) = percent {
+            let filled = width * usize::from(percent) / 100;
+            spans.push(Span::styled(full.repeat(filled), style));
+            spans.push(Span::raw(empty.repeat(width - filled)));
+            let percent = Humanize::new().precision(0).percent(f64::from(percent));
+            spans.push(Span::styled(format!(" {percent:>4}"), style));
+        } else {
+            spans.push(Span::raw(empty.repeat(width)));
+            spans.push(Span::raw("    …"));
+        }
+        if width == 0 {
+            spans.retain(|span| !span.content.is_empty());
+            if let Some(last) = spans.last_mut() {
+                last.content = last.content.trim_start().to_string().into();
+            }
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bar() {
+        let mut progress = ProgressSegment::new().width(5);
+        assert_eq!(progress.content(), Line::default());
+        progress.set(ProgressState::Error(200));
+        assert_eq!(progress.state(), ProgressState::Error(100));
+        assert_eq!(progress.content().to_string(), "█████ 100%");
+        progress.set(ProgressState::Paused(39));
+        assert_eq!(progress.content().to_string(), "█░░░░  39%");
+        progress.set(ProgressState::Indeterminate);
+        assert_eq!(progress.content().to_string(), "░░░░░    …");
+
+        let mut progress = ProgressSegment::new().width(0);
+        progress.set(ProgressState::Normal(7));
+        assert_eq!(progress.content().to_string(), "7%");
+    }
+
+    #[test]
+    fn thresholds() {
+        let mut progress = ProgressSegment::new().width(2).thresholds(
+            Thresholds::new()
+                .below(90.0, Style::new().green())
+                .otherwise(Style::new().red())
+                .hysteresis(5.0),
+        );
+        progress.set(ProgressState::Normal(50));
+        assert_eq!(
+            progress.content(),
+            Line::from(vec![
+                Span::styled("█", Style::new().green()),
+                Span::raw("░"),
+                Span::styled("  50%", Style::new().green()),
+            ])
+        );
+        progress.set(ProgressState::Normal(92));
+        assert_eq!(progress.content().spans[0].style, Style::new().green());
+        progress.set(ProgressState::Normal(96));
+        assert_eq!(progress.content().spans[0].style, Style::new().red());
+        progress.set(ProgressState::Paused(20));
+        assert_eq!(progress.content().spans[0].style, Style::new().yellow());
+    }
+}
@@ -0,0 +1,438 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "segments-clock")]
+use super::Clock;
+#[cfg(feature = "segments-git")]
+use super::GitBranch;
+use super::{Breadcrumbs, Segment};
+use crate::StatusBarError;
+
+/// Creates a [`Segment`] from its options.
+pub type SegmentFactory =
+    dyn Fn(&SegmentOptions) -> Result<Box<dyn Segment>, StatusBarError> + Send + Sync;
+
+/// A collection of [`Segment`] factories registered under names.
+///
+/// Configuration driven status bars (see [`config`](crate::config)) resolve segment names through
+/// a registry, so applications and third-party crates can make their own segments available to
+/// end users next to the built-in ones:
+///
+/// - `"breadcrumbs"`: [`Breadcrumbs`] for a `path`, with an optional `separator` and
+///   `max_width`.
+/// - `"clock"`: a `Clock` with an optional `format` (requires the `segments-clock` feature).
+/// - `"git_branch"` (or `"git"`): a `GitBranch` for an optional `path`, with a branch `icon`
+///   if set (requires the `segments-git` feature).
+/// - `"script"`: a [`Script`](super::Script) running `source` (requires the `scripting`
+///   feature).
+///
+/// # Examples
+/// ```
+/// use ratatui::text::Line;
+/// use ratatui_statusbar::segments::{Segment, SegmentOptions, SegmentRegistry};
+///
+/// struct Greeting(String);
+///
+/// impl Segment for Greeting {
+///     fn content(&mut self) -> Line<'static> {
+///         Line::from(format!("Hello, {}!", self.0))
+///     }
+/// }
+///
+/// let mut registry = SegmentRegistry::with_builtins();
+/// registry.register("greeting", |options| {
+///     let name = options.get_str("name")?.unwrap_or("world");
+///     Ok(Box::new(Greeting(name.to_string())))
+/// });
+/// let options = SegmentOptions::new().with("name", "ratatui");
+/// let mut segment = registry.create("greeting", &options)?;
+/// assert_eq!(segment.content(), Line::from("Hello, ratatui!"));
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+pub struct SegmentRegistry {
+    factories: HashMap<String, Box<SegmentFactory>>,
+}
+
+impl Default for SegmentRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+impl fmt::Debug for SegmentRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.names().collect::<Vec<_>>();
+        names.sort_unstable();
+        f.debug_struct("SegmentRegistry")
+            .field("names", &names)
+            .finish()
+    }
+}
+
+impl SegmentRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Creates a registry containing the built-in segments.
+    #[must_use]
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register("breadcrumbs", |options| {
+            let path = options.get_str("path")?.ok_or_else(|| {
+                StatusBarError::Config("segment `breadcrumbs` requires a `path`".to_string())
+            })?;
+            let mut breadcrumbs = Breadcrumbs::from_path(path);
+            if let Some(separator) = options.get_str("separator")? {
+                breadcrumbs =
+                    breadcrumbs
+                        .separator(crate::markup::parse_span_strict(separator).map_err(|err| {
+                            StatusBarError::Config(format!("`separator`: {err}"))
+                        })?);
+            }
+            if let Some(max_width) = options.get_i64("max_width")? {
+                let max_width = usize::try_from(max_width).map_err(|_| {
+                    StatusBarError::Config("`max_width` must not be negative".to_string())
+                })?;
+                breadcrumbs = breadcrumbs.max_width(max_width);
+            }
+            Ok(Box::new(breadcrumbs))
+        });
+        #[cfg(feature = "segments-clock")]
+        registry.register("clock", |options| {
+            let mut clock = Clock::new();
+            if let Some(format) = options.get_str("format")? {
+                clock = clock.format(format);
+            }
+            Ok(Box::new(clock))
+        });
+        #[cfg(feature = "segments-git")]
+        {
+            let git_branch =
+                |options: &SegmentOptions| -> Result<Box<dyn Segment>, StatusBarError> {
+                    Ok(Box::new(
+                        GitBranch::new(options.get_str("path")?.unwrap_or("."))
+                            .icon(options.get_bool("icon")?.unwrap_or(false)),
+                    ))
+                };
+            registry.register("git_branch", git_branch);
+            registry.register("git", git_branch);
+        }
+        #[cfg(feature = "scripting")]
+        registry.register("script", |options| {
+            let source = options.get_str("source")?.ok_or_else(|| {
+                StatusBarError::Config("segment `script` requires a `source`".to_string())
+            })?;
+            Ok(Box::new(super::Script::new(source)?))
+        });
+        registry
+    }
+
+    /// Registers a factory under `name`, replacing any factory previously registered under it.
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F) -> &mut Self
+    where
+        F: Fn(&SegmentOptions) -> Result<Box<dyn Segment>, StatusBarError> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.into(), Box::new(factory));
+        self
+    }
+
+    /// Returns whether a factory is registered under `name`.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Returns the names of all registered factories, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+
+    /// Creates the segment registered under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if no factory is registered under `name` or the factory
+    /// didn't read all options, which catches misspelled options, or any error returned by the
+    /// factory.
+    pub fn create(
+        &self,
+        name: &str,
+        options: &SegmentOptions,
+    ) -> Result<Box<dyn Segment>, StatusBarError> {
+        let factory = self
+            .factories
+            .get(name)
+            .ok_or_else(|| StatusBarError::Config(format!("unknown segment `{name}`")))?;
+        // each call tracks its reads in its own copy, so concurrent builds don't interfere
+        let options = options.tracked();
+        let segment = factory(&options)?;
+        let unused = options
+            .unread()
+            .map(|option| format!("`{option}`"))
+            .collect::<Vec<_>>();
+        if unused.is_empty() {
+            Ok(segment)
+        } else {
+            Err(StatusBarError::Config(format!(
+                "unknown options of segment `{name}`: {}",
+                unused.join(", ")
+            )))
+        }
+    }
+}
+
+/// The options passed to a [`SegmentFactory`].
+///
+/// [`SegmentRegistry::create`] passes the factory a copy that records which options it reads,
+/// to reject options the segment doesn't know.
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct SegmentOptions {
+    values: BTreeMap<String, OptionValue>,
+    /// Whether each of the values, in order, has been read, if reads are tracked.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    read: Option<Box<[AtomicBool]>>,
+}
+
+impl fmt::Debug for SegmentOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SegmentOptions").field(&self.values).finish()
+    }
+}
+
+impl Clone for SegmentOptions {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            read: None,
+        }
+    }
+}
+
+/// Options are equal if they have the same values, whichever of them have been read.
+impl PartialEq for SegmentOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+/// A segment option value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
+pub enum OptionValue {
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Integer(i64),
+    /// A floating point value.
+    Float(f64),
+    /// A string value.
+    String(String),
+}
+
+impl From<bool> for OptionValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for OptionValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for OptionValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<&str> for OptionValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for OptionValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl SegmentOptions {
+    /// Creates empty options.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets an option.
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<OptionValue>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Sets an option, returning the previous value.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<OptionValue>,
+    ) -> Option<OptionValue> {
+        self.values.insert(name.into(), value.into())
+    }
+
+    /// Returns an option, marking it as read.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&OptionValue> {
+        if let Some(read) = &self.read {
+            if let Some(index) = self.values.keys().position(|option| option == name) {
+                read[index].store(true, Ordering::Relaxed);
+            }
+        }
+        self.values.get(name)
+    }
+
+    /// Returns a copy of the options that records which of them are read.
+    fn tracked(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            read: Some(self.values.keys().map(|_| AtomicBool::new(false)).collect()),
+        }
+    }
+
+    /// Returns the names of the options that haven't been read from a [tracked](Self::tracked)
+    /// copy.
+    fn unread(&self) -> impl Iterator<Item = &str> {
+        self.values
+            .keys()
+            .zip(self.read.iter().flat_map(|read| read.iter()))
+            .filter(|(_, read)| !read.load(Ordering::Relaxed))
+            .map(|(option, _)| option.as_str())
+    }
+
+    /// Returns a string option.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if the option is not a string.
+    pub fn get_str(&self, name: &str) -> Result<Option<&str>, StatusBarError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(OptionValue::String(value)) => Ok(Some(value)),
+            Some(_) => Err(invalid_type(name, "a string")),
+        }
+    }
+
+    /// Returns a boolean option.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if the option is not a boolean.
+    pub fn get_bool(&self, name: &str) -> Result<Option<bool>, StatusBarError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(OptionValue::Bool(value)) => Ok(Some(*value)),
+            Some(_) => Err(invalid_type(name, "a boolean")),
+        }
+    }
+
+    /// Returns an integer option.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if the option is not an integer.
+    pub fn get_i64(&self, name: &str) -> Result<Option<i64>, StatusBarError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(OptionValue::Integer(value)) => Ok(Some(*value)),
+            Some(_) => Err(invalid_type(name, "an integer")),
+        }
+    }
+
+    /// Returns a floating point option, accepting integers as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Config`] if the option is not a number.
+    pub fn get_f64(&self, name: &str) -> Result<Option<f64>, StatusBarError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(OptionValue::Float(value)) => Ok(Some(*value)),
+            Some(OptionValue::Integer(value)) => Ok(Some(*value as f64)),
+            Some(_) => Err(invalid_type(name, "a number")),
+        }
+    }
+}
+
+fn invalid_type(name: &str, expected: &str) -> StatusBarError {
+    StatusBarError::Config(format!("option `{name}` must be {expected}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "segments-clock", feature = "segments-git"))]
+    #[test]
+    fn builtins_and_errors() {
+        let registry = SegmentRegistry::default();
+        assert!(registry.contains("clock"));
+        assert!(registry.contains("git_branch"));
+        assert!(matches!(
+            registry.create("weather", &SegmentOptions::new()),
+            Err(StatusBarError::Config(_))
+        ));
+        let options = SegmentOptions::new().with("format", 3_i64);
+        assert!(registry.create("clock", &options).is_err());
+        let options = SegmentOptions::new().with("formt", "%H").with("path", ".");
+        let error = registry
+            .create("clock", &options)
+            .err()
+            .map(|err| err.to_string());
+        assert_eq!(
+            error.as_deref(),
+            Some("Invalid configuration: unknown options of segment `clock`: `formt`, `path`")
+        );
+        assert!(registry
+            .create("git", &options.with("formt", false))
+            .is_err());
+
+        // builds of the same options on several threads track their reads separately
+        let options = SegmentOptions::new().with("format", "%H:%M");
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        assert!(registry.create("clock", &options).is_ok());
+                    }
+                });
+            }
+        });
+        assert!(SegmentRegistry::new().names().next().is_none());
+    }
+
+    #[test]
+    fn breadcrumbs() -> Result<(), StatusBarError> {
+        let registry = SegmentRegistry::default();
+        let options = SegmentOptions::new()
+            .with("path", "a/b/c")
+            .with("separator", "/")
+            .with("max_width", 4_i64);
+        let mut segment = registry.create("breadcrumbs", &options)?;
+        assert_eq!(segment.content().to_string(), "a/…/c");
+        let options = options.with("max_width", -1_i64);
+        assert!(registry.create("breadcrumbs", &options).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use ratatui::prelude::*;
+use rhai::{Dynamic, Engine, Map, Scope, AST};
+
+use super::Segment;
+use crate::{markup, StatusBarError};
+
+/// A [`Segment`] whose content is produced by a [Rhai](https://rhai.rs) script.
+///
+/// The script sees a `ctx` object map holding the values set with [`Script::set`], and the value
+/// of its last expression is shown as [`markup`]. A script that fails shows its error instead.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::segments::{Script, Segment};
+///
+/// let mut script = Script::new(
+///     r##"if ctx.modified { "#[fg=yellow]" + ctx.file + " +" } else { ctx.file }"##,
+/// )?;
+/// script.set("file", "main.rs").set("modified", true);
+/// assert_eq!(script.content().to_string(), "main.rs +");
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+#[derive(Debug)]
+pub struct Script {
+    engine: Engine,
+    ast: AST,
+    context: Map,
+}
+
+impl Script {
+    /// The maximum number of operations a script may run, which stops runaway loops from
+    /// freezing the application.
+    const MAX_OPERATIONS: u64 = 100_000;
+
+    /// Compiles a script.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Script`] if the script doesn't compile.
+    pub fn new(source: &str) -> Result<Self, StatusBarError> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(Self::MAX_OPERATIONS);
+        let ast = engine
+            .compile(source)
+            .map_err(|err| StatusBarError::Script(err.to_string()))?;
+        Ok(Self {
+            engine,
+            ast,
+            context: Map::new(),
+        })
+    }
+
+    /// Sets a value of the `ctx` object map.
+    pub fn set(&mut self, key: &str, value: impl Into<Dynamic>) -> &mut Self {
+        self.context.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the `ctx` object map.
+    pub fn context_mut(&mut self) -> &mut Map {
+        &mut self.context
+    }
+
+    /// Runs the script and parses its result as [`markup`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StatusBarError::Script`] if the script fails.
+    pub fn eval(&self) -> Result<Line<'static>, StatusBarError> {
+        let mut scope = Scope::new();
+        scope.push_constant("ctx", self.context.clone());
+        let result: Dynamic = self
+            .engine
+            .eval_ast_with_scope(&mut scope, &self.ast)
+            .map_err(|err| StatusBarError::Script(err.to_string()))?;
+        Ok(markup::parse(&result.to_string()))
+    }
+}
+
+impl Segment for Script {
+    fn content(&mut self) -> Line<'static> {
+        self.eval()
+            .unwrap_or_else(|err| Line::styled(err.to_string(), Style::new().red()))
+    }
+
+    fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+        self.eval()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_and_errors() -> Result<(), StatusBarError> {
+        let mut script = Script::new(r#"`#[bold]${ctx.count * 2}`"#)?;
+        script.set("count", 21_i64);
+        assert_eq!(
+            script.eval()?,
+            Line::from(Span::styled("42", Style::new().bold()))
+        );
+
+        assert!(matches!(Script::new("let"), Err(StatusBarError::Script(_))));
+        let mut runaway = Script::new("loop {}")?;
+        assert_eq!(runaway.content().style, Style::new().red());
+        Ok(())
+    }
+}
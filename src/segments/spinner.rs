@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use ratatui::text::Line;
+
+use super::Segment;
+use crate::icons::{icon_set, IconSet};
+
+/// A [`Segment`] cycling through animation frames, e.g. to show that work is in progress.
+///
+/// The spinner moves to the next frame every `interval` of time passed to [`Segment::advance`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spinner {
+    frames: Vec<String>,
+    interval: Duration,
+    index: usize,
+    elapsed: Duration,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        let frames: &[&str] = if icon_set() == IconSet::Ascii {
+            &["|", "/", "-", "\\"]
+        } else {
+            &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]
+        };
+        Self {
+            frames: frames.iter().map(|frame| (*frame).to_string()).collect(),
+            interval: Duration::from_millis(80),
+            index: 0,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+impl Spinner {
+    /// Creates a braille dot spinner advancing every 80ms, or a `|/-\\` spinner if the global
+    /// [`IconSet`] is [ASCII](IconSet::Ascii).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the animation frames.
+    #[must_use]
+    pub fn frames<I, S>(mut self, frames: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.frames = frames.into_iter().map(Into::into).collect();
+        self.index = 0;
+        self
+    }
+
+    /// Sets the time each frame is shown.
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl Segment for Spinner {
+    fn content(&mut self) -> Line<'static> {
+        self.frames
+            .get(self.index)
+            .map_or_else(Line::default, |frame| Line::from(frame.clone()))
+    }
+
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        if self.frames.len() < 2 || self.interval.is_zero() {
+            return false;
+        }
+        self.elapsed += elapsed;
+        let steps = self.elapsed.as_nanos() / self.interval.as_nanos();
+        self.elapsed = Duration::from_nanos(
+            u64::try_from(self.elapsed.as_nanos() % self.interval.as_nanos()).unwrap_or_default(),
+        );
+        let len = self.frames.len() as u128;
+        self.index = usize::try_from((self.index as u128 + steps) % len).unwrap_or_default();
+        steps > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_frames() {
+        let mut spinner = Spinner::new()
+            .frames(["a", "b", "c"])
+            .interval(Duration::from_millis(100));
+        assert!(!spinner.advance(Duration::from_millis(60)));
+        assert_eq!(spinner.content(), Line::from("a"));
+        assert!(spinner.advance(Duration::from_millis(60)));
+        assert_eq!(spinner.content(), Line::from("b"));
+        assert!(spinner.advance(Duration::from_millis(280)));
+        assert_eq!(spinner.content(), Line::from("b"));
+    }
+}
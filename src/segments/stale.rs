@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use ratatui::prelude::*;
+
+use super::Segment;
+use crate::format;
+
+/// How a [`Stale`] segment marks content that hasn't been updated for too long.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StaleIndicator {
+    /// Patches a style over the content, e.g. to dim it.
+    Style(Style),
+    /// Appends a marker such as `*` to the content.
+    Marker(Span<'static>),
+    /// Appends the time since the last successful update in a style, e.g. ` 3m 12s`, written
+    /// with [`format::duration`].
+    Age(Style),
+}
+
+impl Default for StaleIndicator {
+    fn default() -> Self {
+        Self::Style(Style::new().add_modifier(Modifier::DIM))
+    }
+}
+
+/// A [`Segment`] that marks the content of the wrapped segment once its last successful update
+/// is older than a threshold, so users can tell that a git, network or battery readout is no
+/// longer fresh.
+///
+/// An update is successful if [`Segment::try_content`] succeeds and the segment isn't
+/// [stale](Segment::is_stale) afterwards. This covers a [`Fallback`](super::Fallback) showing
+/// fallback content and a [`Threaded`](super::Threaded) segment whose background refresh hasn't
+/// finished. The age is measured by [`Segment::advance`]. If the wrapped segment fails, the
+/// content of its last successful update is shown with the indicator right away.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::segments::{Fallback, OnError, Segment, Stale, StaleIndicator};
+/// use ratatui_statusbar::StatusBarError;
+///
+/// struct Network(bool);
+///
+/// impl Segment for Network {
+///     fn content(&mut self) -> Line<'static> {
+///         self.try_content().unwrap_or_default()
+///     }
+///
+///     fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+///         match self.0 {
+///             true => Ok(Line::from("wlan0 up")),
+///             false => Err(StatusBarError::Segment("no connection".to_string())),
+///         }
+///     }
+/// }
+///
+/// let network = Fallback::new(Network(true), OnError::LastKnown(Span::raw("")));
+/// let mut network = Stale::new(network, Duration::from_secs(30))
+///     .indicator(StaleIndicator::Marker(Span::raw("*")));
+/// assert_eq!(network.content().to_string(), "wlan0 up");
+///
+/// network.get_mut().get_mut().0 = false;
+/// network.advance(Duration::from_secs(30));
+/// assert_eq!(network.content().to_string(), "wlan0 up*");
+/// ```
+#[derive(Debug)]
+pub struct Stale<S> {
+    segment: S,
+    threshold: Duration,
+    indicator: StaleIndicator,
+    age: Duration,
+    last: Line<'static>,
+    failed: bool,
+}
+
+impl<S: Segment> Stale<S> {
+    /// Wraps `segment`, marking its content once its last successful update is older than
+    /// `threshold`.
+    #[must_use]
+    pub fn new(segment: S, threshold: Duration) -> Self {
+        Self {
+            segment,
+            threshold,
+            indicator: StaleIndicator::default(),
+            age: Duration::ZERO,
+            last: Line::default(),
+            failed: false,
+        }
+    }
+
+    /// Sets how outdated content is marked, dimmed by default.
+    #[must_use]
+    pub fn indicator(mut self, indicator: StaleIndicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Returns the time since the last successful update.
+    pub const fn age(&self) -> Duration {
+        self.age
+    }
+
+    /// Returns the wrapped segment.
+    pub fn get_ref(&self) -> &S {
+        &self.segment
+    }
+
+    /// Returns the wrapped segment mutably.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.segment
+    }
+
+    fn is_outdated(&self) -> bool {
+        self.age >= self.threshold
+    }
+}
+
+impl<S: Segment> Segment for Stale<S> {
+    fn content(&mut self) -> Line<'static> {
+        let content = match self.segment.try_content() {
+            Ok(content) => {
+                if !self.segment.is_stale() {
+                    self.age = Duration::ZERO;
+                }
+                self.failed = false;
+                self.last = content.clone();
+                content
+            }
+            Err(_) => {
+                self.failed = true;
+                self.last.clone()
+            }
+        };
+        if !self.failed && !self.is_outdated() {
+            return content;
+        }
+        match &self.indicator {
+            StaleIndicator::Style(style) => content.patch_style(*style),
+            StaleIndicator::Marker(marker) => {
+                let mut content = content;
+                content.spans.push(marker.clone());
+                content
+            }
+            StaleIndicator::Age(style) => {
+                let mut content = content;
+                let age = format!(" {}", format::duration(self.age));
+                content.spans.push(Span::styled(age, *style));
+                content
+            }
+        }
+    }
+
+    /// Advances the wrapped segment and ages the last update.
+    ///
+    /// Returns whether the wrapped segment changed or the content became outdated.
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        let changed = self.segment.advance(elapsed);
+        let outdated = self.is_outdated();
+        self.age = self.age.saturating_add(elapsed);
+        changed || (!outdated && self.is_outdated())
+    }
+
+    /// Returns how long until the wrapped segment changes or the content becomes outdated,
+    /// whichever comes first.
+    fn until_change(&self) -> Option<Duration> {
+        let outdated = (!self.is_outdated()).then(|| self.threshold - self.age);
+        match (self.segment.until_change(), outdated) {
+            (Some(change), Some(outdated)) => Some(change.min(outdated)),
+            (change, outdated) => change.or(outdated),
+        }
+    }
+
+    fn is_stale(&self) -> bool {
+        self.failed || self.is_outdated() || self.segment.is_stale()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StatusBarError;
+
+    struct Source(bool);
+
+    impl Segment for Source {
+        fn content(&mut self) -> Line<'static> {
+            Line::from("42%")
+        }
+
+        fn is_stale(&self) -> bool {
+            self.0
+        }
+    }
+
+    #[test]
+    fn marks_outdated_content() {
+        let mut stale = Stale::new(Source(false), Duration::from_secs(10));
+        assert!(!stale.advance(Duration::from_secs(9)));
+        assert_eq!(stale.until_change(), Some(Duration::from_secs(1)));
+        assert_eq!(stale.content(), Line::from("42%"));
+        assert_eq!(stale.age(), Duration::ZERO);
+
+        stale.get_mut().0 = true;
+        assert_eq!(stale.content(), Line::from("42%"));
+        assert!(stale.advance(Duration::from_secs(10)));
+        assert!(!stale.advance(Duration::from_secs(1)));
+        assert_eq!(stale.until_change(), None);
+        assert_eq!(stale.content(), Line::from("42%").dim());
+
+        stale.get_mut().0 = false;
+        assert_eq!(stale.content(), Line::from("42%"));
+        assert!(!stale.is_stale());
+    }
+
+    struct Flaky(Option<&'static str>);
+
+    impl Segment for Flaky {
+        fn content(&mut self) -> Line<'static> {
+            self.try_content().unwrap_or_default()
+        }
+
+        fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+            self.0
+                .map(Line::from)
+                .ok_or_else(|| StatusBarError::Segment("offline".to_string()))
+        }
+    }
+
+    #[test]
+    fn keeps_last_content_on_error() {
+        let mut stale = Stale::new(Flaky(Some("wlan0 up")), Duration::from_secs(30))
+            .indicator(StaleIndicator::Marker(Span::raw("*")));
+        assert_eq!(stale.content().to_string(), "wlan0 up");
+
+        stale.get_mut().0 = None;
+        assert_eq!(stale.content().to_string(), "wlan0 up*");
+        assert!(stale.is_stale());
+
+        stale.get_mut().0 = Some("wlan0 down");
+        assert_eq!(stale.content().to_string(), "wlan0 down");
+        assert!(!stale.is_stale());
+    }
+
+    #[test]
+    fn shows_age() {
+        let mut stale = Stale::new(Source(true), Duration::from_secs(60))
+            .indicator(StaleIndicator::Age(Style::new().dim()));
+        stale.advance(Duration::from_secs(192));
+        assert_eq!(
+            stale.content(),
+            Line::from(vec![Span::raw("42%"), Span::raw(" 3m 12s").dim()])
+        );
+    }
+}
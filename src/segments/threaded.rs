@@ -0,0 +1,239 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use ratatui::prelude::*;
+
+use super::Segment;
+use crate::StatusBarError;
+
+/// A [`Segment`] that refreshes a slow segment, such as [`GitBranch`], on a background thread and
+/// waits at most a timeout for it.
+///
+/// A refresh that takes longer than the timeout keeps running in the background while the
+/// previous content is shown in the stale style, dimmed by default, so building the status bar
+/// never blocks on I/O for longer than the timeout. The content of a late refresh is picked up by
+/// the next call to [`Segment::content`], or by [`Segment::advance`], which then asks for a
+/// redraw.
+///
+/// Errors of the slow segment are returned by [`Segment::try_content`], while
+/// [`Segment::content`] keeps showing the previous content in the stale style. Wrap the
+/// [`Threaded`] segment in a [`Fallback`](super::Fallback) to show something else instead.
+///
+/// [`GitBranch`]: super::GitBranch
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use ratatui_statusbar::segments::{GitBranch, Segment, Threaded};
+///
+/// let mut branch = Threaded::new(GitBranch::new("."), Duration::from_millis(10));
+/// // on every frame, waiting at most 10ms for git
+/// let content = branch.content();
+/// if branch.is_stale() {
+///     // git is still running, `content` is the previous branch
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Threaded {
+    requests: Sender<Request>,
+    replies: Receiver<Result<Line<'static>, StatusBarError>>,
+    timeout: Duration,
+    pending: bool,
+    content: Option<Line<'static>>,
+    failed: bool,
+    error: Option<StatusBarError>,
+    stale_style: Style,
+}
+
+#[derive(Debug)]
+enum Request {
+    Refresh,
+    Advance(Duration),
+}
+
+impl Threaded {
+    /// Moves `segment` to a background thread and waits at most `timeout` for each refresh.
+    ///
+    /// The thread stops when the [`Threaded`] segment is dropped.
+    #[must_use]
+    pub fn new<S: Segment + Send + 'static>(mut segment: S, timeout: Duration) -> Self {
+        let (requests, worker_requests) = mpsc::channel();
+        let (worker_replies, replies) = mpsc::channel();
+        thread::spawn(move || {
+            for request in worker_requests {
+                match request {
+                    Request::Refresh => {
+                        if worker_replies.send(segment.try_content()).is_err() {
+                            break;
+                        }
+                    }
+                    Request::Advance(elapsed) => {
+                        segment.advance(elapsed);
+                    }
+                }
+            }
+        });
+        Self {
+            requests,
+            replies,
+            timeout,
+            pending: false,
+            content: None,
+            failed: false,
+            error: None,
+            stale_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Sets the style patched over the previous content while a refresh takes longer than the
+    /// timeout.
+    #[must_use]
+    pub const fn stale_style(mut self, style: Style) -> Self {
+        self.stale_style = style;
+        self
+    }
+
+    /// Returns whether the last refresh took longer than the timeout and is still running, or
+    /// failed.
+    pub const fn is_stale(&self) -> bool {
+        self.pending || self.failed
+    }
+
+    fn receive(&mut self, reply: Result<Line<'static>, StatusBarError>) {
+        match reply {
+            Ok(content) => {
+                self.content = Some(content);
+                self.failed = false;
+                self.error = None;
+            }
+            Err(error) => {
+                self.failed = true;
+                self.error = Some(error);
+            }
+        }
+        self.pending = false;
+    }
+
+    /// Picks up a late refresh, or starts a new one and waits at most the timeout for it.
+    fn refresh(&mut self) {
+        if self.pending {
+            if let Ok(reply) = self.replies.try_recv() {
+                self.receive(reply);
+            }
+        }
+        if !self.pending && self.requests.send(Request::Refresh).is_ok() {
+            self.pending = true;
+            if let Ok(reply) = self.replies.recv_timeout(self.timeout) {
+                self.receive(reply);
+            }
+        }
+    }
+
+    fn shown(&self) -> Line<'static> {
+        let content = self.content.clone().unwrap_or_default();
+        if self.is_stale() {
+            content.patch_style(self.stale_style)
+        } else {
+            content
+        }
+    }
+}
+
+impl Segment for Threaded {
+    fn content(&mut self) -> Line<'static> {
+        self.refresh();
+        self.shown()
+    }
+
+    /// Returns the error of the last refresh if it failed, otherwise like
+    /// [`content`](Segment::content).
+    fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+        self.refresh();
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(self.shown()),
+        }
+    }
+
+    /// Advances the segment on the background thread and picks up the content of a late
+    /// refresh.
+    ///
+    /// Returns whether a late refresh finished, since the segment's own answer isn't waited for.
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        let _ = self.requests.send(Request::Advance(elapsed));
+        if self.pending {
+            if let Ok(reply) = self.replies.try_recv() {
+                self.receive(reply);
+                return true;
+            }
+        }
+        false
+    }
+
+    fn is_stale(&self) -> bool {
+        Self::is_stale(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Produces the contents sent by the test, blocking until the next one arrives.
+    struct Gated(Receiver<Result<&'static str, &'static str>>);
+
+    impl Segment for Gated {
+        fn content(&mut self) -> Line<'static> {
+            self.try_content().unwrap_or_default()
+        }
+
+        fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+            match self.0.recv() {
+                Ok(Ok(content)) => Ok(Line::from(content)),
+                Ok(Err(error)) => Err(StatusBarError::Segment(error.to_string())),
+                Err(_) => Ok(Line::default()),
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_stale_content() {
+        let (contents, gate) = mpsc::channel();
+        let mut threaded = Threaded::new(Gated(gate), Duration::from_secs(5));
+        contents.send(Ok("1")).unwrap();
+        assert_eq!(threaded.content(), Line::from("1"));
+        assert!(!threaded.is_stale());
+
+        let mut threaded = threaded.stale_style(Style::new().italic());
+        threaded.timeout = Duration::ZERO;
+        assert_eq!(threaded.content(), Line::from("1").italic());
+        assert!(threaded.is_stale());
+
+        contents.send(Ok("2")).unwrap();
+        while !threaded.advance(Duration::ZERO) {
+            thread::yield_now();
+        }
+        assert!(!threaded.is_stale());
+        assert_eq!(threaded.content, Some(Line::from("2")));
+    }
+
+    #[test]
+    fn forwards_errors() {
+        let (contents, gate) = mpsc::channel();
+        let mut threaded = Threaded::new(Gated(gate), Duration::from_secs(5));
+        contents.send(Ok("main")).unwrap();
+        assert_eq!(threaded.try_content().ok(), Some(Line::from("main")));
+
+        contents.send(Err("not a repository")).unwrap();
+        assert!(matches!(
+            threaded.try_content(),
+            Err(StatusBarError::Segment(message)) if message == "not a repository"
+        ));
+        assert!(threaded.is_stale());
+
+        contents.send(Err("not a repository")).unwrap();
+        assert_eq!(threaded.content(), Line::from("main").dim());
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ratatui::text::Line;
+
+use super::Segment;
+use crate::StatusBarError;
+
+/// A [`Segment`] that measures how long the wrapped segment takes to refresh its content, to find
+/// out which segment slows down a TUI.
+///
+/// The duration of the last refresh is returned by [`Timed::last_refresh`] and passed to the
+/// [hook](Timed::on_refresh) along with the name of the segment. With the `tracing` feature, every
+/// refresh is also recorded in a `segment_refresh` span with the name of the segment. The render
+/// time of the whole bar is reported by [`StatusBar::on_render`](crate::StatusBar::on_render).
+///
+/// Refreshes are timed with [`Instant`] unless another [clock](Timed::clock) is set, e.g.
+/// `performance.now()` on the web, where [`Instant`] isn't available, or a simulated clock in
+/// tests.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::segments::{GitBranch, Segment, Timed};
+///
+/// let mut branch = Timed::new("git", GitBranch::new(".")).on_refresh(|name, elapsed| {
+///     if elapsed.as_millis() > 16 {
+///         eprintln!("{name} took {elapsed:?}");
+///     }
+/// });
+/// branch.content();
+/// assert!(branch.last_refresh().is_some());
+/// ```
+pub struct Timed<S> {
+    name: String,
+    segment: S,
+    last_refresh: Option<Duration>,
+    hook: Option<RefreshHook>,
+    clock: Option<MonotonicClock>,
+}
+
+type RefreshHook = Arc<dyn Fn(&str, Duration) + Send + Sync>;
+type MonotonicClock = Arc<dyn Fn() -> Duration + Send + Sync>;
+
+impl<S: fmt::Debug> fmt::Debug for Timed<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timed")
+            .field("name", &self.name)
+            .field("segment", &self.segment)
+            .field("last_refresh", &self.last_refresh)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Segment> Timed<S> {
+    /// Measures the refreshes of `segment`, reporting them under `name`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, segment: S) -> Self {
+        Self {
+            name: name.into(),
+            segment,
+            last_refresh: None,
+            hook: None,
+            clock: None,
+        }
+    }
+
+    /// Calls `hook` with the name of the segment and the duration of every refresh.
+    #[must_use]
+    pub fn on_refresh<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str, Duration) + Send + Sync + 'static,
+    {
+        self.hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets a monotonic clock, returning the time since any fixed point, to time refreshes with
+    /// instead of [`Instant`]. The clock may capture state such as a simulated clock.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// use ratatui_statusbar::segments::{Clock, Segment, Timed};
+    ///
+    /// let ticks = Arc::new(AtomicU64::new(0));
+    /// let mut clock = Timed::new("clock", Clock::new()).clock({
+    ///     let ticks = ticks.clone();
+    ///     move || Duration::from_millis(ticks.fetch_add(3, Ordering::Relaxed))
+    /// });
+    /// clock.content();
+    /// assert_eq!(clock.last_refresh(), Some(Duration::from_millis(3)));
+    /// ```
+    #[must_use]
+    pub fn clock<F>(mut self, clock: F) -> Self
+    where
+        F: Fn() -> Duration + Send + Sync + 'static,
+    {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Returns how long the last refresh took, or `None` before the first refresh.
+    pub const fn last_refresh(&self) -> Option<Duration> {
+        self.last_refresh
+    }
+
+    /// Returns the wrapped segment.
+    pub fn get_ref(&self) -> &S {
+        &self.segment
+    }
+
+    /// Returns the wrapped segment mutably.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.segment
+    }
+
+    fn measure<T>(&mut self, refresh: impl FnOnce(&mut S) -> T) -> T {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("segment_refresh", name = %self.name).entered();
+        let elapsed;
+        let result = if let Some(clock) = &self.clock {
+            let start = clock();
+            let result = refresh(&mut self.segment);
+            elapsed = clock().saturating_sub(start);
+            result
+        } else {
+            let start = Instant::now();
+            let result = refresh(&mut self.segment);
+            elapsed = start.elapsed();
+            result
+        };
+        self.last_refresh = Some(elapsed);
+        if let Some(hook) = &self.hook {
+            hook(&self.name, elapsed);
+        }
+        result
+    }
+}
+
+impl<S: Segment> Segment for Timed<S> {
+    fn content(&mut self) -> Line<'static> {
+        self.measure(S::content)
+    }
+
+    fn try_content(&mut self) -> Result<Line<'static>, StatusBarError> {
+        self.measure(S::try_content)
+    }
+
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        self.segment.advance(elapsed)
+    }
+
+    fn until_change(&self) -> Option<Duration> {
+        self.segment.until_change()
+    }
+
+    fn is_stale(&self) -> bool {
+        self.segment.is_stale()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::thread;
+
+    use super::*;
+
+    struct Slow;
+
+    impl Segment for Slow {
+        fn content(&mut self) -> Line<'static> {
+            thread::sleep(Duration::from_millis(5));
+            Line::from("slow")
+        }
+    }
+
+    #[test]
+    fn reports_refreshes() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let mut timed = Timed::new("slow", Slow).on_refresh({
+            let reports = reports.clone();
+            move |name, elapsed| reports.lock().unwrap().push((name.to_string(), elapsed))
+        });
+        assert_eq!(timed.last_refresh(), None);
+        assert_eq!(timed.content(), Line::from("slow"));
+        let elapsed = timed.last_refresh().unwrap();
+        assert!(elapsed >= Duration::from_millis(5));
+        assert_eq!(*reports.lock().unwrap(), [("slow".to_string(), elapsed)]);
+    }
+}
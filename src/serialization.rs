@@ -0,0 +1,587 @@
+//! [`serde`] support for [`StatusBar`] and [`StatusBarSection`].
+//!
+//! Content and separators are written as [`markup`](crate::markup) strings and styles as markup
+//! attribute lists (`"fg=red,bold"`) so that styled content stays readable in configuration
+//! files. A section can also be deserialized from a single markup string, which is shorthand for
+//! a section with only content. Multi-line content is written as one string with a line break
+//! between lines. Malformed markup, such as a misspelled attribute, fails deserialization instead
+//! of being ignored.
+
+use ratatui::layout::Flex;
+use ratatui::style::Style;
+use ratatui::text::{Line, Text};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::events::Action;
+use crate::{
+    markup, Breakpoint, ColorDepth, Role, SeparatorMerge, SeparatorStyle, StatusBar,
+    StatusBarError, StatusBarSection, Theme, Truncation,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SectionDef {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pre_separator: Option<String>,
+    #[serde(default)]
+    content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    short_content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    post_separator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hyperlink: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    action: Option<Action>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    style: Option<Style>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    hidden: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    truncation: Truncation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_width: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_width: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gap_after: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    group: Option<Box<StatusBarDef>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tooltip: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pages: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pinned: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    optional: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    role: Option<Role>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    gap_weight: u16,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SectionRepr {
+    Content(String),
+    Section(Box<SectionDef>),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StatusBarDef {
+    #[serde(default)]
+    sections: Vec<SectionRepr>,
+    #[serde(default, with = "flex")]
+    flex: Flex,
+    #[serde(default = "default_spacing")]
+    spacing: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    style: Option<Style>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    separator_style: SeparatorStyle,
+    #[serde(default, skip_serializing_if = "is_default")]
+    separator_merge: SeparatorMerge,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    inactive: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    inactive_style: Option<Style>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    right_to_left: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    compact: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    fill_from_end: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    ascii: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    color_depth: ColorDepth,
+    #[serde(default, skip_serializing_if = "is_default")]
+    scrollable: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    scroll_indicators: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    overflow_indicator: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    theme: Option<ThemeDef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    breakpoints: Vec<BreakpointDef>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BreakpointDef {
+    max_width: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    spacing: Option<u16>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    short: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    hide: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeDef {
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    bar: Option<Style>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    accent: Option<Style>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    muted: Option<Style>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    info: Option<Style>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    warning: Option<Style>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    error: Option<Style>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "style")]
+    inactive: Option<Style>,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+const fn default_spacing() -> u16 {
+    1
+}
+
+fn non_default(style: Style) -> Option<Style> {
+    (style != Style::default()).then_some(style)
+}
+
+impl From<&StatusBarSection<'_>> for SectionDef {
+    fn from(section: &StatusBarSection<'_>) -> Self {
+        Self {
+            name: section.name.clone(),
+            pre_separator: section.pre_separator.as_ref().map(markup::span_to_markup),
+            content: std::iter::once(&section.content)
+                .chain(&section.continuation)
+                .map(markup::to_markup)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            short_content: section.short_content.as_ref().map(markup::to_markup),
+            post_separator: section.post_separator.as_ref().map(markup::span_to_markup),
+            hyperlink: section.hyperlink.clone(),
+            action: section.action.clone(),
+            style: non_default(section.style),
+            hidden: section.hidden,
+            truncation: section.truncation,
+            min_width: section.min_width,
+            max_width: section.max_width,
+            gap_after: section.gap_after,
+            group: section
+                .group
+                .as_deref()
+                .map(|group| Box::new(StatusBarDef::from(group))),
+            tooltip: section.tooltip.as_ref().map(|tooltip| {
+                tooltip
+                    .lines
+                    .iter()
+                    .map(markup::to_markup)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }),
+            pages: section.pages.iter().map(markup::to_markup).collect(),
+            pinned: section.pinned,
+            optional: section.optional,
+            role: section.role,
+            gap_weight: section.gap_weight,
+        }
+    }
+}
+
+/// Parses each line of multi-line markup.
+fn parse_lines(markup: &str) -> Result<Vec<Line<'static>>, StatusBarError> {
+    markup.split('\n').map(markup::parse_strict).collect()
+}
+
+impl TryFrom<SectionDef> for StatusBarSection<'_> {
+    type Error = StatusBarError;
+
+    fn try_from(def: SectionDef) -> Result<Self, Self::Error> {
+        let mut lines = parse_lines(&def.content)?.into_iter();
+        Ok(Self {
+            pre_separator: def
+                .pre_separator
+                .as_deref()
+                .map(markup::parse_span_strict)
+                .transpose()?,
+            content: lines.next().unwrap_or_default(),
+            continuation: lines.collect(),
+            post_separator: def
+                .post_separator
+                .as_deref()
+                .map(markup::parse_span_strict)
+                .transpose()?,
+            hyperlink: def.hyperlink,
+            action: def.action,
+            style: def.style.unwrap_or_default(),
+            name: def.name,
+            hidden: def.hidden,
+            visible_when: None,
+            short_content: def
+                .short_content
+                .as_deref()
+                .map(markup::parse_strict)
+                .transpose()?,
+            truncation: def.truncation,
+            truncator: None,
+            min_width: def.min_width,
+            max_width: def.max_width,
+            gap_after: def.gap_after,
+            group: def
+                .group
+                .map(|group| StatusBar::try_from(*group).map(Box::new))
+                .transpose()?,
+            widget: None,
+            data: None,
+            tooltip: def
+                .tooltip
+                .as_deref()
+                .map(parse_lines)
+                .transpose()?
+                .map(Text::from),
+            pages: def
+                .pages
+                .iter()
+                .map(|page| markup::parse_strict(page))
+                .collect::<Result<_, _>>()?,
+            pinned: def.pinned,
+            optional: def.optional,
+            role: def.role,
+            gap_weight: def.gap_weight,
+            transition: None,
+        })
+    }
+}
+
+impl TryFrom<SectionRepr> for StatusBarSection<'_> {
+    type Error = StatusBarError;
+
+    fn try_from(repr: SectionRepr) -> Result<Self, Self::Error> {
+        match repr {
+            SectionRepr::Content(content) => Ok(Self::default().text(parse_lines(&content)?)),
+            SectionRepr::Section(def) => (*def).try_into(),
+        }
+    }
+}
+
+impl Serialize for StatusBarSection<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SectionDef::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusBarSection<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SectionRepr::deserialize(deserializer)?
+            .try_into()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl From<Theme> for ThemeDef {
+    fn from(theme: Theme) -> Self {
+        Self {
+            bar: non_default(theme.bar),
+            accent: non_default(theme.accent),
+            muted: non_default(theme.muted),
+            info: non_default(theme.info),
+            warning: non_default(theme.warning),
+            error: non_default(theme.error),
+            inactive: non_default(theme.inactive),
+        }
+    }
+}
+
+impl From<ThemeDef> for Theme {
+    fn from(def: ThemeDef) -> Self {
+        Self {
+            bar: def.bar.unwrap_or_default(),
+            accent: def.accent.unwrap_or_default(),
+            muted: def.muted.unwrap_or_default(),
+            info: def.info.unwrap_or_default(),
+            warning: def.warning.unwrap_or_default(),
+            error: def.error.unwrap_or_default(),
+            inactive: def.inactive.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&StatusBar<'_>> for StatusBarDef {
+    fn from(bar: &StatusBar<'_>) -> Self {
+        Self {
+            sections: bar
+                .sections
+                .iter()
+                .map(|section| SectionRepr::Section(Box::new(section.into())))
+                .collect(),
+            flex: bar.flex,
+            spacing: bar.spacing,
+            style: non_default(bar.style),
+            separator_style: bar.separator_style,
+            separator_merge: bar.separator_merge,
+            inactive: bar.inactive,
+            inactive_style: bar.inactive_style,
+            right_to_left: bar.right_to_left,
+            compact: bar.compact,
+            fill_from_end: bar.fill_from_end,
+            ascii: bar.ascii,
+            color_depth: bar.color_depth,
+            scrollable: bar.scrollable,
+            scroll_indicators: bar.scroll_indicators,
+            overflow_indicator: bar.overflow_indicator,
+            theme: bar.theme.map(ThemeDef::from),
+            breakpoints: bar
+                .breakpoints
+                .iter()
+                .map(|(max_width, breakpoint)| BreakpointDef {
+                    max_width: *max_width,
+                    spacing: breakpoint.spacing,
+                    short: breakpoint.short,
+                    hide: breakpoint.hidden.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<StatusBarDef> for StatusBar<'_> {
+    type Error = StatusBarError;
+
+    fn try_from(def: StatusBarDef) -> Result<Self, Self::Error> {
+        let status_bar = Self {
+            sections: def
+                .sections
+                .into_iter()
+                .map(StatusBarSection::try_from)
+                .collect::<Result<_, _>>()?,
+            flex: def.flex,
+            spacing: def.spacing,
+            style: def.style.unwrap_or_default(),
+            separator_style: def.separator_style,
+            separator_merge: def.separator_merge,
+            theme: def.theme.map(Theme::from),
+            inactive: def.inactive,
+            inactive_style: def.inactive_style,
+            breakpoints: Vec::new(),
+            right_to_left: def.right_to_left,
+            compact: def.compact,
+            fill_from_end: def.fill_from_end,
+            ascii: def.ascii,
+            color_depth: def.color_depth,
+            scrollable: def.scrollable,
+            scroll_indicators: def.scroll_indicators,
+            overflow_indicator: def.overflow_indicator,
+            on_render: None,
+        };
+        Ok(def
+            .breakpoints
+            .into_iter()
+            .fold(status_bar, |status_bar, breakpoint| {
+                status_bar.breakpoint(
+                    breakpoint.max_width,
+                    Breakpoint {
+                        spacing: breakpoint.spacing,
+                        short: breakpoint.short,
+                        hidden: breakpoint.hide,
+                    },
+                )
+            }))
+    }
+}
+
+impl Serialize for StatusBar<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StatusBarDef::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusBar<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        StatusBarDef::deserialize(deserializer)?
+            .try_into()
+            .map_err(D::Error::custom)
+    }
+}
+
+pub(crate) mod flex {
+    use ratatui::layout::Flex;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(flex: &Flex, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(flex)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Flex, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse()
+            .map_err(|_| D::Error::custom(format!("unknown flex `{name}`")))
+    }
+}
+
+/// Serializes optional styles as [`markup`] attribute lists.
+pub(crate) mod style {
+    use ratatui::style::Style;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::markup;
+
+    pub fn serialize<S: Serializer>(
+        style: &Option<Style>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match style {
+            Some(style) => serializer.serialize_str(&markup::style_to_markup(*style)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Style>, D::Error> {
+        let attributes = Option::<String>::deserialize(deserializer)?;
+        attributes
+            .as_deref()
+            .map(markup::parse_style_strict)
+            .transpose()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() -> color_eyre::Result<()> {
+        let status_bar = StatusBar::new(2)
+            .flex(Flex::SpaceBetween)
+            .spacing(2u16)
+            .on_black()
+            .section(0, Span::styled("NORMAL", Style::new().bold()))?
+            .section(
+                1,
+                StatusBarSection::default()
+                    .pre_separator(" | ")
+                    .content("main #1")
+                    .yellow(),
+            )?;
+        let json = serde_json::to_string(&status_bar)?;
+        assert_eq!(
+            json,
+            r##"{"sections":[{"content":"#[default,bold]NORMAL"},{"pre_separator":" | ","content":"main ##1","style":"default,fg=Yellow"}],"flex":"SpaceBetween","spacing":2,"style":"default,bg=Black"}"##
+        );
+        let deserialized: StatusBar = serde_json::from_str(&json)?;
+        assert_eq!(serde_json::to_string(&deserialized)?, json);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_theme() -> color_eyre::Result<()> {
+        let status_bar = StatusBar::new(2)
+            .theme(Theme::light())
+            .section(0, StatusBarSection::from("NORMAL").role(Role::Accent))?
+            .section(1, "main")?;
+        let json = serde_json::to_string(&status_bar)?;
+        let deserialized: StatusBar = serde_json::from_str(&json)?;
+        assert_eq!(deserialized.theme, Some(Theme::light()));
+        let render = |status_bar: &StatusBar| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+            Widget::render(status_bar, buf.area, &mut buf);
+            buf
+        };
+        assert_eq!(render(&deserialized), render(&status_bar));
+        assert_eq!(
+            render(&deserialized).get(0, 0).bg,
+            Theme::light().accent.bg.unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_inactive() -> color_eyre::Result<()> {
+        let status_bar = StatusBar::new(1)
+            .inactive(true)
+            .inactive_style(Style::new().dim())
+            .section(0, "main")?;
+        let json = serde_json::to_string(&status_bar)?;
+        assert!(json.contains(r#""inactive":true"#));
+        let deserialized: StatusBar = serde_json::from_str(&json)?;
+        assert!(deserialized.inactive);
+        assert_eq!(serde_json::to_string(&deserialized)?, json);
+        let active: StatusBar = serde_json::from_str(r#"{"sections":["main"]}"#)?;
+        assert!(!active.inactive);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_breakpoints() -> color_eyre::Result<()> {
+        let status_bar = StatusBar::new(2)
+            .section(0, StatusBarSection::from("NORMAL").short_content("N"))?
+            .section(1, StatusBarSection::from("main").name("git"))?
+            .breakpoint(12, Breakpoint::new().short(true))
+            .breakpoint(6, Breakpoint::new().short(true).spacing(0).hide("git"));
+        let json = serde_json::to_string(&status_bar)?;
+        assert!(json.contains(
+            r#""breakpoints":[{"max_width":6,"spacing":0,"short":true,"hide":["git"]},{"max_width":12,"short":true}]"#
+        ));
+        let deserialized: StatusBar = serde_json::from_str(&json)?;
+        assert_eq!(deserialized.breakpoints, status_bar.breakpoints);
+        for width in [4, 10, 16] {
+            let render = |status_bar: &StatusBar| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+                Widget::render(status_bar, buf.area, &mut buf);
+                buf
+            };
+            assert_eq!(render(&deserialized), render(&status_bar));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_markup() {
+        for json in [
+            r##"{"sections":["#[fg=red,blod]main"]}"##,
+            r##"{"sections":[{"content":"main","pre_separator":"#[bold"}]}"##,
+            r#"{"sections":[],"style":"fg=grene"}"#,
+        ] {
+            assert!(serde_json::from_str::<StatusBar>(json).is_err(), "{json}");
+        }
+        let error = serde_json::from_str::<StatusBarSection>(r#"{"content":"a # b"}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unescaped `#`"), "{error}");
+    }
+
+    #[test]
+    fn deserialize_shorthand() -> color_eyre::Result<()> {
+        let status_bar: StatusBar =
+            serde_json::from_str(r#"{"sections":["hello","world\nagain"]}"#)?;
+        assert_eq!(status_bar.sections.len(), 2);
+        assert_eq!(status_bar.sections[1].content, Line::from("world"));
+        assert_eq!(status_bar.sections[1].continuation, [Line::from("again")]);
+        assert_eq!(status_bar.height(), 2);
+        assert_eq!(status_bar.spacing, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn groups() -> color_eyre::Result<()> {
+        let json = r#"{"sections":[{"group":{"sections":["main","+1"],"spacing":0}},"utf-8"]}"#;
+        let status_bar: StatusBar = serde_json::from_str(json)?;
+        assert_eq!(status_bar.desired_width(), 12);
+        assert_eq!(
+            serde_json::to_string(&status_bar)?,
+            r#"{"sections":[{"content":"","group":{"sections":[{"content":"main"},{"content":"+1"}],"flex":"Start","spacing":0}},{"content":"utf-8"}],"flex":"Start","spacing":1}"#
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,467 @@
+//! Time-based state of a [`StatusBar`](crate::StatusBar).
+
+use std::time::Duration;
+
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::color::blend;
+#[cfg(feature = "segments")]
+use crate::segments::Segment;
+use crate::tween::progress;
+use crate::Transition;
+
+/// The state of a [`StatusBar`](crate::StatusBar) rendered as a
+/// [`StatefulWidget`](ratatui::widgets::StatefulWidget).
+///
+/// The state holds everything that changes over time independently of the sections, such as
+/// flashes. Time only passes when the application calls [`StatusBarState::advance`], or
+/// [`StatusBarState::advance_with`] to drive animated [segments](crate::segments) as well, which
+/// keeps rendering deterministic and lets a single timer drive all animations.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::{StatusBar, StatusBarState};
+///
+/// let status_bar = StatusBar::new(2).section(1, "copied!").unwrap();
+/// let mut state = StatusBarState::default();
+/// state.flash(1, Style::new().black().on_green(), Duration::from_secs(1));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+/// StatefulWidget::render(status_bar, buf.area, &mut buf, &mut state);
+///
+/// // On every tick of the event loop:
+/// if state.advance(Duration::from_millis(100)) {
+///     // redraw
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct StatusBarState {
+    flashes: Vec<Flash>,
+    tooltip: Option<usize>,
+    scroll: usize,
+    pan: i32,
+    drag: Option<(usize, u16)>,
+    pagers: Vec<Pager>,
+    transitions: Vec<ContentTransition>,
+    toggled: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct ContentTransition {
+    index: usize,
+    shown: Line<'static>,
+    previous: Option<Line<'static>>,
+    transition: Transition,
+    duration: Duration,
+    elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+struct Pager {
+    index: usize,
+    page: usize,
+    interval: Option<Duration>,
+    elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+struct Flash {
+    index: usize,
+    style: Style,
+    duration: Duration,
+    elapsed: Duration,
+}
+
+impl StatusBarState {
+    /// Creates a new [`StatusBarState`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flashes the section at `index` with `style` for `duration`.
+    ///
+    /// The style is patched over the style of the section. During the second half of `duration`
+    /// its colors fade back to the colors of the section, provided both have an RGB value. A new
+    /// flash of the same section replaces the previous one, a flash of zero duration only ends it.
+    pub fn flash(&mut self, index: usize, style: impl Into<Style>, duration: Duration) {
+        self.flashes.retain(|flash| flash.index != index);
+        if duration.is_zero() {
+            return;
+        }
+        self.flashes.push(Flash {
+            index,
+            style: style.into(),
+            duration,
+            elapsed: Duration::ZERO,
+        });
+    }
+
+    /// Shows the [tooltip](crate::StatusBar::tooltip) of the section at `index`, e.g. while the
+    /// mouse hovers over it, or hides it with `None`.
+    pub fn show_tooltip(&mut self, index: Option<usize>) {
+        self.tooltip = index;
+    }
+
+    /// Returns the index of the section whose tooltip is shown.
+    #[must_use]
+    pub const fn tooltip(&self) -> Option<usize> {
+        self.tooltip
+    }
+
+    /// Pans a [scrollable](crate::StatusBar::scrollable) status bar `columns` to the left.
+    pub fn scroll_left(&mut self, columns: u16) {
+        self.pan = self.pan.saturating_sub(i32::from(columns));
+    }
+
+    /// Pans a [scrollable](crate::StatusBar::scrollable) status bar `columns` to the right.
+    pub fn scroll_right(&mut self, columns: u16) {
+        self.pan = self.pan.saturating_add(i32::from(columns));
+    }
+
+    /// Returns how many columns the status bar was scrolled away from its start, i.e. from the
+    /// left edge or from the right edge in right-to-left mode, as of the last render.
+    #[must_use]
+    pub const fn scroll_offset(&self) -> usize {
+        self.scroll
+    }
+
+    /// Scrolls the status bar back to its start.
+    pub fn reset_scroll(&mut self) {
+        self.scroll = 0;
+        self.pan = 0;
+    }
+
+    /// Applies the pending pans for a bar that can be scrolled by up to `max` columns and returns
+    /// the number of columns hidden on the left.
+    pub(crate) fn scroll_to(&mut self, max: u16, right_to_left: bool) -> u16 {
+        let left = self.scroll_position(max, right_to_left);
+        self.pan = 0;
+        self.scroll = if right_to_left {
+            usize::from(max - left)
+        } else {
+            usize::from(left)
+        };
+        left
+    }
+
+    /// Returns the number of columns hidden on the left of a bar that can be scrolled by up to
+    /// `max` columns once the pending pans are applied, without applying them.
+    pub(crate) fn scroll_position(&self, max: u16, right_to_left: bool) -> u16 {
+        let max = usize::from(max);
+        let scroll = self.scroll.min(max);
+        let left = if right_to_left { max - scroll } else { scroll };
+        let pan = usize::try_from(self.pan.unsigned_abs()).unwrap_or(usize::MAX);
+        let left = if self.pan < 0 {
+            left.saturating_sub(pan)
+        } else {
+            left.saturating_add(pan).min(max)
+        };
+        u16::try_from(left).unwrap_or(u16::MAX)
+    }
+
+    /// Grabs the section at `index` with the pointer at `column`, e.g. on a mouse press on the
+    /// section found with [`StatusBar::hit_test`](crate::StatusBar::hit_test).
+    ///
+    /// While dragging, the section is rendered reversed and a marker shows where it will be
+    /// dropped. Call [`StatusBar::end_drag`](crate::StatusBar::end_drag) to drop it.
+    pub fn start_drag(&mut self, index: usize, column: u16) {
+        self.drag = Some((index, column));
+    }
+
+    /// Moves the pointer dragging a section to `column`.
+    pub fn drag_to(&mut self, column: u16) {
+        if let Some((_, pointer)) = &mut self.drag {
+            *pointer = column;
+        }
+    }
+
+    /// Returns the index of the dragged section and the column of the pointer.
+    #[must_use]
+    pub const fn drag(&self) -> Option<(usize, u16)> {
+        self.drag
+    }
+
+    /// Stops dragging without moving the section and returns its index.
+    pub fn end_drag(&mut self) -> Option<usize> {
+        self.drag.take().map(|(index, _)| index)
+    }
+
+    /// Shows the next [page](crate::StatusBarSection::pages) of the section at `index`, wrapping
+    /// around after the last page.
+    ///
+    /// A [rotating](Self::rotate_pages) section starts waiting for its next page anew.
+    pub fn next_page(&mut self, index: usize) {
+        let pager = self.pager(index);
+        pager.page += 1;
+        pager.elapsed = Duration::ZERO;
+    }
+
+    /// Shows the next [page](crate::StatusBarSection::pages) of the section at `index` every
+    /// `interval`, as time passes with [`StatusBarState::advance`].
+    pub fn rotate_pages(&mut self, index: usize, interval: Duration) {
+        let pager = self.pager(index);
+        pager.interval = Some(interval);
+        pager.elapsed = Duration::ZERO;
+    }
+
+    /// Returns how many times the section at `index` turned to its next page. The page that is
+    /// shown is this count modulo the number of pages.
+    #[must_use]
+    pub fn page(&self, index: usize) -> usize {
+        self.pagers
+            .iter()
+            .find(|pager| pager.index == index)
+            .map_or(0, |pager| pager.page)
+    }
+
+    fn pager(&mut self, index: usize) -> &mut Pager {
+        let position = self
+            .pagers
+            .iter()
+            .position(|pager| pager.index == index)
+            .unwrap_or_else(|| {
+                self.pagers.push(Pager {
+                    index,
+                    page: 0,
+                    interval: None,
+                    elapsed: Duration::ZERO,
+                });
+                self.pagers.len() - 1
+            });
+        &mut self.pagers[position]
+    }
+
+    /// Shows the section at `index` if it is hidden, or hides it if it is shown, see
+    /// [`StatusBar::toggle_section`](crate::StatusBar::toggle_section).
+    pub fn toggle_section(&mut self, index: usize) {
+        if let Some(position) = self.toggled.iter().position(|toggled| *toggled == index) {
+            self.toggled.remove(position);
+        } else {
+            self.toggled.push(index);
+        }
+    }
+
+    /// Returns whether the section at `index` is toggled, i.e. shown if it is
+    /// [optional](crate::StatusBarSection::optional) and hidden otherwise.
+    #[must_use]
+    pub fn is_toggled(&self, index: usize) -> bool {
+        self.toggled.contains(&index)
+    }
+
+    /// Returns whether the section at `index` is currently flashing.
+    #[must_use]
+    pub fn is_flashing(&self, index: usize) -> bool {
+        self.flashes.iter().any(|flash| flash.index == index)
+    }
+
+    /// Returns whether the section at `index` is animating a change of its content.
+    #[must_use]
+    pub fn is_transitioning(&self, index: usize) -> bool {
+        self.transitions
+            .iter()
+            .any(|transition| transition.index == index && transition.previous.is_some())
+    }
+
+    /// Remembers the content shown by the section at `index` and starts animating the change
+    /// from the content shown before, if it differs.
+    pub(crate) fn track_content(
+        &mut self,
+        index: usize,
+        content: &Line<'_>,
+        transition: Transition,
+        duration: Duration,
+    ) {
+        let Some(tracked) = self
+            .transitions
+            .iter_mut()
+            .find(|tracked| tracked.index == index)
+        else {
+            self.transitions.push(ContentTransition {
+                index,
+                shown: to_owned_line(content),
+                previous: None,
+                transition,
+                duration,
+                elapsed: Duration::ZERO,
+            });
+            return;
+        };
+        if tracked.shown != *content {
+            let shown = std::mem::replace(&mut tracked.shown, to_owned_line(content));
+            // a transition without duration is over before it is shown
+            tracked.previous = (!duration.is_zero()).then_some(shown);
+            tracked.transition = transition;
+            tracked.duration = duration;
+            tracked.elapsed = Duration::ZERO;
+        }
+    }
+
+    /// Returns the ongoing transition of the section at `index`: its kind, the previous and new
+    /// content and the progress from 0 to 1.
+    pub(crate) fn content_transition(
+        &self,
+        index: usize,
+    ) -> Option<(Transition, &Line<'static>, &Line<'static>, f32)> {
+        let tracked = self
+            .transitions
+            .iter()
+            .find(|tracked| tracked.index == index)?;
+        let previous = tracked.previous.as_ref()?;
+        let progress = progress(tracked.elapsed, tracked.duration);
+        Some((tracked.transition, previous, &tracked.shown, progress))
+    }
+
+    /// Advances the state by `elapsed`, fading flashes and expiring those that have run their
+    /// course, turning the pages of rotating sections and animating content transitions.
+    ///
+    /// Returns whether the status bar needs to be redrawn.
+    pub fn advance(&mut self, elapsed: Duration) -> bool {
+        let mut redraw = !self.flashes.is_empty();
+        for flash in &mut self.flashes {
+            flash.elapsed += elapsed;
+        }
+        self.flashes.retain(|flash| flash.elapsed < flash.duration);
+        for pager in &mut self.pagers {
+            let Some(interval) = pager.interval.filter(|interval| !interval.is_zero()) else {
+                continue;
+            };
+            pager.elapsed += elapsed;
+            while pager.elapsed >= interval {
+                pager.elapsed -= interval;
+                pager.page += 1;
+                redraw = true;
+            }
+        }
+        for tracked in &mut self.transitions {
+            if tracked.previous.is_none() {
+                continue;
+            }
+            tracked.elapsed += elapsed;
+            if tracked.elapsed >= tracked.duration {
+                tracked.previous = None;
+            }
+            redraw = true;
+        }
+        redraw
+    }
+
+    /// Advances the state and `segments` by `elapsed`.
+    ///
+    /// Returns whether the status bar needs to be redrawn, i.e. whether the state or any segment
+    /// changed.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ratatui_statusbar::segments::{LogSegment, Segment, Spinner};
+    /// use ratatui_statusbar::StatusBarState;
+    ///
+    /// let mut state = StatusBarState::new();
+    /// let mut segments: Vec<Box<dyn Segment>> = vec![
+    ///     Box::new(Spinner::new()),
+    ///     Box::new(LogSegment::new().timeout(Duration::from_secs(5))),
+    /// ];
+    /// let redraw = state.advance_with(Duration::from_millis(100), &mut segments);
+    /// assert!(redraw);
+    /// ```
+    #[cfg(feature = "segments")]
+    pub fn advance_with<'s, I, S>(&mut self, elapsed: Duration, segments: I) -> bool
+    where
+        I: IntoIterator<Item = &'s mut S>,
+        S: Segment + ?Sized + 's,
+    {
+        segments
+            .into_iter()
+            .fold(self.advance(elapsed), |redraw, segment| {
+                segment.advance(elapsed) || redraw
+            })
+    }
+
+    /// Returns the style the section at `index` is rendered with, given its own style.
+    pub(crate) fn section_style(&self, index: usize, style: Style) -> Style {
+        let style = self
+            .flashes
+            .iter()
+            .find(|flash| flash.index == index)
+            .map_or(style, |flash| flash.apply(style));
+        match self.drag {
+            Some((dragged, _)) if dragged == index => style.add_modifier(Modifier::REVERSED),
+            _ => style,
+        }
+    }
+}
+
+fn to_owned_line(line: &Line<'_>) -> Line<'static> {
+    Line {
+        spans: line
+            .spans
+            .iter()
+            .map(|span| Span::styled(span.content.to_string(), span.style))
+            .collect(),
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+impl Flash {
+    fn apply(&self, style: Style) -> Style {
+        let fade = (progress(self.elapsed, self.duration) * 2.0 - 1.0).max(0.0);
+        let mix = |flash: Option<ratatui::style::Color>, base| match (flash, base) {
+            (Some(flash), Some(base)) => Some(blend(flash, base, fade)),
+            (flash, base) => flash.or(base),
+        };
+        Style {
+            fg: mix(self.style.fg, style.fg),
+            bg: mix(self.style.bg, style.bg),
+            ..style.patch(self.style)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn flash_fades() {
+        let base = Style::new().fg(Color::Rgb(0, 0, 0)).italic();
+        let mut state = StatusBarState::new();
+        state.flash(0, Style::new().white().bold(), Duration::from_secs(4));
+        assert_eq!(state.section_style(1, base), base);
+        assert_eq!(state.section_style(0, base), base.white().bold());
+
+        assert!(state.advance(Duration::from_secs(3)));
+        assert_eq!(
+            state.section_style(0, base),
+            base.fg(Color::Rgb(128, 128, 128)).bold()
+        );
+
+        assert!(state.advance(Duration::from_secs(1)));
+        assert!(!state.is_flashing(0));
+        assert!(!state.advance(Duration::from_secs(1)));
+        assert_eq!(state.section_style(0, base), base);
+    }
+
+    #[test]
+    fn zero_duration() {
+        let base = Style::new().fg(Color::Rgb(200, 200, 200));
+        let mut state = StatusBarState::new();
+        state.flash(0, Style::new().red(), Duration::from_secs(1));
+        state.flash(0, Style::new().red(), Duration::ZERO);
+        assert!(!state.is_flashing(0));
+        assert_eq!(state.section_style(0, base), base);
+
+        state.track_content(0, &Line::from("old"), Transition::Fade, Duration::ZERO);
+        state.track_content(0, &Line::from("new"), Transition::Fade, Duration::ZERO);
+        assert!(!state.is_transitioning(0));
+        assert_eq!(state.content_transition(0), None);
+    }
+}
@@ -0,0 +1,379 @@
+//! A tab bar that matches the [`StatusBar`].
+
+use ratatui::layout::Position;
+use ratatui::prelude::*;
+use ratatui::widgets::{StatefulWidgetRef, WidgetRef};
+
+use crate::{StatusBar, StatusBarSection};
+
+/// A row of labeled tabs, typically shown at the top of the screen above a [`StatusBar`].
+///
+/// Each tab is rendered as a [`StatusBarSection`], padded with a space on either side and
+/// followed by an optional close button, with a divider between tabs. When the tabs don't fit,
+/// the row scrolls to keep the selected tab visible and `‹` / `›` mark the hidden tabs.
+///
+/// Use [`TabLine::hit_test`] to map mouse clicks to tabs and close buttons.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::{TabHit, TabLine, TabLineState};
+///
+/// let tab_line = TabLine::new(["main.rs", "lib.rs"]).close_button("×");
+/// let mut state = TabLineState::default().select(1);
+///
+/// let area = Rect::new(0, 0, 40, 1);
+/// let mut buf = Buffer::empty(area);
+/// StatefulWidget::render(&tab_line, area, &mut buf, &mut state);
+/// assert_eq!(tab_line.hit_test(area, &state, (1, 0)), Some(TabHit::Tab(0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TabLine<'a> {
+    tabs: Vec<Line<'a>>,
+    style: Style,
+    active_style: Style,
+    inactive_style: Style,
+    divider: Span<'a>,
+    close_button: Option<Span<'a>>,
+}
+
+/// The state of a [`TabLine`]: the selected tab and the scroll position.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TabLineState {
+    selected: usize,
+    offset: usize,
+}
+
+/// The part of a [`TabLine`] at a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TabHit {
+    /// The label of the tab at the index.
+    Tab(usize),
+    /// The close button of the tab at the index.
+    Close(usize),
+    /// The marker of tabs hidden to the left.
+    ScrollLeft,
+    /// The marker of tabs hidden to the right.
+    ScrollRight,
+}
+
+/// The visible part of a [`TabLine`].
+struct TabLayout {
+    /// The area of each visible tab, including its divider.
+    tabs: Vec<(usize, Rect)>,
+    /// The area the visible tabs are rendered into.
+    inner: Rect,
+    left: Option<Rect>,
+    right: Option<Rect>,
+}
+
+impl<'a> TabLine<'a> {
+    /// Creates a [`TabLine`] with the given tab labels.
+    #[must_use]
+    pub fn new<I>(tabs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        Self {
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            style: Style::default(),
+            active_style: Style::new().add_modifier(Modifier::REVERSED),
+            inactive_style: Style::default(),
+            divider: Span::raw("│"),
+            close_button: None,
+        }
+    }
+
+    /// Sets the style of the whole [`TabLine`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected tab, reversed by default.
+    #[must_use]
+    pub fn active_style(mut self, style: impl Into<Style>) -> Self {
+        self.active_style = style.into();
+        self
+    }
+
+    /// Sets the style of the tabs that are not selected.
+    #[must_use]
+    pub fn inactive_style(mut self, style: impl Into<Style>) -> Self {
+        self.inactive_style = style.into();
+        self
+    }
+
+    /// Sets the divider shown between tabs, `│` by default.
+    #[must_use]
+    pub fn divider(mut self, divider: impl Into<Span<'a>>) -> Self {
+        self.divider = divider.into();
+        self
+    }
+
+    /// Shows a close button after the label of every tab.
+    #[must_use]
+    pub fn close_button(mut self, close_button: impl Into<Span<'a>>) -> Self {
+        self.close_button = Some(close_button.into());
+        self
+    }
+
+    /// Returns the part of the [`TabLine`] at `position`.
+    ///
+    /// `area` and `state` must be the area and state of the last render.
+    #[must_use]
+    pub fn hit_test(
+        &self,
+        area: Rect,
+        state: &TabLineState,
+        position: impl Into<Position>,
+    ) -> Option<TabHit> {
+        let position = position.into();
+        let layout = self.layout(area, state.offset);
+        if layout.left.is_some_and(|rect| rect.contains(position)) {
+            return Some(TabHit::ScrollLeft);
+        }
+        if layout.right.is_some_and(|rect| rect.contains(position)) {
+            return Some(TabHit::ScrollRight);
+        }
+        let (index, rect) = layout
+            .tabs
+            .into_iter()
+            .find(|(_, rect)| rect.contains(position))?;
+        let divider = if index + 1 < self.tabs.len() {
+            self.divider.width()
+        } else {
+            0
+        };
+        let close = self.close_button.as_ref().map_or(0, Span::width);
+        let close_end = rect
+            .right()
+            .saturating_sub(u16::try_from(divider + 1).unwrap_or(0));
+        let close_start = close_end.saturating_sub(u16::try_from(close).unwrap_or(0));
+        if close > 0 && (close_start..close_end).contains(&position.x) {
+            Some(TabHit::Close(index))
+        } else {
+            Some(TabHit::Tab(index))
+        }
+    }
+
+    fn section(&self, index: usize, selected: usize) -> StatusBarSection<'a> {
+        let mut content = self.tabs[index].clone();
+        content.spans.insert(0, Span::raw(" "));
+        if let Some(close_button) = &self.close_button {
+            content.spans.push(Span::raw(" "));
+            content.spans.push(close_button.clone());
+        }
+        content.spans.push(Span::raw(" "));
+        let section = StatusBarSection::from(content).style(if index == selected {
+            self.active_style
+        } else {
+            self.inactive_style
+        });
+        if index + 1 < self.tabs.len() {
+            section.post_separator(self.divider.clone())
+        } else {
+            section
+        }
+    }
+
+    fn width(&self, index: usize) -> u16 {
+        u16::try_from(self.section(index, 0).width()).unwrap_or(u16::MAX)
+    }
+
+    /// Returns the first visible tab, scrolled as little as possible from `offset` so that the
+    /// selected tab is visible.
+    fn scroll(&self, area: Rect, offset: usize, selected: usize) -> usize {
+        let inner = self.inner(area);
+        let mut offset = offset.min(selected);
+        while offset < selected
+            && (offset..=selected)
+                .map(|index| self.width(index))
+                .fold(0u16, u16::saturating_add)
+                > inner.width
+        {
+            offset += 1;
+        }
+        offset
+    }
+
+    /// Returns the area left for tabs after reserving space for the scroll markers.
+    fn inner(&self, area: Rect) -> Rect {
+        let total = (0..self.tabs.len())
+            .map(|index| self.width(index))
+            .fold(0u16, u16::saturating_add);
+        if total > area.width && area.width > 2 {
+            Rect {
+                x: area.x + 1,
+                width: area.width - 2,
+                ..area
+            }
+        } else {
+            area
+        }
+    }
+
+    fn layout(&self, area: Rect, offset: usize) -> TabLayout {
+        let inner = self.inner(area);
+        let mut x = inner.x;
+        let mut tabs = Vec::new();
+        for index in offset..self.tabs.len() {
+            let width = self.width(index);
+            if x.saturating_add(width) > inner.right() && !tabs.is_empty() {
+                break;
+            }
+            let width = width.min(inner.right() - x);
+            tabs.push((index, Rect { x, width, ..inner }));
+            x += width;
+        }
+        let marker = |x| Rect {
+            x,
+            width: 1,
+            ..area
+        };
+        let last = tabs.last().map_or(offset, |(index, _)| *index);
+        TabLayout {
+            left: (inner != area && offset > 0).then(|| marker(area.x)),
+            right: (inner != area && last + 1 < self.tabs.len()).then(|| marker(area.right() - 1)),
+            tabs,
+            inner,
+        }
+    }
+}
+
+impl TabLineState {
+    /// Selects the tab at `index`.
+    #[must_use]
+    pub const fn select(mut self, index: usize) -> Self {
+        self.selected = index;
+        self
+    }
+
+    /// Returns the index of the selected tab.
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the index of the first visible tab.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Selects the tab at `index`.
+    pub fn set_selected(&mut self, index: usize) {
+        self.selected = index;
+    }
+}
+
+impl StatefulWidget for TabLine<'_> {
+    type State = TabLineState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &TabLine<'_> {
+    type State = TabLineState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for TabLine<'_> {
+    type State = TabLineState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        if area.is_empty() || self.tabs.is_empty() {
+            return;
+        }
+        state.selected = state.selected.min(self.tabs.len() - 1);
+        state.offset = self.scroll(area, state.offset, state.selected);
+
+        let layout = self.layout(area, state.offset);
+        let status_bar = StatusBar {
+            sections: layout
+                .tabs
+                .iter()
+                .map(|(index, _)| self.section(*index, state.selected))
+                .collect(),
+            spacing: 0,
+            style: self.style,
+            ..StatusBar::new(0)
+        };
+        WidgetRef::render_ref(&status_bar, layout.inner, buf);
+        if let Some(rect) = layout.left {
+            buf.set_string(rect.x, rect.y, "‹", self.style);
+        }
+        if let Some(rect) = layout.right {
+            buf.set_string(rect.x, rect.y, "›", self.style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(tab_line: &TabLine, width: u16, state: &mut TabLineState) -> String {
+        let area = Rect::new(0, 0, width, 1);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(tab_line, area, &mut buf, state);
+        buf.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn render_tabs() {
+        let tab_line = TabLine::new(["a", "b"]).close_button("x");
+        let mut state = TabLineState::default().select(1);
+        let area = Rect::new(0, 0, 12, 1);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(&tab_line, area, &mut buf, &mut state);
+
+        let mut expected = Buffer::with_lines(vec![" a x │ b x  "]);
+        expected.set_style(Rect::new(6, 0, 5, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+
+        assert_eq!(
+            tab_line.hit_test(area, &state, (1, 0)),
+            Some(TabHit::Tab(0))
+        );
+        assert_eq!(
+            tab_line.hit_test(area, &state, (3, 0)),
+            Some(TabHit::Close(0))
+        );
+        assert_eq!(
+            tab_line.hit_test(area, &state, (5, 0)),
+            Some(TabHit::Tab(0))
+        );
+        assert_eq!(
+            tab_line.hit_test(area, &state, (9, 0)),
+            Some(TabHit::Close(1))
+        );
+        assert_eq!(tab_line.hit_test(area, &state, (11, 0)), None);
+    }
+
+    #[test]
+    fn scroll_to_selected() {
+        let tab_line = TabLine::new(["one", "two", "three", "four"]).divider("|");
+        let mut state = TabLineState::default();
+        assert_eq!(render(&tab_line, 14, &mut state), "  one | two |›");
+        state.set_selected(2);
+        assert_eq!(render(&tab_line, 14, &mut state), "‹ three |    ›");
+        assert_eq!(state.offset(), 2);
+        state.set_selected(3);
+        assert_eq!(render(&tab_line, 14, &mut state), "‹ four        ");
+        state.set_selected(0);
+        assert_eq!(render(&tab_line, 14, &mut state), "  one | two |›");
+        assert_eq!(
+            tab_line.hit_test(Rect::new(0, 0, 14, 1), &state, (13, 0)),
+            Some(TabHit::ScrollRight)
+        );
+    }
+}
@@ -0,0 +1,403 @@
+//! Recording rendered frames for visual regression tests of animated status bars.
+//!
+//! A [`Recorder`] renders a status bar once per frame into a fresh buffer and keeps the frames,
+//! so behavior over time, such as spinners turning, pages rotating or flashes expiring, can be
+//! compared with a snapshot in a single assertion.
+//!
+//! # Examples
+//! ```
+//! use std::time::Duration;
+//!
+//! use ratatui::prelude::*;
+//! use ratatui_statusbar::segments::{Segment, Spinner};
+//! use ratatui_statusbar::testing::Recorder;
+//! use ratatui_statusbar::StatusBar;
+//!
+//! let mut spinner = Spinner::new().frames(["-", "+"]).interval(Duration::from_millis(100));
+//! let mut recorder = Recorder::new(Rect::new(0, 0, 8, 1));
+//! for _ in 0..3 {
+//!     let status_bar = StatusBar::new(2).section(0, spinner.content())?.section(1, "build")?;
+//!     recorder.record(&status_bar);
+//!     spinner.advance(Duration::from_millis(100));
+//! }
+//! recorder.assert_snapshot(
+//!     "
+//! frame 0
+//! |- build |
+//! frame 1
+//! |+ build |
+//! frame 2
+//! |- build |
+//! ",
+//! );
+//! # Ok::<(), ratatui_statusbar::StatusBarError>(())
+//! ```
+//!
+//! With the `proptest` feature, [`StatusBar`], [`StatusBarSection`](crate::StatusBarSection) and
+//! the layout parameters implement `proptest::arbitrary::Arbitrary`, and [`flex`], [`area`] and
+//! [`content`] are strategies for the values that don't, so property tests can check the
+//! [invariants](check_invariants) of generated status bars:
+//!
+//! ```
+//! # #[cfg(feature = "proptest")]
+//! # {
+//! use proptest::prelude::*;
+//! use ratatui_statusbar::testing::{area, check_invariants};
+//! use ratatui_statusbar::StatusBar;
+//!
+//! proptest!(|(status_bar in any::<StatusBar<'static>>(), area in area(40))| {
+//!     prop_assert_eq!(check_invariants(&status_bar, area), Ok(()));
+//! });
+//! # }
+//! ```
+
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::{env, fs};
+
+use ratatui::buffer::Cell;
+use ratatui::layout::Position;
+use ratatui::prelude::*;
+use ratatui::widgets::WidgetRef;
+
+use crate::StatusBar;
+
+#[cfg(feature = "proptest")]
+mod strategies;
+
+#[cfg(feature = "proptest")]
+pub use strategies::{area, content, flex};
+
+/// The environment variable that makes [`Recorder::assert_snapshot_file`] write the recorded
+/// frames instead of comparing them.
+pub const UPDATE_SNAPSHOTS: &str = "STATUSBAR_UPDATE_SNAPSHOTS";
+
+/// Records the frames of a status bar, see the [module](self) for an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorder {
+    area: Rect,
+    frames: Vec<Buffer>,
+}
+
+impl Recorder {
+    /// Creates a recorder rendering frames into `area`.
+    #[must_use]
+    pub const fn new(area: Rect) -> Self {
+        Self {
+            area,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Renders `widget` into a new frame.
+    pub fn record(&mut self, widget: &impl WidgetRef) {
+        self.record_with(|area, buf| widget.render_ref(area, buf));
+    }
+
+    /// Renders a new frame with `render`, e.g. to render a status bar with its
+    /// [state](crate::StatusBarState).
+    pub fn record_with(&mut self, render: impl FnOnce(Rect, &mut Buffer)) {
+        let mut buf = Buffer::empty(self.area);
+        render(self.area, &mut buf);
+        self.frames.push(buf);
+    }
+
+    /// Returns the recorded frames.
+    #[must_use]
+    pub fn frames(&self) -> &[Buffer] {
+        &self.frames
+    }
+
+    /// Returns the recorded frames as text, each frame headed by its number and each row
+    /// enclosed in `|`. Styles are left out.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for (index, frame) in self.frames.iter().enumerate() {
+            let _ = writeln!(text, "frame {index}");
+            for row in rows(frame) {
+                let _ = writeln!(text, "|{row}|");
+            }
+        }
+        text
+    }
+
+    /// Compares the recorded frames with `snapshot`, written like [`Recorder::to_text`], and
+    /// returns an annotated report of the differing rows, or `None` if they match.
+    ///
+    /// Leading and trailing blank lines of the snapshot are ignored.
+    #[must_use]
+    pub fn diff(&self, snapshot: &str) -> Option<String> {
+        let actual = self.to_text();
+        let expected = snapshot.trim_matches('\n');
+        let actual = actual.trim_matches('\n');
+        if expected == actual {
+            return None;
+        }
+        let mut report = String::new();
+        let mut expected_lines = expected.lines();
+        let mut actual_lines = actual.lines();
+        let mut frame = String::new();
+        loop {
+            let (expected, actual) = (expected_lines.next(), actual_lines.next());
+            if expected.is_none() && actual.is_none() {
+                break;
+            }
+            if let Some(header) = actual.filter(|line| line.starts_with("frame ")) {
+                header.clone_into(&mut frame);
+            }
+            if expected == actual {
+                continue;
+            }
+            let _ = writeln!(report, "{frame}");
+            let _ = writeln!(report, "- {}", expected.unwrap_or("<missing>"));
+            let _ = writeln!(report, "+ {}", actual.unwrap_or("<missing>"));
+            if let (Some(expected), Some(actual)) = (expected, actual) {
+                let marks = expected
+                    .chars()
+                    .zip(actual.chars())
+                    .map(|(expected, actual)| if expected == actual { ' ' } else { '^' })
+                    .collect::<String>();
+                let _ = writeln!(report, "  {}", marks.trim_end());
+            }
+        }
+        Some(report)
+    }
+
+    /// Asserts that the recorded frames match `snapshot`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the [diff](Recorder::diff) if they don't.
+    #[track_caller]
+    pub fn assert_snapshot(&self, snapshot: &str) {
+        if let Some(diff) = self.diff(snapshot) {
+            panic!("recorded frames differ from the snapshot:\n{diff}");
+        }
+    }
+
+    /// Asserts that the recorded frames match the snapshot stored at `path`.
+    ///
+    /// The snapshot is written instead if it doesn't exist yet or the [`UPDATE_SNAPSHOTS`]
+    /// environment variable is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the [diff](Recorder::diff) if the frames don't match, or if the snapshot
+    /// can't be read or written.
+    #[track_caller]
+    pub fn assert_snapshot_file(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        if env::var_os(UPDATE_SNAPSHOTS).is_some() || !path.exists() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).expect("failed to create the snapshot directory");
+            }
+            fs::write(path, self.to_text()).expect("failed to write the snapshot");
+            return;
+        }
+        let snapshot = fs::read_to_string(path).expect("failed to read the snapshot");
+        if let Some(diff) = self.diff(&snapshot) {
+            panic!(
+                "recorded frames differ from {}, set {UPDATE_SNAPSHOTS}=1 to update it:\n{diff}",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Checks the invariants every status bar upholds when rendered into `area`, for property and
+/// fuzz tests over generated status bars, e.g. with the `Arbitrary` implementations of the
+/// `proptest` feature:
+///
+/// - rendering doesn't panic,
+/// - no cell outside `area` is written,
+/// - the [areas](StatusBar::areas) of the sections lie within `area`.
+///
+/// # Errors
+///
+/// Returns a description of the first violated invariant.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::testing::check_invariants;
+/// use ratatui_statusbar::StatusBar;
+///
+/// let status_bar = StatusBar::new(2).section(0, "NORMAL")?.section(1, "src/lib.rs")?;
+/// for width in 0..20 {
+///     check_invariants(&status_bar, Rect::new(3, 2, width, 1)).unwrap();
+/// }
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+pub fn check_invariants(status_bar: &StatusBar<'_>, area: Rect) -> Result<(), String> {
+    let bounds = Rect::new(
+        area.x.saturating_sub(1),
+        area.y.saturating_sub(1),
+        area.width.saturating_add(2),
+        area.height.saturating_add(2),
+    );
+    let mut sentinel = Cell::default();
+    sentinel.set_symbol("\u{1}");
+    let mut buf = Buffer::filled(bounds, &sentinel);
+    let rendered = panic::catch_unwind(AssertUnwindSafe(|| {
+        status_bar.render_ref(area, &mut buf);
+        status_bar.areas(area)
+    }));
+    let Ok(areas) = rendered else {
+        return Err(format!("rendering into {area:?} panicked"));
+    };
+    for y in bounds.top()..bounds.bottom() {
+        for x in bounds.left()..bounds.right() {
+            if !area.contains(Position { x, y }) && buf.get(x, y) != &sentinel {
+                return Err(format!(
+                    "the cell at ({x}, {y}) outside {area:?} was written"
+                ));
+            }
+        }
+    }
+    match areas
+        .iter()
+        .enumerate()
+        .find(|(_, rect)| !rect.is_empty() && rect.intersection(area) != **rect)
+    {
+        Some((index, rect)) => Err(format!(
+            "section {index} at {rect:?} doesn't fit into {area:?}"
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns the symbols of each row of `buf`.
+fn rows(buf: &Buffer) -> impl Iterator<Item = String> + '_ {
+    let area = buf.area;
+    (area.top()..area.bottom()).map(move |y| {
+        (area.left()..area.right())
+            .map(|x| buf.get(x, y).symbol())
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ratatui::layout::Flex;
+
+    use super::*;
+    use crate::{StatusBar, StatusBarError, StatusBarSection, StatusBarState};
+
+    #[test]
+    fn flash_expiry() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(1).section(0, "saved")?;
+        let mut state = StatusBarState::new();
+        state.flash(0, Style::new().reversed(), Duration::from_secs(1));
+        let mut recorder = Recorder::new(Rect::new(0, 0, 6, 1));
+        for _ in 0..2 {
+            recorder.record_with(|area, buf| {
+                StatefulWidget::render(&status_bar, area, buf, &mut state);
+            });
+            state.advance(Duration::from_secs(1));
+        }
+        let frames = recorder.frames();
+        assert!(frames[0].get(0, 0).modifier.contains(Modifier::REVERSED));
+        assert!(!frames[1].get(0, 0).modifier.contains(Modifier::REVERSED));
+        recorder.assert_snapshot("frame 0\n|saved |\nframe 1\n|saved |\n");
+
+        assert_eq!(
+            recorder.diff("frame 0\n|saved |\nframe 1\n|sived |"),
+            Some("frame 1\n- |sived |\n+ |saved |\n    ^\n".to_string())
+        );
+        assert_eq!(
+            recorder.diff("frame 0\n|saved |"),
+            Some("frame 1\n- <missing>\n+ frame 1\nframe 1\n- <missing>\n+ |saved |\n".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invariants() -> Result<(), StatusBarError> {
+        let status_bar = StatusBar::new(3)
+            .section(0, "mode")?
+            .section(1, "日本語")?
+            .section(2, StatusBarSection::from("file.rs").pre_separator("["))?
+            .scrollable(true);
+        for width in 0..24 {
+            for x in [0, 5] {
+                assert_eq!(
+                    check_invariants(&status_bar, Rect::new(x, 1, width, 1)),
+                    Ok(())
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn arbitrary_bars(
+            status_bar in proptest::arbitrary::any::<StatusBar<'static>>(),
+            area in area(40),
+        ) {
+            proptest::prop_assert_eq!(check_invariants(&status_bar, area), Ok(()));
+        }
+    }
+
+    #[test]
+    fn generated_bars() -> Result<(), StatusBarError> {
+        // xorshift, so that failures are reproducible without a dependency
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = |bound: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % bound
+        };
+        let contents = ["", "a", "mode", "日本語", "src/lib.rs", "🦀 main", "a\tb"];
+        let flexes = [
+            Flex::Legacy,
+            Flex::Start,
+            Flex::End,
+            Flex::Center,
+            Flex::SpaceBetween,
+        ];
+        for _ in 0..300 {
+            let count = usize::try_from(next(5)).unwrap_or_default();
+            let mut status_bar = StatusBar::new(count)
+                .flex(flexes[usize::try_from(next(5)).unwrap_or_default()])
+                .spacing(u16::try_from(next(3)).unwrap_or_default())
+                .right_to_left(next(2) == 0)
+                .fill_from_end(next(2) == 0)
+                .compact(next(2) == 0)
+                .scrollable(next(4) == 0)
+                .overflow_indicator(next(2) == 0);
+            for index in 0..count {
+                let content = contents[usize::try_from(next(7)).unwrap_or_default()];
+                let mut section = StatusBarSection::from(content)
+                    .min_width(u16::try_from(next(4)).unwrap_or_default())
+                    .optional(next(6) == 0)
+                    .hidden(next(8) == 0);
+                if next(3) == 0 {
+                    section = section.pre_separator("[").post_separator("]");
+                }
+                if next(3) == 0 {
+                    section = section.max_width(u16::try_from(next(6)).unwrap_or_default());
+                }
+                status_bar = status_bar.section(index, section)?;
+            }
+            let area = Rect::new(
+                u16::try_from(next(4)).unwrap_or_default(),
+                u16::try_from(next(3)).unwrap_or_default(),
+                u16::try_from(next(30)).unwrap_or_default(),
+                u16::try_from(next(3)).unwrap_or_default(),
+            );
+            assert_eq!(
+                check_invariants(&status_bar, area),
+                Ok(()),
+                "{status_bar:?}"
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+use proptest::prelude::*;
+use ratatui::layout::Flex;
+use ratatui::prelude::*;
+
+use crate::{
+    Breakpoint, ColorDepth, Role, SeparatorMerge, SeparatorStyle, StatusBar, StatusBarSection,
+    Truncation,
+};
+
+/// The section names generated sections and breakpoints pick from, so that breakpoints hide some
+/// of the sections.
+const NAMES: [&str; 3] = ["mode", "file", "git"];
+
+/// Returns a strategy for the [`Flex`] layout of a status bar.
+pub fn flex() -> impl Strategy<Value = Flex> {
+    prop_oneof![
+        Just(Flex::Legacy),
+        Just(Flex::Start),
+        Just(Flex::End),
+        Just(Flex::Center),
+        Just(Flex::SpaceBetween),
+        Just(Flex::SpaceAround),
+    ]
+}
+
+/// Returns a strategy for areas of up to `max_width` columns and a few rows, offset from the
+/// origin of the buffer.
+pub fn area(max_width: u16) -> impl Strategy<Value = Rect> {
+    (0..8u16, 0..4u16, 0..=max_width, 0..4u16)
+        .prop_map(|(x, y, width, height)| Rect::new(x, y, width, height))
+}
+
+/// Returns a strategy for section content, including wide characters, emoji, combining marks and
+/// control characters.
+pub fn content() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-z ]{0,12}",
+        Just("日本語".to_string()),
+        Just("🦀 main".to_string()),
+        Just("e\u{301}".to_string()),
+        Just("a\tb".to_string()),
+        Just("src/segments/git.rs".to_string()),
+    ]
+}
+
+fn style() -> impl Strategy<Value = Style> {
+    prop_oneof![
+        Just(Style::new()),
+        Just(Style::new().bold()),
+        Just(Style::new().black().on_green()),
+        Just(Style::new().fg(Color::Rgb(255, 128, 0))),
+    ]
+}
+
+fn breakpoint() -> impl Strategy<Value = (u16, Breakpoint)> {
+    (
+        0..40u16,
+        proptest::option::of(0..3u16),
+        any::<bool>(),
+        0..NAMES.len(),
+    )
+        .prop_map(|(max_width, spacing, short, hidden)| {
+            let mut breakpoint = Breakpoint::new().short(short).hide(NAMES[hidden]);
+            if let Some(spacing) = spacing {
+                breakpoint = breakpoint.spacing(spacing);
+            }
+            (max_width, breakpoint)
+        })
+}
+
+macro_rules! arbitrary_enum {
+    ($type:ty, [$($variant:expr),+ $(,)?]) => {
+        impl Arbitrary for $type {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                prop_oneof![$(Just($variant)),+].boxed()
+            }
+        }
+    };
+}
+
+arbitrary_enum!(
+    Truncation,
+    [
+        Truncation::Clip,
+        Truncation::End,
+        Truncation::Start,
+        Truncation::Middle
+    ]
+);
+arbitrary_enum!(
+    SeparatorStyle,
+    [
+        SeparatorStyle::Section,
+        SeparatorStyle::Preceding,
+        SeparatorStyle::Following,
+        SeparatorStyle::Bar,
+        SeparatorStyle::Explicit,
+    ]
+);
+arbitrary_enum!(
+    SeparatorMerge,
+    [
+        SeparatorMerge::Keep,
+        SeparatorMerge::Preceding,
+        SeparatorMerge::Following
+    ]
+);
+arbitrary_enum!(
+    ColorDepth,
+    [
+        ColorDepth::TrueColor,
+        ColorDepth::Indexed256,
+        ColorDepth::Ansi16
+    ]
+);
+arbitrary_enum!(
+    Role,
+    [
+        Role::Accent,
+        Role::Muted,
+        Role::Info,
+        Role::Warning,
+        Role::Error
+    ]
+);
+
+/// Generates sections with plain or multi-line content, short content, separators, width limits,
+/// truncation and visibility flags. Widgets, groups and closures aren't generated.
+impl Arbitrary for StatusBarSection<'static> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        let text = (
+            content(),
+            proptest::option::of(content()),
+            proptest::option::of(content()),
+            proptest::option::of(prop_oneof![Just("["), Just(" | "), Just("")]),
+            proptest::option::of(prop_oneof![Just("]"), Just(" "), Just("")]),
+            proptest::option::of(0..NAMES.len()),
+        );
+        let layout = (
+            any::<Truncation>(),
+            proptest::option::of(0..8u16),
+            proptest::option::of(0..12u16),
+            proptest::option::of(0..3u16),
+            0..3u16,
+        );
+        let flags = (
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            proptest::option::of(any::<Role>()),
+            style(),
+        );
+        (text, layout, flags)
+            .prop_map(
+                |(
+                    (content, second_line, short, pre, post, name),
+                    (truncation, min_width, max_width, gap_after, gap_weight),
+                    (hidden, pinned, optional, role, style),
+                )| {
+                    let text = match second_line {
+                        Some(line) => format!("{content}\n{line}"),
+                        None => content,
+                    };
+                    let mut section = StatusBarSection::default()
+                        .text(text)
+                        .truncation(truncation)
+                        .gap_weight(gap_weight)
+                        .hidden(hidden)
+                        .pinned(pinned)
+                        .optional(optional)
+                        .style(style);
+                    if let Some(short) = short {
+                        section = section.short_content(short);
+                    }
+                    if let Some(pre) = pre {
+                        section = section.pre_separator(pre);
+                    }
+                    if let Some(post) = post {
+                        section = section.post_separator(post);
+                    }
+                    if let Some(name) = name {
+                        section = section.name(NAMES[name]);
+                    }
+                    if let Some(min_width) = min_width {
+                        section = section.min_width(min_width);
+                    }
+                    if let Some(max_width) = max_width {
+                        section = section.max_width(max_width);
+                    }
+                    if let Some(gap_after) = gap_after {
+                        section = section.gap_after(gap_after);
+                    }
+                    if let Some(role) = role {
+                        section = section.role(role);
+                    }
+                    section
+                },
+            )
+            .boxed()
+    }
+}
+
+/// Generates status bars of up to six [arbitrary](Arbitrary) sections with arbitrary layout
+/// parameters and breakpoints.
+impl Arbitrary for StatusBar<'static> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        let layout = (
+            flex(),
+            0..3u16,
+            any::<SeparatorStyle>(),
+            any::<SeparatorMerge>(),
+            any::<ColorDepth>(),
+            proptest::collection::vec(breakpoint(), 0..3),
+        );
+        let flags = (
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+        );
+        (
+            proptest::collection::vec(any::<StatusBarSection<'static>>(), 0..6),
+            layout,
+            flags,
+        )
+            .prop_map(
+                |(
+                    sections,
+                    (flex, spacing, separator_style, separator_merge, color_depth, breakpoints),
+                    (
+                        right_to_left,
+                        compact,
+                        fill_from_end,
+                        ascii,
+                        scrollable,
+                        scroll_indicators,
+                        overflow_indicator,
+                        inactive,
+                    ),
+                )| {
+                    let mut status_bar = StatusBar::new(0)
+                        .flex(flex)
+                        .spacing(spacing)
+                        .separator_style(separator_style)
+                        .separator_merge(separator_merge)
+                        .color_depth(color_depth)
+                        .right_to_left(right_to_left)
+                        .compact(compact)
+                        .fill_from_end(fill_from_end)
+                        .ascii(ascii)
+                        .scrollable(scrollable)
+                        .scroll_indicators(scroll_indicators)
+                        .overflow_indicator(overflow_indicator)
+                        .inactive(inactive);
+                    status_bar.sections = sections;
+                    for (max_width, breakpoint) in breakpoints {
+                        status_bar = status_bar.breakpoint(max_width, breakpoint);
+                    }
+                    status_bar
+                },
+            )
+            .boxed()
+    }
+}
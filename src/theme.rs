@@ -0,0 +1,133 @@
+//! Color themes for status bars.
+
+use ratatui::prelude::*;
+
+/// The styles a [`StatusBar`](crate::StatusBar) and its sections are built from.
+///
+/// Sections pick their style from the theme of the bar through their [`Role`].
+///
+/// [`Theme::dark`] and [`Theme::light`] are readable presets for dark and light terminal
+/// backgrounds. With the `appearance` feature on Unix, `Theme::auto` picks one of them by querying the
+/// background color of the terminal.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::{Role, StatusBar, StatusBarSection, Theme};
+///
+/// let theme = Theme::light();
+/// let status_bar = StatusBar::new(2)
+///     .theme(theme)
+///     .section(0, StatusBarSection::from(" NORMAL ").role(Role::Accent))?
+///     .section(1, StatusBarSection::from("2 errors").style(theme.error))?;
+/// # Ok::<(), ratatui_statusbar::StatusBarError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// The style of the whole bar.
+    pub bar: Style,
+    /// The style of sections that stand out, such as a mode indicator.
+    pub accent: Style,
+    /// The style of secondary information.
+    pub muted: Style,
+    /// The style of informational messages.
+    pub info: Style,
+    /// The style of warnings.
+    pub warning: Style,
+    /// The style of errors.
+    pub error: Style,
+    /// The style applied on top of [inactive](crate::StatusBar::inactive) bars.
+    pub inactive: Style,
+}
+
+/// What a section shows, which selects its style from the [`Theme`] of the bar.
+///
+/// See [`StatusBarSection::role`](crate::StatusBarSection::role).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Role {
+    /// A section that stands out, styled with [`Theme::accent`].
+    Accent,
+    /// Secondary information, styled with [`Theme::muted`].
+    Muted,
+    /// An informational message, styled with [`Theme::info`].
+    Info,
+    /// A warning, styled with [`Theme::warning`].
+    Warning,
+    /// An error, styled with [`Theme::error`].
+    Error,
+}
+
+impl Theme {
+    /// Returns the style of sections with `role`.
+    #[must_use]
+    pub const fn style(&self, role: Role) -> Style {
+        match role {
+            Role::Accent => self.accent,
+            Role::Muted => self.muted,
+            Role::Info => self.info,
+            Role::Warning => self.warning,
+            Role::Error => self.error,
+        }
+    }
+
+    /// A theme for dark terminal backgrounds.
+    #[must_use]
+    pub const fn dark() -> Self {
+        Self {
+            bar: Style::new()
+                .fg(Color::Rgb(0xd0, 0xd0, 0xd0))
+                .bg(Color::Rgb(0x26, 0x26, 0x26)),
+            accent: Style::new()
+                .fg(Color::Rgb(0x1c, 0x1c, 0x1c))
+                .bg(Color::Rgb(0x87, 0xaf, 0xd7))
+                .add_modifier(Modifier::BOLD),
+            muted: Style::new().fg(Color::Rgb(0x80, 0x80, 0x80)),
+            info: Style::new().fg(Color::Rgb(0x5f, 0xaf, 0xff)),
+            warning: Style::new().fg(Color::Rgb(0xff, 0xaf, 0x00)),
+            error: Style::new().fg(Color::Rgb(0xff, 0x5f, 0x5f)),
+            inactive: Style::new()
+                .fg(Color::Rgb(0x80, 0x80, 0x80))
+                .add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// A theme for light terminal backgrounds.
+    #[must_use]
+    pub const fn light() -> Self {
+        Self {
+            bar: Style::new()
+                .fg(Color::Rgb(0x30, 0x30, 0x30))
+                .bg(Color::Rgb(0xe4, 0xe4, 0xe4)),
+            accent: Style::new()
+                .fg(Color::Rgb(0xff, 0xff, 0xff))
+                .bg(Color::Rgb(0x00, 0x5f, 0xaf))
+                .add_modifier(Modifier::BOLD),
+            muted: Style::new().fg(Color::Rgb(0x8a, 0x8a, 0x8a)),
+            info: Style::new().fg(Color::Rgb(0x00, 0x5f, 0xd7)),
+            warning: Style::new().fg(Color::Rgb(0xaf, 0x5f, 0x00)),
+            error: Style::new().fg(Color::Rgb(0xd7, 0x00, 0x00)),
+            inactive: Style::new()
+                .fg(Color::Rgb(0x8a, 0x8a, 0x8a))
+                .add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Returns the preset for the background of the terminal, falling back to
+    /// [`Theme::dark`] if the terminal doesn't report its background color.
+    ///
+    /// This briefly enables raw mode, so call it before reading terminal events.
+    #[cfg(all(feature = "appearance", unix))]
+    #[must_use]
+    pub fn auto() -> Self {
+        use crate::appearance::TerminalAppearance;
+
+        match TerminalAppearance::detect() {
+            Ok(TerminalAppearance::Light) => Self::light(),
+            Ok(TerminalAppearance::Dark) | Err(_) => Self::dark(),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Styles for numeric readouts by range.
+
+use ratatui::style::Style;
+
+/// Maps ranges of a numeric value to styles, e.g. CPU usage below 50% in green, below 85% in
+/// yellow and red above, for segments showing measurements.
+///
+/// Ranges are added in increasing order with [`below`](Self::below). Values at or above the last
+/// bound use the [`otherwise`](Self::otherwise) style. [`style`](Self::style) looks a value up,
+/// while [`update`](Self::update) remembers the current range and only leaves it once the value
+/// is more than the [hysteresis](Self::hysteresis) past a bound, so a value hovering around a
+/// bound doesn't make the style flicker.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::Thresholds;
+///
+/// let mut cpu = Thresholds::new()
+///     .below(50.0, Style::new().green())
+///     .below(85.0, Style::new().yellow())
+///     .otherwise(Style::new().red())
+///     .hysteresis(2.0);
+/// assert_eq!(cpu.update(49.0), Style::new().green());
+/// assert_eq!(cpu.update(51.0), Style::new().green());
+/// assert_eq!(cpu.update(53.0), Style::new().yellow());
+/// assert_eq!(cpu.update(49.0), Style::new().yellow());
+/// assert_eq!(cpu.style(49.0), Style::new().green());
+///
+/// // low values are the bad ones for a battery
+/// let battery = Thresholds::new()
+///     .below(15.0, Style::new().red())
+///     .otherwise(Style::new().green());
+/// assert_eq!(battery.style(10.0), Style::new().red());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Thresholds {
+    bounds: Vec<(f64, Style)>,
+    otherwise: Style,
+    hysteresis: f64,
+    current: Option<usize>,
+}
+
+impl Thresholds {
+    /// Creates thresholds that style every value with the default style.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Styles values below `bound`, and at or above the previous bound, with `style`.
+    ///
+    /// Bounds must be added in increasing order.
+    #[must_use]
+    pub fn below(mut self, bound: f64, style: Style) -> Self {
+        self.bounds.push((bound, style));
+        self
+    }
+
+    /// Styles values at or above the last bound with `style`.
+    #[must_use]
+    pub const fn otherwise(mut self, style: Style) -> Self {
+        self.otherwise = style;
+        self
+    }
+
+    /// Sets how far past a bound a value has to be for [`update`](Self::update) to change to the
+    /// next range. Defaults to 0.
+    #[must_use]
+    pub const fn hysteresis(mut self, hysteresis: f64) -> Self {
+        self.hysteresis = hysteresis.abs();
+        self
+    }
+
+    /// Returns the style of the range `value` is in.
+    #[must_use]
+    pub fn style(&self, value: f64) -> Style {
+        self.style_of(self.range(value))
+    }
+
+    /// Returns the style for `value`, staying in the range of the previous update until `value`
+    /// is more than the hysteresis past its bounds. A `NaN` value, e.g. a failed measurement,
+    /// keeps the previous range.
+    pub fn update(&mut self, value: f64) -> Style {
+        let range = self.range(value);
+        let range = match self.current {
+            Some(current) if value.is_nan() => current,
+            Some(current) if range > current => current.max(self.range(value - self.hysteresis)),
+            Some(current) if range < current => current.min(self.range(value + self.hysteresis)),
+            _ => range,
+        };
+        self.current = Some(range);
+        self.style_of(range)
+    }
+
+    /// Returns the index of the range `value` is in, where the range of the
+    /// [`otherwise`](Self::otherwise) style comes after all bounds.
+    fn range(&self, value: f64) -> usize {
+        self.bounds
+            .iter()
+            .position(|(bound, _)| value < *bound)
+            .unwrap_or(self.bounds.len())
+    }
+
+    fn style_of(&self, range: usize) -> Style {
+        self.bounds
+            .get(range)
+            .map_or(self.otherwise, |(_, style)| *style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn hysteresis() {
+        let mut thresholds = Thresholds::new()
+            .below(50.0, Style::new().green())
+            .below(85.0, Style::new().yellow())
+            .otherwise(Style::new().red())
+            .hysteresis(5.0);
+        let styles: Vec<_> = [10.0, 54.0, 55.0, 90.0, 89.0, 84.0, 79.0, 30.0, f64::NAN]
+            .into_iter()
+            .map(|value| thresholds.update(value))
+            .collect();
+        assert_eq!(
+            styles,
+            [
+                Style::new().green(),
+                Style::new().green(),
+                Style::new().yellow(),
+                Style::new().red(),
+                Style::new().red(),
+                Style::new().red(),
+                Style::new().yellow(),
+                Style::new().green(),
+                Style::new().green(),
+            ]
+        );
+        assert_eq!(Thresholds::new().style(1.0), Style::default());
+    }
+}
@@ -0,0 +1,139 @@
+//! Popups showing more information about a section.
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, WidgetRef};
+
+/// A popup anchored to a section of a [`StatusBar`](crate::StatusBar), e.g. the full path of a
+/// truncated file name or the full text of a diagnostic.
+///
+/// Tooltips are usually created with [`StatusBar::tooltip`](crate::StatusBar::tooltip) for the
+/// section selected with [`StatusBarState::show_tooltip`](crate::StatusBarState::show_tooltip),
+/// and rendered after the rest of the frame so they are drawn on top. The tooltip is placed above
+/// its anchor, or below it when there is more room there, within the area it is rendered into.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui::widgets::StatefulWidgetRef;
+/// use ratatui_statusbar::{StatusBar, StatusBarSection, StatusBarState};
+///
+/// let status_bar = StatusBar::new(1)
+///     .section(0, StatusBarSection::from("lib.rs").tooltip("~/src/ratatui-statusbar/src/lib.rs"))
+///     .unwrap();
+/// let mut state = StatusBarState::default();
+/// state.show_tooltip(Some(0));
+///
+/// let frame = Rect::new(0, 0, 80, 24);
+/// let (_, bar) = status_bar.split(frame);
+/// let mut buf = Buffer::empty(frame);
+/// StatefulWidgetRef::render_ref(&status_bar, bar, &mut buf, &mut state);
+/// if let Some(tooltip) = status_bar.tooltip(bar, &state) {
+///     tooltip.render(frame, &mut buf);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tooltip<'a> {
+    text: Text<'a>,
+    anchor: Rect,
+    style: Style,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> Tooltip<'a> {
+    /// Creates a bordered tooltip showing `text` next to `anchor`.
+    #[must_use]
+    pub fn new(text: impl Into<Text<'a>>, anchor: Rect) -> Self {
+        Self {
+            text: text.into(),
+            anchor,
+            style: Style::default(),
+            block: Some(Block::default().borders(Borders::ALL)),
+        }
+    }
+
+    /// Sets the style of the tooltip.
+    #[must_use]
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the block around the text, or removes it with `None`.
+    #[must_use]
+    pub fn block(mut self, block: Option<Block<'a>>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Returns the area of the tooltip when it is rendered into `area`.
+    #[must_use]
+    pub fn area(&self, area: Rect) -> Rect {
+        let border = if self.block.is_some() { 2 } else { 0 };
+        let text_width = u16::try_from(self.text.width()).unwrap_or(u16::MAX);
+        let text_height = u16::try_from(self.text.height()).unwrap_or(u16::MAX);
+        let width = text_width.saturating_add(border).min(area.width);
+        let above = self.anchor.y.saturating_sub(area.y);
+        let below = area.bottom().saturating_sub(self.anchor.bottom());
+        let height = text_height.saturating_add(border);
+        let (y, height) = if height <= above || above >= below {
+            let height = height.min(above);
+            (self.anchor.y - height, height)
+        } else {
+            (self.anchor.bottom(), height.min(below))
+        };
+        let x = self
+            .anchor
+            .x
+            .clamp(area.x, area.right().saturating_sub(width).max(area.x));
+        Rect::new(x, y, width, height)
+    }
+}
+
+impl Widget for Tooltip<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for Tooltip<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let area = self.area(area);
+        if area.is_empty() {
+            return;
+        }
+        Clear.render(area, buf);
+        let mut paragraph = Paragraph::new(self.text.clone()).style(self.style);
+        if let Some(block) = &self.block {
+            paragraph = paragraph.block(block.clone());
+        }
+        paragraph.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placement() {
+        let frame = Rect::new(0, 0, 10, 5);
+        let tooltip = Tooltip::new("abc", Rect::new(8, 4, 2, 1));
+        assert_eq!(tooltip.area(frame), Rect::new(5, 1, 5, 3));
+
+        let mut buf = Buffer::empty(frame);
+        tooltip.render(frame, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "          ",
+                "     ┌───┐",
+                "     │abc│",
+                "     └───┘",
+                "          ",
+            ])
+        );
+
+        let tooltip = Tooltip::new("abc", Rect::new(0, 0, 2, 1)).block(None);
+        assert_eq!(tooltip.area(frame), Rect::new(0, 1, 3, 1));
+    }
+}
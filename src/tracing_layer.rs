@@ -0,0 +1,126 @@
+//! A [`tracing_subscriber::Layer`] that shows the most recent event in a [`LogSegment`].
+//!
+//! # Examples
+//! ```
+//! use ratatui_statusbar::segments::LogSegment;
+//! use ratatui_statusbar::tracing_layer::StatusBarLayer;
+//! use tracing_subscriber::prelude::*;
+//!
+//! let log = LogSegment::new();
+//! tracing_subscriber::registry()
+//!     .with(StatusBarLayer::new(log.clone()).level(tracing::Level::INFO))
+//!     .init();
+//!
+//! tracing::warn!("disk almost full");
+//! assert_eq!(log.latest().unwrap().message, "disk almost full");
+//! ```
+
+use std::fmt::{self, Write};
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+use crate::segments::{LogLevel, LogMessage, LogSegment};
+
+/// A [`Layer`] forwarding events at or above a level to a [`LogSegment`].
+#[derive(Debug, Clone)]
+pub struct StatusBarLayer {
+    segment: LogSegment,
+    level: Level,
+}
+
+impl StatusBarLayer {
+    /// Creates a layer forwarding warnings and errors to `segment`.
+    #[must_use]
+    pub const fn new(segment: LogSegment) -> Self {
+        Self {
+            segment,
+            level: Level::WARN,
+        }
+    }
+
+    /// Sets the least severe level that is forwarded.
+    #[must_use]
+    pub const fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+}
+
+impl<S: Subscriber> Layer<S> for StatusBarLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        // more verbose levels compare greater in tracing
+        if *metadata.level() > self.level {
+            return;
+        }
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.segment.push(LogMessage {
+            level: log_level(*metadata.level()),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+        });
+    }
+}
+
+fn log_level(level: Level) -> LogLevel {
+    match level {
+        Level::ERROR => LogLevel::Error,
+        Level::WARN => LogLevel::Warn,
+        Level::INFO => LogLevel::Info,
+        Level::DEBUG => LogLevel::Debug,
+        Level::TRACE => LogLevel::Trace,
+    }
+}
+
+/// Formats the `message` field followed by the other fields as `name=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.insert_str(0, value);
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message.insert_str(0, &format!("{value:?}"));
+        } else {
+            let _ = write!(self.message, " {}={value:?}", field.name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn forwards_events_above_level() {
+        let log = LogSegment::new();
+        let subscriber = tracing_subscriber::registry().with(StatusBarLayer::new(log.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::error!(code = 2, "request failed");
+            tracing::info!("ignored");
+        });
+        assert_eq!(
+            log.latest(),
+            Some(LogMessage {
+                level: LogLevel::Error,
+                target: module_path!().to_string(),
+                message: "request failed code=2".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Animated transitions between the old and new content of a section.
+
+use ratatui::prelude::*;
+use unicode_width::UnicodeWidthChar;
+
+use crate::Blend;
+
+/// How a section animates a change of its content, see
+/// [`StatusBarSection::transition`](crate::StatusBarSection::transition).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transition {
+    /// The old content slides out to the start while the new content slides in after it.
+    Slide,
+    /// The old content fades into the background, then the new content fades in. Only colors
+    /// with an RGB value fade.
+    Fade,
+}
+
+impl Transition {
+    /// Returns the content shown `progress` of the way from `from` to `to`, in a section with
+    /// `base` style.
+    pub(crate) fn frame(
+        self,
+        from: &Line<'_>,
+        to: &Line<'static>,
+        progress: f32,
+        base: Style,
+    ) -> Line<'static> {
+        match self {
+            Self::Slide => {
+                let mut spans = owned_spans(from);
+                spans.push(Span::raw(" "));
+                spans.extend(owned_spans(to));
+                let distance = from.width() + 1;
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let skip = (distance as f32 * progress).round() as usize;
+                Line {
+                    spans: skip_width(spans, skip),
+                    ..to.clone()
+                }
+            }
+            Self::Fade if progress < 0.5 => fade(from, base, progress * 2.0, false),
+            Self::Fade => fade(to, base, progress * 2.0 - 1.0, true),
+        }
+    }
+}
+
+/// Blends the foreground colors of `line` with the background of `base`, fading in or out by
+/// `t`.
+fn fade(line: &Line<'_>, base: Style, t: f32, fade_in: bool) -> Line<'static> {
+    let base = base.patch(line.style);
+    let spans = owned_spans(line)
+        .into_iter()
+        .map(|span| {
+            let style = base.patch(span.style);
+            match (style.fg, style.bg) {
+                (Some(fg), Some(bg)) => {
+                    let fg = if fade_in {
+                        bg.blend(&fg, t)
+                    } else {
+                        fg.blend(&bg, t)
+                    };
+                    span.fg(fg)
+                }
+                _ => span,
+            }
+        })
+        .collect();
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+fn owned_spans(line: &Line<'_>) -> Vec<Span<'static>> {
+    line.spans
+        .iter()
+        .map(|span| Span::styled(span.content.to_string(), span.style))
+        .collect()
+}
+
+/// Drops the first `width` columns of `spans`.
+fn skip_width(spans: Vec<Span<'static>>, width: usize) -> Vec<Span<'static>> {
+    let mut skipped = 0;
+    spans
+        .into_iter()
+        .filter_map(|span| {
+            if skipped >= width {
+                return Some(span);
+            }
+            let content = span
+                .content
+                .chars()
+                .skip_while(|c| {
+                    if skipped >= width {
+                        return false;
+                    }
+                    skipped += c.width().unwrap_or(0);
+                    true
+                })
+                .collect::<String>();
+            (!content.is_empty()).then(|| Span::styled(content, span.style))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slide() {
+        let from = Line::from("NORMAL");
+        let to = Line::from(vec![Span::raw("INS").bold(), Span::raw("ERT")]);
+        let frame = |progress| Transition::Slide.frame(&from, &to, progress, Style::new());
+        assert_eq!(frame(0.0).to_string(), "NORMAL INSERT");
+        assert_eq!(frame(0.5).to_string(), "AL INSERT");
+        assert_eq!(frame(1.0), to);
+    }
+
+    #[test]
+    fn fade() {
+        let base = Style::new()
+            .fg(Color::Rgb(200, 200, 200))
+            .bg(Color::Rgb(0, 0, 0));
+        let from = Line::from("old");
+        let to = Line::from("new").fg(Color::Rgb(100, 0, 0));
+        let frame = |progress| Transition::Fade.frame(&from, &to, progress, base);
+        assert_eq!(
+            frame(0.0).spans[0].style.fg,
+            Some(Color::Rgb(200, 200, 200))
+        );
+        assert_eq!(
+            frame(0.25).spans[0].style.fg,
+            Some(Color::Rgb(100, 100, 100))
+        );
+        assert_eq!(frame(0.5).to_string(), "new");
+        assert_eq!(frame(0.5).spans[0].style.fg, Some(Color::Rgb(0, 0, 0)));
+        assert_eq!(frame(0.75).spans[0].style.fg, Some(Color::Rgb(50, 0, 0)));
+    }
+}
@@ -0,0 +1,355 @@
+//! Shortening content that doesn't fit its section.
+
+use ratatui::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::width;
+
+/// Where content that doesn't fit its section is cut.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::{StatusBarSection, Truncation};
+///
+/// // Keep the file name, which is the informative part of a path.
+/// let section = StatusBarSection::from("~/src/ratatui-statusbar/src/lib.rs").truncation(Truncation::Start);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Truncation {
+    /// The content is clipped at the end without an ellipsis.
+    #[default]
+    Clip,
+    /// The end of the content is replaced by an ellipsis: `long te…`.
+    End,
+    /// The start of the content is replaced by an ellipsis: `…ng text`.
+    Start,
+    /// The middle of the content is replaced by an ellipsis: `long…ext`.
+    Middle,
+}
+
+/// A strategy for shortening content that doesn't fit its section, selected per section with
+/// [`StatusBarSection::truncator`](crate::StatusBarSection::truncator).
+///
+/// Implement it for domain-specific shortening of e.g. URLs or branch names. [`Truncation`],
+/// [`PathTruncator`] and [`WordTruncator`] are built in.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::{StatusBarSection, Truncation, Truncator};
+///
+/// /// Drops the `feature/` prefix of branch names before cutting the end.
+/// struct BranchTruncator;
+///
+/// impl Truncator for BranchTruncator {
+///     fn truncate<'a>(&self, line: &Line<'a>, width: usize) -> Line<'a> {
+///         let branch = line.to_string();
+///         let short = Line::from(branch.trim_start_matches("feature/").to_string());
+///         Truncation::End.truncate(&short, width)
+///     }
+/// }
+///
+/// let section = StatusBarSection::from("feature/statusbar").truncator(BranchTruncator);
+/// ```
+pub trait Truncator: Send + Sync {
+    /// Shortens `line` to at most `width` columns. Lines that fit are returned as they are.
+    fn truncate<'a>(&self, line: &Line<'a>, width: usize) -> Line<'a>;
+}
+
+impl Truncator for Truncation {
+    fn truncate<'a>(&self, line: &Line<'a>, width: usize) -> Line<'a> {
+        truncate(line, width, *self)
+    }
+}
+
+/// A [`Truncator`] for file paths that drops leading path components, keeping as much of the end
+/// of the path as fits whole components: `…/src/lib.rs`.
+///
+/// Paths whose last component alone doesn't fit are cut at the [start](Truncation::Start).
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::{PathTruncator, Truncator};
+///
+/// let path = Line::from("~/src/ratatui-statusbar/src/lib.rs");
+/// assert_eq!(PathTruncator.truncate(&path, 16).to_string(), "…/src/lib.rs");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PathTruncator;
+
+impl Truncator for PathTruncator {
+    fn truncate<'a>(&self, line: &Line<'a>, width: usize) -> Line<'a> {
+        if width::line_width(line) <= width {
+            return line.clone();
+        }
+        let graphemes = styled_graphemes(line);
+        let fits = |start: usize| {
+            let suffix: usize = graphemes[start..]
+                .iter()
+                .map(|(grapheme, _)| width::width(grapheme))
+                .sum();
+            suffix < width
+        };
+        let Some(start) = (0..graphemes.len())
+            .find(|start| matches!(graphemes[*start].0, "/" | "\\") && *start > 0 && fits(*start))
+        else {
+            return truncate(line, width, Truncation::Start);
+        };
+        let style = graphemes[start].1;
+        let mut truncated = vec![(ELLIPSIS, style)];
+        truncated.extend_from_slice(&graphemes[start..]);
+        Line {
+            spans: group(truncated),
+            ..line.clone()
+        }
+    }
+}
+
+/// A [`Truncator`] that cuts the end of the content at a word boundary, so no word is shown
+/// partially: `long…`.
+///
+/// Content whose first word alone doesn't fit is cut at the [end](Truncation::End).
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::{Truncator, WordTruncator};
+///
+/// let message = Line::from("build finished with warnings");
+/// assert_eq!(WordTruncator.truncate(&message, 20).to_string(), "build finished with…");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WordTruncator;
+
+impl Truncator for WordTruncator {
+    fn truncate<'a>(&self, line: &Line<'a>, width: usize) -> Line<'a> {
+        if width::line_width(line) <= width {
+            return line.clone();
+        }
+        let graphemes = styled_graphemes(line);
+        let prefix = take_width(graphemes.iter().copied(), width.saturating_sub(1));
+        // the grapheme after the prefix starts a new word if it is whitespace
+        let end = if is_whitespace(graphemes[prefix.len()].0) {
+            prefix.len()
+        } else {
+            prefix
+                .iter()
+                .rposition(|(grapheme, _)| is_whitespace(grapheme))
+                .unwrap_or(0)
+        };
+        let end = prefix[..end]
+            .iter()
+            .rposition(|(grapheme, _)| !is_whitespace(grapheme))
+            .map_or(0, |last| last + 1);
+        if end == 0 {
+            return truncate(line, width, Truncation::End);
+        }
+        let mut truncated = prefix[..end].to_vec();
+        truncated.push((ELLIPSIS, prefix[end - 1].1));
+        Line {
+            spans: group(truncated),
+            ..line.clone()
+        }
+    }
+}
+
+const ELLIPSIS: &str = "…";
+
+/// Shortens `line` to at most `width` columns.
+///
+/// The ellipsis takes the style of the character it replaces next to.
+pub(crate) fn truncate<'a>(line: &Line<'a>, width: usize, truncation: Truncation) -> Line<'a> {
+    if width::line_width(line) <= width || truncation == Truncation::Clip {
+        return line.clone();
+    }
+    let graphemes = styled_graphemes(line);
+    let budget = width.saturating_sub(1);
+    let (head, tail) = match truncation {
+        Truncation::Clip | Truncation::End => (budget, 0),
+        Truncation::Start => (0, budget),
+        Truncation::Middle => (budget - budget / 2, budget / 2),
+    };
+    let prefix = take_width(graphemes.iter().copied(), head);
+    let mut suffix = take_width(graphemes.iter().rev().copied(), tail);
+    suffix.reverse();
+    // combining marks at the start of the content, which have no base character
+    let orphans = suffix
+        .iter()
+        .take_while(|(grapheme, _)| width::width(grapheme) == 0)
+        .count();
+    suffix.drain(..orphans);
+
+    let mut truncated = prefix;
+    if width > 0 {
+        let style = truncated
+            .last()
+            .or_else(|| suffix.first())
+            .or_else(|| graphemes.first())
+            .map_or_else(Style::default, |(_, style)| *style);
+        truncated.push((ELLIPSIS, style));
+    }
+    truncated.extend(suffix);
+    Line {
+        spans: group(truncated),
+        ..line.clone()
+    }
+}
+
+/// Removes the whitespace at the start and end of `line`.
+pub(crate) fn trim<'a>(line: &Line<'a>) -> Line<'a> {
+    let mut spans = line.spans.clone();
+    while let Some(span) = spans.first_mut() {
+        let trimmed = span.content.trim_start();
+        if trimmed.is_empty() {
+            spans.remove(0);
+        } else {
+            span.content = trimmed.to_string().into();
+            break;
+        }
+    }
+    while let Some(span) = spans.last_mut() {
+        let trimmed = span.content.trim_end();
+        if trimmed.is_empty() {
+            spans.pop();
+        } else {
+            span.content = trimmed.to_string().into();
+            break;
+        }
+    }
+    Line {
+        spans,
+        ..line.clone()
+    }
+}
+
+/// Returns the grapheme clusters of `line` with the styles of their spans, so that clusters
+/// such as emoji sequences are never cut apart.
+fn styled_graphemes<'a>(line: &'a Line<'_>) -> Vec<(&'a str, Style)> {
+    line.spans
+        .iter()
+        .flat_map(|span| {
+            span.content
+                .graphemes(true)
+                .map(|grapheme| (grapheme, span.style))
+        })
+        .collect()
+}
+
+fn is_whitespace(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+/// Takes graphemes until `width` columns are filled.
+fn take_width<'a>(
+    graphemes: impl Iterator<Item = (&'a str, Style)>,
+    width: usize,
+) -> Vec<(&'a str, Style)> {
+    let mut used = 0;
+    graphemes
+        .take_while(|(grapheme, _)| {
+            used += width::width(grapheme);
+            used <= width
+        })
+        .collect()
+}
+
+/// Groups consecutive graphemes with the same style into spans.
+fn group(graphemes: Vec<(&str, Style)>) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (grapheme, style) in graphemes {
+        match spans.last_mut() {
+            Some(span) if span.style == style => span.content.to_mut().push_str(grapheme),
+            _ => spans.push(Span::styled(grapheme.to_string(), style)),
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let line = Line::from("long text");
+        let truncate = |truncation| truncate(&line, 8, truncation).to_string();
+        assert_eq!(truncate(Truncation::Clip), "long text");
+        assert_eq!(truncate(Truncation::End), "long te…");
+        assert_eq!(truncate(Truncation::Start), "…ng text");
+        assert_eq!(truncate(Truncation::Middle), "long…ext");
+        assert_eq!(super::truncate(&line, 9, Truncation::End), line);
+        assert_eq!(super::truncate(&line, 1, Truncation::End).to_string(), "…");
+    }
+
+    #[test]
+    fn styles_and_wide_chars() {
+        let line = Line::from(vec![
+            Span::raw("ab"),
+            Span::styled("日本", Style::new().red()),
+        ]);
+        assert_eq!(
+            truncate(&line, 5, Truncation::End),
+            Line::from(vec![
+                Span::raw("ab"),
+                Span::styled("日…", Style::new().red())
+            ])
+        );
+        assert_eq!(
+            truncate(&line, 4, Truncation::Start),
+            Line::from(vec![Span::styled("…本", Style::new().red())])
+        );
+    }
+
+    #[test]
+    fn trim_padding() {
+        let line = Line::from(vec![Span::raw("  "), Span::raw(" a "), Span::raw("b ")]);
+        assert_eq!(
+            trim(&line),
+            Line::from(vec![Span::raw("a "), Span::raw("b")])
+        );
+        assert_eq!(trim(&Line::from("   ")), Line::default());
+    }
+
+    #[test]
+    fn strategies() {
+        let path = Line::from(vec![Span::raw("~/src/"), Span::raw("main.rs").bold()]);
+        assert_eq!(PathTruncator.truncate(&path, 13), path);
+        assert_eq!(
+            PathTruncator.truncate(&path, 12),
+            Line::from(vec![Span::raw("…/"), Span::raw("main.rs").bold()])
+        );
+        assert_eq!(PathTruncator.truncate(&path, 6).to_string(), "…in.rs");
+
+        let words = Line::from("one two  three");
+        let truncate = |width| WordTruncator.truncate(&words, width).to_string();
+        assert_eq!(truncate(14), "one two  three");
+        assert_eq!(truncate(13), "one two…");
+        assert_eq!(truncate(8), "one two…");
+        assert_eq!(truncate(7), "one…");
+        assert_eq!(truncate(3), "on…");
+    }
+
+    #[test]
+    fn combining_marks() {
+        let line = Line::from("שָׁלוֹם");
+        assert_eq!(line.width(), 4);
+        assert_eq!(truncate(&line, 3, Truncation::End).to_string(), "שָׁל…");
+        assert_eq!(truncate(&line, 2, Truncation::Start).to_string(), "…ם");
+    }
+
+    #[test]
+    fn emoji_sequences() {
+        let line = Line::from("ok 👍\u{1f3fd}!");
+        assert_eq!(truncate(&line, 5, Truncation::End).to_string(), "ok …");
+        assert_eq!(
+            truncate(&line, 6, Truncation::Start).to_string(),
+            "…👍\u{1f3fd}!"
+        );
+    }
+}
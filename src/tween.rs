@@ -0,0 +1,190 @@
+//! Smooth color transitions between styles and themes.
+
+use std::time::Duration;
+
+use ratatui::style::{Color, Style};
+
+use crate::color::blend;
+use crate::Theme;
+
+/// Values whose colors can be interpolated in RGB space, see [`ColorTransition`].
+pub trait Blend {
+    /// Returns the value `t` of the way from `self` to `other`, with `t` between 0 and 1.
+    #[must_use]
+    fn blend(&self, other: &Self, t: f32) -> Self;
+}
+
+/// Colors without an RGB value, i.e. [`Color::Reset`], switch halfway.
+impl Blend for Color {
+    fn blend(&self, other: &Self, t: f32) -> Self {
+        if *self == Color::Reset || *other == Color::Reset {
+            return if t < 0.5 { *self } else { *other };
+        }
+        blend(*self, *other, t)
+    }
+}
+
+/// Colors are blended, while modifiers and colors that are only set on one side switch halfway.
+impl Blend for Style {
+    fn blend(&self, other: &Self, t: f32) -> Self {
+        let color = |from: Option<Color>, to: Option<Color>| match (from, to) {
+            (Some(from), Some(to)) => Some(from.blend(&to, t)),
+            (from, to) => {
+                if t < 0.5 {
+                    from
+                } else {
+                    to
+                }
+            }
+        };
+        let switched = if t < 0.5 { self } else { other };
+        Style {
+            fg: color(self.fg, other.fg),
+            bg: color(self.bg, other.bg),
+            underline_color: color(self.underline_color, other.underline_color),
+            ..*switched
+        }
+    }
+}
+
+impl Blend for Theme {
+    fn blend(&self, other: &Self, t: f32) -> Self {
+        Self {
+            bar: self.bar.blend(&other.bar, t),
+            accent: self.accent.blend(&other.accent, t),
+            muted: self.muted.blend(&other.muted, t),
+            info: self.info.blend(&other.info, t),
+            warning: self.warning.blend(&other.warning, t),
+            error: self.error.blend(&other.error, t),
+            inactive: self.inactive.blend(&other.inactive, t),
+        }
+    }
+}
+
+/// An eased change from one [style](Style), [`Theme`] or [`Color`] to another over a duration,
+/// so that e.g. switching vim modes or between light and dark themes doesn't snap.
+///
+/// Time passes through [`ColorTransition::advance`], like for the
+/// [`StatusBarState`](crate::StatusBarState).
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use ratatui_statusbar::{ColorTransition, StatusBar, Theme};
+///
+/// let mut theme = ColorTransition::new(Theme::dark(), Theme::light(), Duration::from_millis(300));
+/// // on every tick of the event loop:
+/// if theme.advance(Duration::from_millis(100)) {
+///     let status_bar = StatusBar::new(1).theme(theme.current());
+///     // redraw
+/// }
+/// assert!(!theme.is_finished());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorTransition<T> {
+    from: T,
+    to: T,
+    duration: Duration,
+    elapsed: Duration,
+}
+
+impl<T: Blend + Clone> ColorTransition<T> {
+    /// Creates a transition from `from` to `to` over `duration`.
+    #[must_use]
+    pub const fn new(from: T, to: T, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Starts a new transition to `to` from the current value, e.g. when the mode changes again
+    /// before the previous transition finished.
+    pub fn retarget(&mut self, to: T) {
+        self.from = self.current();
+        self.to = to;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Advances the transition by `elapsed`.
+    ///
+    /// Returns whether the current value changed, i.e. whether the status bar needs to be
+    /// redrawn.
+    pub fn advance(&mut self, elapsed: Duration) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        self.elapsed = (self.elapsed + elapsed).min(self.duration);
+        true
+    }
+
+    /// Returns whether the transition reached its target.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Returns the current value.
+    #[must_use]
+    pub fn current(&self) -> T {
+        if self.is_finished() {
+            return self.to.clone();
+        }
+        self.from
+            .blend(&self.to, progress(self.elapsed, self.duration))
+    }
+}
+
+/// Returns how far `elapsed` is into `duration`, from 0 to 1. A zero `duration` is over at once.
+pub(crate) fn progress(elapsed: Duration, duration: Duration) -> f32 {
+    if elapsed >= duration {
+        return 1.0;
+    }
+    elapsed.as_secs_f32() / duration.as_secs_f32()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn transition() {
+        let from = Style::new().fg(Color::Rgb(0, 0, 0)).bg(Color::Black).bold();
+        let to = Style::new().fg(Color::Rgb(200, 100, 0)).italic();
+        let mut transition = ColorTransition::new(from, to, Duration::from_secs(4));
+        assert_eq!(transition.current(), from);
+
+        assert!(transition.advance(Duration::from_secs(1)));
+        assert_eq!(
+            transition.current(),
+            Style::new()
+                .fg(Color::Rgb(50, 25, 0))
+                .bg(Color::Black)
+                .bold()
+        );
+        assert!(transition.advance(Duration::from_secs(1)));
+        assert_eq!(transition.current(), to.fg(Color::Rgb(100, 50, 0)));
+
+        transition.retarget(from);
+        assert!(transition.advance(Duration::from_secs(8)));
+        assert!(transition.is_finished());
+        assert!(!transition.advance(Duration::from_secs(1)));
+        assert_eq!(transition.current(), from);
+
+        assert_eq!(Color::Reset.blend(&Color::Red, 0.4), Color::Reset);
+        assert_eq!(Theme::dark().blend(&Theme::light(), 1.0), Theme::light());
+
+        let instant = ColorTransition::new(from, to, Duration::ZERO);
+        assert!(instant.is_finished());
+        assert_eq!(instant.current(), to);
+        assert!((progress(Duration::ZERO, Duration::ZERO) - 1.0).abs() < f32::EPSILON);
+        assert!(
+            (progress(Duration::from_secs(1), Duration::from_secs(4)) - 0.25).abs() < f32::EPSILON
+        );
+    }
+}
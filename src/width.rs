@@ -0,0 +1,286 @@
+//! Measuring the width of text in terminal columns.
+//!
+//! The layout and [truncation](crate::Truncation) of status bars measure text with a
+//! process-wide [`WidthPolicy`], which defaults to the widths of the [`unicode-width`] crate that
+//! ratatui uses as well. Terminals don't all agree on these widths, e.g. for East Asian
+//! characters of ambiguous width, and the right edge of the bar drifts where they disagree. Set a
+//! policy that matches the terminal with [`set_width_policy`], and the status bar measures and
+//! places each grapheme cluster with it. Only the wrapping of
+//! [multi-line](crate::StatusBarSection::text) sections is left to ratatui.
+//!
+//! [`unicode-width`]: https://docs.rs/unicode-width
+//!
+//! # Examples
+//! ```
+//! use ratatui_statusbar::width::{self, AmbiguousWide};
+//!
+//! assert_eq!(width::width("→ main"), 6);
+//! width::set_width_policy(AmbiguousWide);
+//! assert_eq!(width::width("→ main"), 7);
+//! ```
+
+use std::sync::{Arc, RwLock};
+
+use ratatui::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Measures grapheme clusters in terminal columns.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::width::{self, WidthPolicy};
+/// use unicode_width::UnicodeWidthStr;
+///
+/// /// A terminal that draws the check mark two columns wide.
+/// struct WideCheckMark;
+///
+/// impl WidthPolicy for WideCheckMark {
+///     fn width(&self, grapheme: &str) -> usize {
+///         match grapheme {
+///             "✔" => 2,
+///             _ => grapheme.width(),
+///         }
+///     }
+/// }
+///
+/// width::set_width_policy(WideCheckMark);
+/// assert_eq!(width::width("✔ ok"), 5);
+/// ```
+pub trait WidthPolicy: Send + Sync {
+    /// Returns the number of columns `grapheme`, a single grapheme cluster, takes up.
+    fn width(&self, grapheme: &str) -> usize;
+}
+
+/// The widths of the `unicode-width` crate, which ratatui uses as well. Characters of ambiguous
+/// width, such as arrows and box drawing characters, are one column wide.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnicodeWidth;
+
+impl WidthPolicy for UnicodeWidth {
+    fn width(&self, grapheme: &str) -> usize {
+        grapheme.width()
+    }
+}
+
+/// Like [`UnicodeWidth`], but characters of ambiguous width are two columns wide, as in
+/// terminals configured for CJK locales.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmbiguousWide;
+
+impl WidthPolicy for AmbiguousWide {
+    fn width(&self, grapheme: &str) -> usize {
+        grapheme.width_cjk()
+    }
+}
+
+/// Measures emoji as `Narrow` (one column) or `Wide` (two columns) and everything else like
+/// [`UnicodeWidth`].
+///
+/// Emoji presentation sequences such as `☺️`, ZWJ sequences such as `👨‍👩‍👧`, flags and
+/// keycaps are each measured as one emoji, see [`is_emoji`]. Terminals disagree on these: some
+/// draw a ZWJ sequence as one wide glyph, others as its parts, and some draw emoji one column
+/// wide. Pick the variant that matches the terminal, so the layout and truncation agree with it
+/// and the right edge of the bar doesn't drift.
+///
+/// # Examples
+/// ```
+/// use ratatui_statusbar::width::{self, EmojiWidth};
+///
+/// width::set_width_policy(EmojiWidth::Wide);
+/// assert_eq!(width::width("👨‍👩‍👧 ok"), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmojiWidth {
+    /// Emoji are one column wide.
+    Narrow,
+    /// Emoji are two columns wide.
+    Wide,
+}
+
+impl WidthPolicy for EmojiWidth {
+    fn width(&self, grapheme: &str) -> usize {
+        if !is_emoji(grapheme) {
+            return grapheme.width();
+        }
+        match self {
+            Self::Narrow => 1,
+            Self::Wide => 2,
+        }
+    }
+}
+
+/// Returns whether `grapheme`, a single grapheme cluster, is drawn as an emoji: a pictograph
+/// with emoji presentation, or a sequence with a variation selector 16, a zero width joiner, a
+/// skin tone modifier, regional indicators or a keycap. Sequences with a variation selector 15
+/// ask for text presentation and aren't emoji.
+///
+/// This is a building block for custom [`WidthPolicy`]s.
+#[must_use]
+pub fn is_emoji(grapheme: &str) -> bool {
+    if grapheme.contains('\u{fe0e}') {
+        return false;
+    }
+    let sequence = grapheme.chars().any(|c| {
+        matches!(
+            c,
+            '\u{fe0f}' | '\u{200d}' | '\u{20e3}' | '\u{1f3fb}'..='\u{1f3ff}' | '\u{1f1e6}'..='\u{1f1ff}'
+        )
+    });
+    let pictograph = grapheme.chars().next().is_some_and(|c| {
+        matches!(c, '\u{2600}'..='\u{27bf}' | '\u{1f000}'..='\u{1faff}') && grapheme.width() == 2
+    });
+    sequence || pictograph
+}
+
+static POLICY: RwLock<Option<Arc<dyn WidthPolicy>>> = RwLock::new(None);
+
+/// Sets the [`WidthPolicy`] status bars are measured with for the whole process.
+pub fn set_width_policy(policy: impl WidthPolicy + 'static) {
+    *POLICY
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(Arc::new(policy));
+}
+
+/// Returns the width of `text` with the [`WidthPolicy`] set with [`set_width_policy`].
+#[must_use]
+pub fn width(text: &str) -> usize {
+    with_policy(|policy| str_width(policy, text))
+}
+
+/// Calls `f` with the current policy.
+pub(crate) fn with_policy<R>(f: impl FnOnce(&dyn WidthPolicy) -> R) -> R {
+    let policy = POLICY
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    match policy.as_deref() {
+        Some(policy) => f(policy),
+        None => f(&UnicodeWidth),
+    }
+}
+
+/// Returns the width of `text`, measured grapheme by grapheme.
+pub(crate) fn str_width(policy: &dyn WidthPolicy, text: &str) -> usize {
+    text.graphemes(true)
+        .map(|grapheme| policy.width(grapheme))
+        .sum()
+}
+
+/// Returns the width of `span`.
+pub(crate) fn span_width(span: &Span<'_>) -> usize {
+    width(&span.content)
+}
+
+/// Returns the width of `line`.
+pub(crate) fn line_width(line: &Line<'_>) -> usize {
+    with_policy(|policy| {
+        line.spans
+            .iter()
+            .map(|span| str_width(policy, &span.content))
+            .sum()
+    })
+}
+
+/// Writes `line` like [`Buffer::set_line`], but places the graphemes according to the policy.
+pub(crate) fn set_line(buf: &mut Buffer, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> u16 {
+    with_policy(|policy| {
+        let mut x = x;
+        let mut remaining = max_width;
+        for span in &line.spans {
+            let end = set_str(
+                buf,
+                policy,
+                (x, y),
+                &span.content,
+                remaining,
+                line.style.patch(span.style),
+            );
+            remaining = remaining.saturating_sub(end - x);
+            x = end;
+        }
+        x
+    })
+}
+
+/// Writes `span` like [`Buffer::set_span`], but places the graphemes according to the policy.
+pub(crate) fn set_span(buf: &mut Buffer, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> u16 {
+    with_policy(|policy| set_str(buf, policy, (x, y), &span.content, max_width, span.style))
+}
+
+/// Writes the graphemes of `text` that fit in `max_width` columns and returns the column after
+/// the last one. The cells hidden by wide graphemes are reset.
+fn set_str(
+    buf: &mut Buffer,
+    policy: &dyn WidthPolicy,
+    (mut x, y): (u16, u16),
+    text: &str,
+    max_width: u16,
+    style: Style,
+) -> u16 {
+    let mut remaining = buf.area.right().saturating_sub(x).min(max_width);
+    for grapheme in text.graphemes(true) {
+        let width = u16::try_from(policy.width(grapheme)).unwrap_or(u16::MAX);
+        if width == 0 {
+            continue;
+        }
+        let Some(rest) = remaining.checked_sub(width) else {
+            break;
+        };
+        remaining = rest;
+        buf.get_mut(x, y).set_symbol(grapheme).set_style(style);
+        let next = x + width;
+        x += 1;
+        while x < next {
+            buf.get_mut(x, y).reset();
+            x += 1;
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubled;
+
+    impl WidthPolicy for Doubled {
+        fn width(&self, grapheme: &str) -> usize {
+            grapheme.width() * 2
+        }
+    }
+
+    #[test]
+    fn places_graphemes() {
+        let line = Line::from(vec![Span::raw("ab"), Span::raw("c").bold()]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut expected = buf.clone();
+        buf.set_line(0, 0, &line, 5);
+        assert_eq!(with_policy(|_| set_line(&mut expected, 0, 0, &line, 5)), 3);
+        assert_eq!(buf, expected);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        assert_eq!(
+            set_str(&mut buf, &Doubled, (0, 0), "a界b", 5, Style::new()),
+            2
+        );
+        assert_eq!(buf, Buffer::with_lines(vec!["a     "]));
+        assert_eq!(str_width(&Doubled, "a\u{301}界"), 6);
+    }
+
+    #[test]
+    fn emoji() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        for (text, narrow, wide) in [
+            (family, 1, 2),
+            ("☺\u{fe0f}", 1, 2),
+            ("✔\u{fe0e}", 1, 1),
+            ("🇩🇪", 1, 2),
+            ("⚡", 1, 2),
+            ("→ a", 3, 3),
+        ] {
+            assert_eq!(str_width(&EmojiWidth::Narrow, text), narrow, "{text}");
+            assert_eq!(str_width(&EmojiWidth::Wide, text), wide, "{text}");
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! A per-pane header that matches the [`StatusBar`].
+
+use ratatui::layout::Flex;
+use ratatui::prelude::*;
+use ratatui::widgets::WidgetRef;
+
+use crate::{StatusBar, StatusBarSection};
+
+/// A one-line header for a pane of a split-pane application, built from the same
+/// [`StatusBarSection`]s as the [`StatusBar`].
+///
+/// A focused [`WinBar`] is rendered with the active style. An unfocused one is rendered as an
+/// [inactive](StatusBar::inactive) status bar, i.e. the inactive style is applied on top of the
+/// active style, dimming it by default.
+///
+/// # Examples
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_statusbar::{StatusBarSection, WinBar};
+///
+/// let panes = [("src/lib.rs", true), ("src/main.rs", false)];
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 40, 1));
+/// for ((title, focused), area) in panes.into_iter().zip([Rect::new(0, 0, 20, 1), Rect::new(20, 0, 20, 1)]) {
+///     let win_bar = WinBar::new()
+///         .section(StatusBarSection::from(title).post_separator(" "))
+///         .section("[+]")
+///         .active_style(Style::new().bold())
+///         .focused(focused);
+///     win_bar.render(area, &mut buf);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct WinBar<'a> {
+    bar: StatusBar<'a>,
+}
+
+impl Default for WinBar<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> WinBar<'a> {
+    /// Creates an empty, focused [`WinBar`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            bar: StatusBar::new(0),
+        }
+    }
+
+    /// Appends a section.
+    #[must_use]
+    pub fn section(mut self, section: impl Into<StatusBarSection<'a>>) -> Self {
+        self.bar.sections.push(section.into());
+        self
+    }
+
+    /// Configures the flex layout mode of the sections.
+    #[must_use]
+    pub fn flex(mut self, flex: Flex) -> Self {
+        self.bar = self.bar.flex(flex);
+        self
+    }
+
+    /// Sets whether the pane the [`WinBar`] belongs to has focus.
+    #[must_use]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.bar = self.bar.inactive(!focused);
+        self
+    }
+
+    /// Sets the style of the [`WinBar`].
+    #[must_use]
+    pub fn active_style(mut self, style: impl Into<Style>) -> Self {
+        self.bar = self.bar.style(style);
+        self
+    }
+
+    /// Sets the style applied on top of the active style while the pane doesn't have focus.
+    ///
+    /// Defaults to [`StatusBar::DEFAULT_INACTIVE_STYLE`].
+    #[must_use]
+    pub fn inactive_style(mut self, style: impl Into<Style>) -> Self {
+        self.bar = self.bar.inactive_style(style);
+        self
+    }
+}
+
+impl Widget for WinBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for WinBar<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self.bar, area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn focus() {
+        let win_bar = || {
+            WinBar::new()
+                .section("a")
+                .section("b")
+                .active_style(Style::new().on_blue())
+                .inactive_style(Style::new().dark_gray())
+        };
+        let render = |win_bar: WinBar| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+            win_bar.render(buf.area, &mut buf);
+            buf
+        };
+
+        let mut expected = Buffer::with_lines(vec!["a b "]);
+        expected.set_style(expected.area, Style::new().on_blue());
+        assert_eq!(render(win_bar()), expected);
+
+        expected.set_style(expected.area, Style::new().dark_gray());
+        assert_eq!(render(win_bar().focused(false)), expected);
+    }
+}